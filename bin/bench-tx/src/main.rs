@@ -26,6 +26,7 @@ use utils::{
 };
 pub enum Benchmark {
     Simple,
+    UnorderedNotes,
     P2ID,
 }
 
@@ -33,6 +34,7 @@ impl fmt::Display for Benchmark {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Benchmark::Simple => write!(f, "simple"),
+            Benchmark::UnorderedNotes => write!(f, "unordered_notes"),
             Benchmark::P2ID => write!(f, "p2id"),
         }
     }
@@ -47,6 +49,7 @@ fn main() -> Result<(), String> {
     // run all available benchmarks
     let benchmark_results = vec![
         (Benchmark::Simple, benchmark_default_tx()?.into()),
+        (Benchmark::UnorderedNotes, benchmark_unordered_notes_tx()?.into()),
         (Benchmark::P2ID, benchmark_p2id()?.into()),
     ];
 
@@ -84,6 +87,34 @@ pub fn benchmark_default_tx() -> Result<TransactionMeasurements, String> {
     Ok(executed_transaction.into())
 }
 
+/// Runs the default transaction with the input notes consumed in the order chosen by the
+/// executor.
+pub fn benchmark_unordered_notes_tx() -> Result<TransactionMeasurements, String> {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let account_id = tx_context.account().id();
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor: TransactionExecutor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+            .with_tracing()
+            .with_unordered_notes();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .map_err(|e| e.to_string())?;
+
+    Ok(executed_transaction.into())
+}
+
 /// Runs the transaction which consumes a P2ID note into a basic wallet.
 pub fn benchmark_p2id() -> Result<TransactionMeasurements, String> {
     // Create assets
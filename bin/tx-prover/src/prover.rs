@@ -2,6 +2,7 @@ use alloc::{
     boxed::Box,
     string::{String, ToString},
 };
+use core::time::Duration;
 
 use miden_objects::transaction::{ProvenTransaction, TransactionWitness};
 use miden_tx::{utils::sync::RwLock, TransactionProver, TransactionProverError};
@@ -18,6 +19,10 @@ use crate::{generated::api_client::ApiClient, RemoteTransactionProverError};
 /// transport. Otherwise, it uses the built-in `tonic::transport` for native platforms.
 ///
 /// The transport layer connection is established lazily when the first transaction is proven.
+///
+/// Failures to reach the server (including timeouts) are reported as
+/// [TransactionProverError::TransportFailed], while failures of the server to prove the provided
+/// witness are reported as [TransactionProverError::RemoteProvingFailed].
 pub struct RemoteTransactionProver {
     #[cfg(target_arch = "wasm32")]
     client: RwLock<Option<ApiClient<tonic_web_wasm_client::Client>>>,
@@ -26,6 +31,7 @@ pub struct RemoteTransactionProver {
     client: RwLock<Option<ApiClient<tonic::transport::Channel>>>,
 
     endpoint: String,
+    timeout: Option<Duration>,
 }
 
 impl RemoteTransactionProver {
@@ -35,9 +41,19 @@ impl RemoteTransactionProver {
         RemoteTransactionProver {
             endpoint: endpoint.to_string(),
             client: RwLock::new(None),
+            timeout: None,
         }
     }
 
+    /// Sets the maximum duration of a request to the remote transaction prover server.
+    ///
+    /// If the server does not return a proven transaction within the timeout, proving fails with
+    /// [TransactionProverError::TransportFailed]. By default, requests do not time out.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Establishes a connection to the remote transaction prover server. The connection is
     /// mantained for the lifetime of the prover. If the connection is already established, this
     /// method does nothing.
@@ -73,25 +89,31 @@ impl TransactionProver for RemoteTransactionProver {
         tx_witness: TransactionWitness,
     ) -> Result<ProvenTransaction, TransactionProverError> {
         use miden_objects::utils::Serializable;
-        self.connect().await.map_err(|err| {
-            TransactionProverError::InternalError(format!(
-                "Failed to connect to the remote prover: {}",
-                err
-            ))
-        })?;
+        self.connect()
+            .await
+            .map_err(|err| TransactionProverError::TransportFailed(err.to_string()))?;
 
         let mut client = self.client.write();
 
-        let request = tonic::Request::new(crate::generated::ProveTransactionRequest {
+        let mut request = tonic::Request::new(crate::generated::ProveTransactionRequest {
             transaction_witness: tx_witness.to_bytes(),
         });
+        if let Some(timeout) = self.timeout {
+            request.set_timeout(timeout);
+        }
 
         let response = client
             .as_mut()
             .expect("client should be connected")
             .prove_transaction(request)
             .await
-            .map_err(|err| TransactionProverError::InternalError(err.to_string()))?;
+            .map_err(|status| match status.code() {
+                // the server rejected the witness or failed to prove it
+                tonic::Code::InvalidArgument | tonic::Code::Internal => {
+                    TransactionProverError::RemoteProvingFailed(status.message().to_string())
+                },
+                _ => TransactionProverError::TransportFailed(status.to_string()),
+            })?;
 
         // Deserialize the response bytes back into a ProvenTransaction.
         let proven_transaction =
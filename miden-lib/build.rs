@@ -316,6 +316,10 @@ const RPO_FALCON_AUTH_CODE: &str = "
     export.::miden::contracts::auth::basic::auth_tx_rpo_falcon512
 ";
 
+const BASIC_CODE_UPGRADE_CODE: &str = "
+    export.::miden::contracts::upgrade::basic::set_code
+";
+
 const BASIC_FUNGIBLE_FAUCET_CODE: &str = "
     export.::miden::contracts::faucets::basic_fungible::distribute
     export.::miden::contracts::faucets::basic_fungible::burn
@@ -328,6 +332,7 @@ fn compile_account_components(target_dir: &Path, assembler: Assembler) -> Result
         ("basic_wallet", BASIC_WALLET_CODE),
         ("rpo_falcon_512", RPO_FALCON_AUTH_CODE),
         ("basic_fungible_faucet", BASIC_FUNGIBLE_FAUCET_CODE),
+        ("basic_code_upgrade", BASIC_CODE_UPGRADE_CODE),
     ] {
         let component_library = assembler.clone().assemble_library([component_code])?;
         let component_file_path =
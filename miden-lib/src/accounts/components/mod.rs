@@ -26,6 +26,15 @@ static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Fungible Faucet library is well-formed")
 });
 
+// Initialize the Basic Code Upgrade library only once.
+static BASIC_CODE_UPGRADE_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/basic_code_upgrade.masl"
+    ));
+    Library::read_from_bytes(bytes).expect("Shipped Basic Code Upgrade library is well-formed")
+});
+
 /// Returns the Basic Wallet Library.
 pub fn basic_wallet_library() -> Library {
     BASIC_WALLET_LIBRARY.clone()
@@ -40,3 +49,8 @@ pub fn rpo_falcon_512_library() -> Library {
 pub fn basic_fungible_faucet_library() -> Library {
     BASIC_FUNGIBLE_FAUCET_LIBRARY.clone()
 }
+
+/// Returns the Basic Code Upgrade Library.
+pub fn basic_code_upgrade_library() -> Library {
+    BASIC_CODE_UPGRADE_LIBRARY.clone()
+}
@@ -4,12 +4,26 @@ use miden_objects::{
     accounts::{
         Account, AccountBuilder, AccountComponent, AccountStorageMode, AccountType, StorageSlot,
     },
-    assets::TokenSymbol,
-    AccountError, Felt, FieldElement, Word,
+    assets::{FungibleAsset, TokenSymbol},
+    utils::sync::LazyLock,
+    AccountError, AssetError, Digest, Felt, FieldElement, Word,
 };
 
 use super::AuthScheme;
-use crate::accounts::{auth::RpoFalcon512, components::basic_fungible_faucet_library};
+use crate::{
+    accounts::{auth::RpoFalcon512, components::basic_fungible_faucet_library},
+    transaction::memory::FAUCET_STORAGE_DATA_SLOT,
+};
+
+// Initialize the root of a procedure of the basic fungible faucet component only once.
+static BASIC_FUNGIBLE_FAUCET_PROCEDURE_ROOT: LazyLock<Digest> = LazyLock::new(|| {
+    let library = basic_fungible_faucet_library();
+    let export = library
+        .exports()
+        .next()
+        .expect("Basic Fungible Faucet library exports a procedure");
+    library.mast_forest()[library.get_export_node_id(export)].digest()
+});
 
 // BASIC FUNGIBLE FAUCET ACCOUNT COMPONENT
 // ================================================================================================
@@ -47,6 +61,38 @@ impl BasicFungibleFaucet {
 
         Ok(Self { symbol, decimals, max_supply })
     }
+
+    /// Returns the [`BasicFungibleFaucet`] component of the provided account, read from the
+    /// metadata stored in the account, or None if the account does not contain the component.
+    pub fn from_account(account: &Account) -> Option<Self> {
+        let code = account.code();
+        let procedure_index =
+            code.get_procedure_index_by_root(*BASIC_FUNGIBLE_FAUCET_PROCEDURE_ROOT)?;
+        let metadata_slot = code.get_procedure_by_index(procedure_index).storage_offset();
+        let [max_supply, decimals, symbol, _] =
+            Word::from(account.storage().get_item(metadata_slot).ok()?);
+
+        let decimals = u8::try_from(decimals.as_int()).ok()?;
+        Self::new(TokenSymbol::try_from(symbol).ok()?, decimals, max_supply).ok()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the tokens issued by the faucet.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the number of decimals of the tokens issued by the faucet.
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// Returns the maximum amount of tokens the faucet may issue.
+    pub fn max_supply(&self) -> Felt {
+        self.max_supply
+    }
 }
 
 impl From<BasicFungibleFaucet> for AccountComponent {
@@ -65,6 +111,65 @@ impl From<BasicFungibleFaucet> for AccountComponent {
 // FUNGIBLE FAUCET
 // ================================================================================================
 
+/// Read access to the issuance data which the transaction kernel maintains for fungible faucets.
+pub trait FungibleFaucet {
+    /// Returns the total amount of tokens issued by this faucet, i.e., the amount minted minus
+    /// the amount burned.
+    ///
+    /// The amount is read from the reserved faucet storage slot, which holds
+    /// `[0, 0, 0, total_issued]`. Returns 0 for accounts which are not fungible faucets.
+    fn total_issued(&self) -> u64;
+}
+
+impl FungibleFaucet for Account {
+    fn total_issued(&self) -> u64 {
+        if self.account_type() != AccountType::FungibleFaucet {
+            return 0;
+        }
+
+        self.storage()
+            .get_item(FAUCET_STORAGE_DATA_SLOT)
+            .map(|faucet_data| Word::from(faucet_data)[3].as_int())
+            .unwrap_or_default()
+    }
+}
+
+// FUNGIBLE ASSET VALIDATION
+// ================================================================================================
+
+/// Validation of fungible assets against the metadata of the faucet which issues them.
+pub trait FungibleAssetValidation {
+    /// Checks that this asset could have been issued by the provided faucet.
+    ///
+    /// The faucet must contain the [`BasicFungibleFaucet`] component, whose metadata is read from
+    /// the storage of the faucet. Since asset amounts are expressed in the smallest unit of the
+    /// token, the number of decimals of the faucet does not restrict the amount.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The asset was not issued by the provided faucet.
+    /// - The faucet does not contain valid basic fungible faucet metadata.
+    /// - The amount of the asset exceeds the max supply of the faucet.
+    fn validate_against_faucet(&self, faucet: &Account) -> Result<(), AssetError>;
+}
+
+impl FungibleAssetValidation for FungibleAsset {
+    fn validate_against_faucet(&self, faucet: &Account) -> Result<(), AssetError> {
+        if self.faucet_id() != faucet.id() {
+            return Err(AssetError::InconsistentFaucetIds(self.faucet_id(), faucet.id()));
+        }
+
+        let metadata = BasicFungibleFaucet::from_account(faucet)
+            .ok_or(AssetError::FaucetMetadataNotFound(faucet.id()))?;
+        let max_supply = metadata.max_supply().as_int();
+        if self.amount() > max_supply {
+            return Err(AssetError::AmountExceedsMaxSupply { amount: self.amount(), max_supply });
+        }
+
+        Ok(())
+    }
+}
+
 const MAX_MAX_SUPPLY: u64 = (1 << 63) - 1;
 const MAX_DECIMALS: u8 = 12;
 
@@ -113,10 +218,16 @@ pub fn create_basic_fungible_faucet(
 
 #[cfg(test)]
 mod tests {
-    use miden_objects::{crypto::dsa::rpo_falcon512, FieldElement, ONE};
+    use miden_objects::{
+        accounts::account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, assets::FungibleAsset,
+        crypto::dsa::rpo_falcon512, AssetError, FieldElement, ONE,
+    };
     use vm_processor::Word;
 
-    use super::{create_basic_fungible_faucet, AccountStorageMode, AuthScheme, Felt, TokenSymbol};
+    use super::{
+        create_basic_fungible_faucet, AccountStorageMode, AuthScheme, BasicFungibleFaucet, Felt,
+        FungibleAssetValidation, FungibleFaucet, TokenSymbol,
+    };
 
     #[test]
     fn faucet_contract_creation() {
@@ -160,5 +271,42 @@ mod tests {
         );
 
         assert!(faucet_account.is_faucet());
+
+        // No tokens have been issued yet.
+        assert_eq!(faucet_account.total_issued(), 0);
+    }
+
+    #[test]
+    fn fungible_asset_validation() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let (faucet_account, _) = create_basic_fungible_faucet(
+            [7; 32],
+            TokenSymbol::try_from("POL").unwrap(),
+            2,
+            Felt::new(123),
+            AccountStorageMode::Private,
+            AuthScheme::RpoFalcon512 { pub_key },
+        )
+        .unwrap();
+
+        let metadata = BasicFungibleFaucet::from_account(&faucet_account).unwrap();
+        assert_eq!(metadata.decimals(), 2);
+        assert_eq!(metadata.max_supply(), Felt::new(123));
+
+        let asset = FungibleAsset::new(faucet_account.id(), 123).unwrap();
+        asset.validate_against_faucet(&faucet_account).unwrap();
+
+        let asset = FungibleAsset::new(faucet_account.id(), 124).unwrap();
+        assert_eq!(
+            asset.validate_against_faucet(&faucet_account),
+            Err(AssetError::AmountExceedsMaxSupply { amount: 124, max_supply: 123 })
+        );
+
+        let other_faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+        let asset = FungibleAsset::new(other_faucet_id, 1).unwrap();
+        assert_eq!(
+            asset.validate_against_faucet(&faucet_account),
+            Err(AssetError::InconsistentFaucetIds(other_faucet_id, faucet_account.id()))
+        );
     }
 }
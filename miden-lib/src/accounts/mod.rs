@@ -3,4 +3,5 @@ use super::auth::AuthScheme;
 pub mod auth;
 pub(super) mod components;
 pub mod faucets;
+pub mod upgrade;
 pub mod wallets;
@@ -0,0 +1,55 @@
+use miden_objects::{
+    accounts::{AccountCode, AccountComponent, AccountType},
+    utils::sync::LazyLock,
+    Digest,
+};
+
+use crate::accounts::components::basic_code_upgrade_library;
+
+// Initialize the root of the code upgrade procedure only once.
+static SET_CODE_PROCEDURE_ROOT: LazyLock<Digest> = LazyLock::new(|| {
+    let library = basic_code_upgrade_library();
+    let export = library
+        .exports()
+        .next()
+        .expect("Basic Code Upgrade library exports a procedure");
+    library.mast_forest()[library.get_export_node_id(export)].digest()
+});
+
+// BASIC CODE UPGRADE
+// ================================================================================================
+
+/// An [`AccountComponent`] allowing the code of an account to be upgraded.
+///
+/// Its exported procedures are:
+/// - `set_code`, which can be used to replace the code of the account with the code with the
+///   specified commitment once the transaction has been executed.
+///
+/// The procedure does not perform any authentication. Thus, this component must be combined with
+/// a component providing authentication.
+///
+/// This component supports only regular accounts with updatable code.
+pub struct BasicCodeUpgrade;
+
+impl BasicCodeUpgrade {
+    /// Returns the MAST root of the `set_code` procedure of this component.
+    pub fn set_code_procedure_root() -> Digest {
+        *SET_CODE_PROCEDURE_ROOT
+    }
+
+    /// Returns true if the provided account code contains the `set_code` procedure of this
+    /// component.
+    pub fn is_included_in(code: &AccountCode) -> bool {
+        code.has_procedure(Self::set_code_procedure_root())
+    }
+}
+
+impl From<BasicCodeUpgrade> for AccountComponent {
+    fn from(_: BasicCodeUpgrade) -> Self {
+        AccountComponent::new(basic_code_upgrade_library(), vec![])
+            .expect(
+                "basic code upgrade component should satisfy the requirements of a valid account component",
+            )
+            .with_supported_type(AccountType::RegularAccountUpdatableCode)
+    }
+}
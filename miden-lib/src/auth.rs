@@ -1,4 +1,18 @@
-use miden_objects::crypto::dsa::rpo_falcon512;
+use miden_objects::{
+    accounts::Account,
+    crypto::dsa::rpo_falcon512::{self, PublicKey},
+    utils::sync::LazyLock,
+    Digest,
+};
+
+use crate::accounts::components::rpo_falcon_512_library;
+
+// Initialize the root of the RPO Falcon512 authentication procedure only once.
+static RPO_FALCON_512_PROCEDURE_ROOT: LazyLock<Digest> = LazyLock::new(|| {
+    let library = rpo_falcon_512_library();
+    let export = library.exports().next().expect("Rpo Falcon 512 library exports a procedure");
+    library.mast_forest()[library.get_export_node_id(export)].digest()
+});
 
 /// Defines authentication schemes available to standard and faucet accounts.
 pub enum AuthScheme {
@@ -8,3 +22,30 @@ pub enum AuthScheme {
     /// we use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
 }
+
+impl AuthScheme {
+    /// Returns the authentication scheme used by the provided account, or `None` if the account
+    /// does not use any of the known authentication schemes.
+    ///
+    /// An account uses the RPO Falcon512 scheme if its code contains the
+    /// `auth_tx_rpo_falcon512` procedure, in which case the public key is read from the first
+    /// storage slot accessible to this procedure.
+    pub fn from_account(account: &Account) -> Option<Self> {
+        let procedure = account
+            .code()
+            .procedures()
+            .iter()
+            .find(|procedure| *procedure.mast_root() == *RPO_FALCON_512_PROCEDURE_ROOT)?;
+        let pub_key = account.storage().get_item(procedure.storage_offset()).ok()?;
+
+        Some(Self::RpoFalcon512 { pub_key: PublicKey::new(pub_key.into()) })
+    }
+
+    /// Returns the MAST root of the account procedure which authenticates transactions using this
+    /// scheme.
+    pub fn procedure_root(&self) -> Digest {
+        match self {
+            Self::RpoFalcon512 { .. } => *RPO_FALCON_512_PROCEDURE_ROOT,
+        }
+    }
+}
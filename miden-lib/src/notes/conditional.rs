@@ -0,0 +1,174 @@
+use alloc::{string::ToString, vec, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::Asset,
+    crypto::rand::FeltRng,
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+        NoteTag, NoteType,
+    },
+    Felt, NoteError, ZERO,
+};
+
+use super::scripts;
+
+// CONDITIONAL NOTE TEMPLATE
+// ================================================================================================
+
+/// A template for notes built from the CONDITIONAL note script, i.e., notes which can be consumed
+/// only once a set of conditions is met.
+///
+/// A note built from this template transfers its assets to the consuming account if:
+/// - The consuming account is the consumer specified by the template, if any.
+/// - The transaction is executed against the earliest block specified by the template or a later
+///   one, if any.
+///
+/// The conditions are encoded in the note inputs, which are laid out as follows:
+///
+/// ```text
+/// [consumer_account_id, earliest_block_num]
+/// ```
+///
+/// where a value of `0` means that the corresponding condition does not apply. When the earliest
+/// block is specified, it is also recorded in the execution hint of the note, so that transactions
+/// consuming the note too early can be rejected without executing its script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConditionalNoteTemplate {
+    assets: Vec<Asset>,
+    consumer: Option<AccountId>,
+    earliest_block: Option<u32>,
+}
+
+impl ConditionalNoteTemplate {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The number of inputs expected by the CONDITIONAL note script.
+    pub const NUM_INPUTS: usize = 2;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ConditionalNoteTemplate] carrying the provided assets, which can be consumed
+    /// by any account at any block.
+    pub fn new(assets: Vec<Asset>) -> Self {
+        Self {
+            assets,
+            consumer: None,
+            earliest_block: None,
+        }
+    }
+
+    /// Restricts the consumption of the note to the account with the specified ID.
+    pub fn with_consumer(mut self, consumer: AccountId) -> Self {
+        self.consumer = Some(consumer);
+        self
+    }
+
+    /// Restricts the consumption of the note to transactions executed against the specified
+    /// block or a later one.
+    pub fn with_earliest_block(mut self, block_num: u32) -> Self {
+        self.earliest_block = Some(block_num);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the assets carried by the note.
+    pub fn assets(&self) -> &[Asset] {
+        &self.assets
+    }
+
+    /// Returns the ID of the only account which can consume the note, if any.
+    pub fn consumer(&self) -> Option<AccountId> {
+        self.consumer
+    }
+
+    /// Returns the number of the earliest block at which the note can be consumed, if any.
+    pub fn earliest_block(&self) -> Option<u32> {
+        self.earliest_block
+    }
+
+    /// Returns the CONDITIONAL note script.
+    pub fn script(&self) -> NoteScript {
+        scripts::conditional()
+    }
+
+    /// Returns the note inputs encoding the conditions of this template.
+    ///
+    /// # Errors
+    /// Returns an error if the note inputs could not be built.
+    pub fn inputs(&self) -> Result<NoteInputs, NoteError> {
+        let consumer = self.consumer.map(Felt::from).unwrap_or(ZERO);
+        let earliest_block = self.earliest_block.map(Felt::from).unwrap_or(ZERO);
+        NoteInputs::new(vec![consumer, earliest_block])
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the parameters of this template are coherent for a note created in the block
+    /// with the specified number.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The template does not carry any assets, or the assets cannot be put into a note.
+    /// - The earliest block is not after the specified block, in which case the condition would
+    ///   never restrict the consumption of the note.
+    pub fn validate(&self, current_block: u32) -> Result<(), NoteError> {
+        if self.assets.is_empty() {
+            return Err(NoteError::InvalidNoteInputs(
+                "conditional note must carry at least one asset".to_string(),
+            ));
+        }
+        NoteAssets::new(self.assets.clone())?;
+
+        if let Some(earliest_block) = self.earliest_block {
+            if earliest_block <= current_block {
+                return Err(NoteError::InvalidNoteInputs(alloc::format!(
+                    "earliest block {earliest_block} of conditional note must be after block {current_block}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // NOTE BUILDING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new note created by `sender` in the block with the specified number from this
+    /// template.
+    ///
+    /// The passed-in `rng` is used to generate a serial number for the note. The execution hint
+    /// of the note is set to the earliest block if specified.
+    ///
+    /// # Errors
+    /// Returns an error if the template is not valid for the specified block (see
+    /// [Self::validate()]), or if the note could not be built.
+    pub fn build_note<R: FeltRng>(
+        &self,
+        sender: AccountId,
+        current_block: u32,
+        note_type: NoteType,
+        tag: NoteTag,
+        aux: Felt,
+        rng: &mut R,
+    ) -> Result<Note, NoteError> {
+        self.validate(current_block)?;
+
+        let serial_num = rng.draw_word();
+        let recipient = NoteRecipient::new(serial_num, self.script(), self.inputs()?);
+
+        let execution_hint = match self.earliest_block {
+            Some(block_num) => NoteExecutionHint::after_block(block_num),
+            None => NoteExecutionHint::always(),
+        };
+
+        let vault = NoteAssets::new(self.assets.clone())?;
+        let metadata = NoteMetadata::new(sender, note_type, tag, execution_hint, aux)?;
+        Ok(Note::new(vault, metadata, recipient))
+    }
+}
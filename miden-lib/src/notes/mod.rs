@@ -15,6 +15,12 @@ use utils::build_swap_tag;
 pub mod scripts;
 pub mod utils;
 
+mod conditional;
+pub use conditional::ConditionalNoteTemplate;
+
+mod well_known_note;
+pub use well_known_note::{ExpectedArgs, P2idrNote, SwapNote, WellKnownNote};
+
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
@@ -36,15 +42,12 @@ pub fn create_p2id_note<R: FeltRng>(
     aux: Felt,
     rng: &mut R,
 ) -> Result<Note, NoteError> {
-    let note_script = scripts::p2id();
-
-    let inputs = NoteInputs::new(vec![target.into()])?;
-    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
     let serial_num = rng.draw_word();
+    let recipient = utils::build_p2id_recipient(target, serial_num)?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
 
     let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
     let vault = NoteAssets::new(assets)?;
-    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
     Ok(Note::new(vault, metadata, recipient))
 }
 
@@ -69,15 +72,12 @@ pub fn create_p2idr_note<R: FeltRng>(
     recall_height: u32,
     rng: &mut R,
 ) -> Result<Note, NoteError> {
-    let note_script = scripts::p2idr();
-
-    let inputs = NoteInputs::new(vec![target.into(), recall_height.into()])?;
-    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
     let serial_num = rng.draw_word();
+    let recipient = utils::build_p2idr_recipient(target, recall_height, serial_num)?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
 
     let vault = NoteAssets::new(assets)?;
     let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
-    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
     Ok(Note::new(vault, metadata, recipient))
 }
 
@@ -89,7 +89,9 @@ pub fn create_p2idr_note<R: FeltRng>(
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
 /// # Errors
-/// Returns an error if deserialization or compilation of the `SWAP` script fails.
+/// Returns an error if:
+/// - The offered and requested assets are the same.
+/// - Deserialization or compilation of the `SWAP` script fails.
 pub fn create_swap_note<R: FeltRng>(
     sender: AccountId,
     offered_asset: Asset,
@@ -98,6 +100,10 @@ pub fn create_swap_note<R: FeltRng>(
     aux: Felt,
     rng: &mut R,
 ) -> Result<(Note, NoteDetails), NoteError> {
+    if offered_asset == requested_asset {
+        return Err(NoteError::IdenticalSwapAssets(offered_asset));
+    }
+
     let note_script = scripts::swap();
 
     let payback_serial_num = rng.draw_word();
@@ -136,3 +142,158 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_note))
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+            },
+            AccountId,
+        },
+        assets::{AssetVault, FungibleAsset},
+        crypto::rand::RpoRandomCoin,
+        notes::{NoteExecutionHint, NoteExecutionMode, NoteScript, NoteTag, NoteType},
+        Felt, NoteError, ZERO,
+    };
+
+    use super::{
+        create_p2id_note, create_p2idr_note, create_swap_note, scripts,
+        utils::build_p2id_recipient, ConditionalNoteTemplate, ExpectedArgs, P2idrNote, SwapNote,
+        WellKnownNote,
+    };
+    use crate::transaction::TransactionKernel;
+
+    #[test]
+    fn note_script_expected_args() {
+        assert_eq!(scripts::p2id().expected_args(), Some(1));
+        assert_eq!(scripts::p2idr().expected_args(), Some(2));
+        assert_eq!(scripts::swap().expected_args(), Some(10));
+
+        // the inputs of custom scripts can not be determined statically
+        let script =
+            NoteScript::compile("begin push.1 drop end", TransactionKernel::assembler()).unwrap();
+        assert_eq!(script.expected_args(), None);
+    }
+
+    #[test]
+    fn p2id_note_matches_standard_script() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let note = create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, &mut rng)
+            .unwrap();
+
+        assert_eq!(WellKnownNote::from_note(&note), Some(WellKnownNote::P2ID));
+        assert_eq!(note.inputs().values(), [target.into()]);
+        assert_eq!(note.inputs().num_values() as usize, WellKnownNote::P2ID.num_expected_inputs());
+        assert_eq!(
+            note.recipient().digest(),
+            build_p2id_recipient(target, note.serial_num()).unwrap().digest()
+        );
+        assert_eq!(note.metadata().sender(), sender);
+    }
+
+    #[test]
+    fn p2idr_note_predicates() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+        let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let note =
+            create_p2idr_note(sender, target, vec![asset], NoteType::Public, ZERO, 10, &mut rng)
+                .unwrap();
+        assert_eq!(WellKnownNote::from_note(&note), Some(WellKnownNote::P2IDR));
+        assert_eq!(note.inputs().values(), [target.into(), Felt::from(10_u32)]);
+
+        let p2idr = P2idrNote::try_from(note).unwrap();
+        assert_eq!(p2idr.target(), target);
+        assert_eq!(p2idr.recall_height(), 10);
+        assert!(!p2idr.can_be_reclaimed_at(9));
+        assert!(p2idr.can_be_reclaimed_at(10));
+        assert!(p2idr.is_claimable_by(target));
+        assert!(!p2idr.is_claimable_by(sender));
+
+        // P2ID notes are rejected
+        let p2id = create_p2id_note(sender, target, vec![asset], NoteType::Public, ZERO, &mut rng)
+            .unwrap();
+        assert!(matches!(P2idrNote::try_from(p2id), Err(NoteError::NoteScriptMismatch { .. })));
+    }
+
+    #[test]
+    fn swap_note_matching() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let offered = FungibleAsset::new(faucet_1, 100).unwrap().into();
+        let requested = FungibleAsset::new(faucet_2, 50).unwrap().into();
+
+        let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let err = create_swap_note(sender, offered, offered, NoteType::Public, ZERO, &mut rng)
+            .unwrap_err();
+        assert_eq!(err, NoteError::IdenticalSwapAssets(offered));
+
+        let (note, payback) =
+            create_swap_note(sender, offered, requested, NoteType::Public, ZERO, &mut rng).unwrap();
+        assert_eq!(WellKnownNote::from_note(&note), Some(WellKnownNote::SWAP));
+
+        let swap = SwapNote::try_from(note).unwrap();
+        assert_eq!(swap.offered_asset(), offered);
+        assert_eq!(swap.requested_asset(), requested);
+        assert_eq!(swap.payback_recipient_digest(), payback.recipient().digest());
+
+        let short_vault =
+            AssetVault::new(&[FungibleAsset::new(faucet_2, 49).unwrap().into()]).unwrap();
+        let full_vault =
+            AssetVault::new(&[FungibleAsset::new(faucet_2, 50).unwrap().into()]).unwrap();
+        assert!(!swap.can_be_filled_by(&short_vault));
+        assert!(swap.can_be_filled_by(&full_vault));
+        assert!(!swap.can_be_filled_by(&AssetVault::default()));
+    }
+
+    #[test]
+    fn conditional_note_template() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let consumer =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+        let tag = NoteTag::from_account_id(consumer, NoteExecutionMode::Local).unwrap();
+
+        // the template must carry assets and the earliest block must be in the future
+        assert!(matches!(
+            ConditionalNoteTemplate::new(vec![]).validate(4),
+            Err(NoteError::InvalidNoteInputs(_))
+        ));
+        let template = ConditionalNoteTemplate::new(vec![asset]).with_earliest_block(4);
+        assert!(matches!(template.validate(4), Err(NoteError::InvalidNoteInputs(_))));
+        assert_eq!(template.validate(3), Ok(()));
+
+        // unset conditions are encoded as zeros
+        let template = ConditionalNoteTemplate::new(vec![asset]);
+        assert_eq!(template.inputs().unwrap().values(), [ZERO, ZERO]);
+
+        let template = template.with_consumer(consumer).with_earliest_block(10);
+        assert_eq!(template.inputs().unwrap().values(), [consumer.into(), Felt::from(10_u32)]);
+        assert_eq!(template.script().expected_args(), None);
+
+        let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let note = template.build_note(sender, 4, NoteType::Public, tag, ZERO, &mut rng).unwrap();
+        assert_eq!(note.script().hash(), scripts::conditional().hash());
+        assert_eq!(note.inputs().num_values() as usize, ConditionalNoteTemplate::NUM_INPUTS);
+        assert_eq!(note.metadata().execution_hint(), NoteExecutionHint::after_block(10));
+        assert_eq!(WellKnownNote::from_note(&note), None);
+    }
+}
@@ -18,6 +18,14 @@ static P2IDR_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the CONDITIONAL note script only once
+static CONDITIONAL_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/CONDITIONAL.masb"));
+    let program =
+        Program::read_from_bytes(bytes).expect("Shipped CONDITIONAL script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the SWAP note script only once
 static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
@@ -35,6 +43,11 @@ pub fn p2idr() -> NoteScript {
     P2IDR_SCRIPT.clone()
 }
 
+/// Returns the CONDITIONAL (conditional consumption) note script.
+pub fn conditional() -> NoteScript {
+    CONDITIONAL_SCRIPT.clone()
+}
+
 /// Returns the SWAP (Swap note) note script.
 pub fn swap() -> NoteScript {
     SWAP_SCRIPT.clone()
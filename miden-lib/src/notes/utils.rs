@@ -1,12 +1,14 @@
+use alloc::vec;
+
 use miden_objects::{
     accounts::AccountId,
     assets::Asset,
-    notes::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteScript, NoteTag, NoteType},
-    utils::Deserializable,
-    vm::Program,
+    notes::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType},
     NoteError, Word,
 };
 
+use super::scripts;
+
 /// Creates a [NoteRecipient] for the P2ID note.
 ///
 /// Notes created with this recipient will be P2ID notes consumable by the specified target
@@ -15,13 +17,23 @@ pub fn build_p2id_recipient(
     target: AccountId,
     serial_num: Word,
 ) -> Result<NoteRecipient, NoteError> {
-    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
-    let program =
-        Program::read_from_bytes(bytes).map_err(NoteError::NoteScriptDeserializationError)?;
-    let note_script = NoteScript::new(program);
     let note_inputs = NoteInputs::new(vec![target.into()])?;
 
-    Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
+    Ok(NoteRecipient::new(serial_num, scripts::p2id(), note_inputs))
+}
+
+/// Creates a [NoteRecipient] for the P2IDR note.
+///
+/// Notes created with this recipient will be P2IDR notes consumable by the specified target
+/// account at any time, and by the sender of the note at or after `recall_height`.
+pub fn build_p2idr_recipient(
+    target: AccountId,
+    recall_height: u32,
+    serial_num: Word,
+) -> Result<NoteRecipient, NoteError> {
+    let note_inputs = NoteInputs::new(vec![target.into(), recall_height.into()])?;
+
+    Ok(NoteRecipient::new(serial_num, scripts::p2idr(), note_inputs))
 }
 
 /// Returns a note tag for a swap note with the specified parameters.
@@ -0,0 +1,286 @@
+use alloc::string::ToString;
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, AssetVault},
+    notes::{Note, NoteScript},
+    Digest, NoteError, Word,
+};
+
+use super::scripts;
+
+// WELL KNOWN NOTE
+// ================================================================================================
+
+/// The notes built from the standard note scripts shipped with this library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WellKnownNote {
+    /// A pay-to-id note, see [super::create_p2id_note()].
+    P2ID,
+    /// A pay-to-id note with recall, see [super::create_p2idr_note()].
+    P2IDR,
+    /// A swap note, see [super::create_swap_note()].
+    SWAP,
+}
+
+impl WellKnownNote {
+    /// The number of inputs expected by the P2ID note script.
+    const P2ID_NUM_INPUTS: usize = 1;
+
+    /// The number of inputs expected by the P2IDR note script.
+    const P2IDR_NUM_INPUTS: usize = 2;
+
+    /// The number of inputs expected by the SWAP note script.
+    const SWAP_NUM_INPUTS: usize = 10;
+
+    /// Returns the kind of the provided note if its script is one of the standard note scripts,
+    /// or `None` otherwise.
+    pub fn from_note(note: &Note) -> Option<Self> {
+        Self::from_script(note.script())
+    }
+
+    /// Returns the kind of note built from the provided script if it is one of the standard note
+    /// scripts, or `None` otherwise.
+    pub fn from_script(script: &NoteScript) -> Option<Self> {
+        let script_root = script.hash();
+        [Self::P2ID, Self::P2IDR, Self::SWAP]
+            .into_iter()
+            .find(|well_known_note| well_known_note.script_root() == script_root)
+    }
+
+    /// Returns the number of inputs expected by the script of this note.
+    pub fn num_expected_inputs(&self) -> usize {
+        match self {
+            Self::P2ID => Self::P2ID_NUM_INPUTS,
+            Self::P2IDR => Self::P2IDR_NUM_INPUTS,
+            Self::SWAP => Self::SWAP_NUM_INPUTS,
+        }
+    }
+
+    /// Returns the script of this note.
+    pub fn script(&self) -> NoteScript {
+        match self {
+            Self::P2ID => scripts::p2id(),
+            Self::P2IDR => scripts::p2idr(),
+            Self::SWAP => scripts::swap(),
+        }
+    }
+
+    /// Returns the MAST root of the script of this note.
+    pub fn script_root(&self) -> Digest {
+        self.script().hash()
+    }
+}
+
+// EXPECTED ARGS
+// ================================================================================================
+
+/// Introspection of the number of inputs a note script expects.
+pub trait ExpectedArgs {
+    /// Returns the number of inputs the note script expects, or `None` if the number can not be
+    /// determined statically.
+    ///
+    /// The number of expected inputs is known for the standard note scripts (see
+    /// [WellKnownNote]). Custom note scripts do not declare their inputs, so `None` is returned
+    /// for them rather than a guess.
+    fn expected_args(&self) -> Option<usize>;
+}
+
+impl ExpectedArgs for NoteScript {
+    fn expected_args(&self) -> Option<usize> {
+        WellKnownNote::from_script(self)
+            .map(|well_known_note| well_known_note.num_expected_inputs())
+    }
+}
+
+// P2IDR NOTE
+// ================================================================================================
+
+/// A view over a P2IDR note exposing the parameters encoded in its inputs.
+///
+/// P2IDR notes can be consumed by the target account at any time, and reclaimed by the sender of
+/// the note in any block at or after the recall height.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct P2idrNote {
+    note: Note,
+    target: AccountId,
+    recall_height: u32,
+}
+
+impl P2idrNote {
+    /// Returns the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+
+    /// Returns the ID of the account this note is intended for.
+    pub fn target(&self) -> AccountId {
+        self.target
+    }
+
+    /// Returns the block number starting from which the sender can reclaim the note.
+    pub fn recall_height(&self) -> u32 {
+        self.recall_height
+    }
+
+    /// Returns true if the sender of the note can reclaim it in a transaction executed against
+    /// the specified block.
+    pub fn can_be_reclaimed_at(&self, block_num: u32) -> bool {
+        block_num >= self.recall_height
+    }
+
+    /// Returns true if the note can be claimed by the specified account regardless of the block
+    /// against which the transaction is executed.
+    pub fn is_claimable_by(&self, account_id: AccountId) -> bool {
+        account_id == self.target
+    }
+
+    /// Consumes self and returns the underlying note.
+    pub fn into_note(self) -> Note {
+        self.note
+    }
+}
+
+impl TryFrom<Note> for P2idrNote {
+    type Error = NoteError;
+
+    /// Returns a [P2idrNote] for the provided note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script of the note is not the P2IDR script.
+    /// - The inputs of the note do not follow the layout expected by the P2IDR script, i.e.,
+    ///   `[target_account_id, recall_height]`.
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        let expected = WellKnownNote::P2IDR.script_root();
+        let actual = note.script().hash();
+        if expected != actual {
+            return Err(NoteError::NoteScriptMismatch { expected, actual });
+        }
+
+        let (target, recall_height) = match note.inputs().values() {
+            [target, recall_height] => (*target, recall_height.as_int()),
+            values => {
+                return Err(NoteError::InvalidNoteInputs(alloc::format!(
+                    "P2IDR note expects 2 inputs, but {} were provided",
+                    values.len()
+                )))
+            },
+        };
+        let target = AccountId::try_from(target)
+            .map_err(|err| NoteError::InvalidNoteInputs(err.to_string()))?;
+        let recall_height = u32::try_from(recall_height).map_err(|_| {
+            NoteError::InvalidNoteInputs(alloc::format!(
+                "P2IDR recall height {recall_height} does not fit into u32"
+            ))
+        })?;
+
+        Ok(Self { note, target, recall_height })
+    }
+}
+
+// SWAP NOTE
+// ================================================================================================
+
+/// A view over a SWAP note exposing the parameters encoded in its assets and inputs.
+///
+/// A SWAP note carries the offered asset; the account consuming it receives the offered asset and
+/// must create a payback note for the sender of the SWAP note containing the requested asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapNote {
+    note: Note,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    payback_recipient_digest: Digest,
+}
+
+impl SwapNote {
+    /// Returns the underlying note.
+    pub fn note(&self) -> &Note {
+        &self.note
+    }
+
+    /// Returns the asset received by the account consuming this note.
+    pub fn offered_asset(&self) -> Asset {
+        self.offered_asset
+    }
+
+    /// Returns the asset which the account consuming this note must pay back to the sender.
+    pub fn requested_asset(&self) -> Asset {
+        self.requested_asset
+    }
+
+    /// Returns the digest of the recipient of the payback note.
+    pub fn payback_recipient_digest(&self) -> Digest {
+        self.payback_recipient_digest
+    }
+
+    /// Returns true if an account with the specified vault holds enough of the requested asset to
+    /// consume this note.
+    pub fn can_be_filled_by(&self, vault: &AssetVault) -> bool {
+        match self.requested_asset {
+            Asset::Fungible(asset) => vault
+                .get_balance(asset.faucet_id())
+                .is_ok_and(|balance| balance >= asset.amount()),
+            Asset::NonFungible(_) => {
+                vault.has_non_fungible_asset(self.requested_asset).unwrap_or(false)
+            },
+        }
+    }
+
+    /// Consumes self and returns the underlying note.
+    pub fn into_note(self) -> Note {
+        self.note
+    }
+}
+
+impl TryFrom<Note> for SwapNote {
+    type Error = NoteError;
+
+    /// Returns a [SwapNote] for the provided note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script of the note is not the SWAP script.
+    /// - The note does not contain exactly one asset.
+    /// - The inputs of the note do not follow the layout expected by the SWAP script, i.e.,
+    ///   `[PAYBACK_RECIPIENT, REQUESTED_ASSET, payback_tag, payback_execution_hint]`.
+    fn try_from(note: Note) -> Result<Self, Self::Error> {
+        let expected = WellKnownNote::SWAP.script_root();
+        let actual = note.script().hash();
+        if expected != actual {
+            return Err(NoteError::NoteScriptMismatch { expected, actual });
+        }
+
+        let offered_asset = match note.assets().iter().as_slice() {
+            [asset] => *asset,
+            assets => {
+                return Err(NoteError::InvalidNoteInputs(alloc::format!(
+                    "SWAP note expects 1 asset, but {} were provided",
+                    assets.len()
+                )))
+            },
+        };
+
+        let inputs = note.inputs().values();
+        if inputs.len() != WellKnownNote::SWAP.num_expected_inputs() {
+            return Err(NoteError::InvalidNoteInputs(alloc::format!(
+                "SWAP note expects {} inputs, but {} were provided",
+                WellKnownNote::SWAP.num_expected_inputs(),
+                inputs.len()
+            )));
+        }
+        let payback_recipient_digest =
+            Digest::from(Word::try_from(&inputs[0..4]).expect("slice has 4 elements"));
+        let requested_asset =
+            Asset::try_from(Word::try_from(&inputs[4..8]).expect("slice has 4 elements"))
+                .map_err(NoteError::InvalidAssetData)?;
+
+        Ok(Self {
+            note,
+            offered_asset,
+            requested_asset,
+            payback_recipient_digest,
+        })
+    }
+}
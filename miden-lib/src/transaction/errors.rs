@@ -120,6 +120,34 @@ impl fmt::Display for TransactionKernelError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionKernelError {}
 
+// TRANSACTION PROGRAM ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TransactionProgramError {
+    AssemblyFailed(String),
+    MismatchedFragment {
+        fragment: &'static str,
+        procedure: &'static str,
+    },
+}
+
+impl fmt::Display for TransactionProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AssemblyFailed(err) => {
+                write!(f, "failed to assemble transaction program: {err}")
+            },
+            Self::MismatchedFragment { fragment, procedure } => {
+                write!(f, "the {fragment} fragment of the transaction program does not execute {procedure}")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionProgramError {}
+
 // TRANSACTION EVENT PARSING ERROR
 // ================================================================================================
 
@@ -34,6 +34,8 @@ const NOTE_AFTER_CREATED: u32 = 0x2_000c; // 131084
 const NOTE_BEFORE_ADD_ASSET: u32 = 0x2_000d; // 131085
 const NOTE_AFTER_ADD_ASSET: u32 = 0x2_000e; // 131086
 
+const ACCOUNT_STORAGE_GET_ITEM: u32 = 0x2_000f; // 131087
+
 /// Events which may be emitted by a transaction kernel.
 ///
 /// The events are emitted via the `emit.<event_id>` instruction. The event ID is a 32-bit
@@ -66,6 +68,8 @@ pub enum TransactionEvent {
 
     NoteBeforeAddAsset = NOTE_BEFORE_ADD_ASSET,
     NoteAfterAddAsset = NOTE_AFTER_ADD_ASSET,
+
+    AccountStorageGetItem = ACCOUNT_STORAGE_GET_ITEM,
 }
 
 impl fmt::Display for TransactionEvent {
@@ -112,6 +116,8 @@ impl TryFrom<u32> for TransactionEvent {
             NOTE_BEFORE_ADD_ASSET => Ok(TransactionEvent::NoteBeforeAddAsset),
             NOTE_AFTER_ADD_ASSET => Ok(TransactionEvent::NoteAfterAddAsset),
 
+            ACCOUNT_STORAGE_GET_ITEM => Ok(TransactionEvent::AccountStorageGetItem),
+
             _ => Err(TransactionEventParsingError::InvalidTransactionEvent(value)),
         }
     }
@@ -0,0 +1,319 @@
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use miden_objects::{
+    assembly::{
+        Assembler, Compile, CompileOptions, DefaultSourceManager, Library, Module, ModuleKind,
+        SourceManager,
+    },
+    vm::Program,
+    Digest,
+};
+use miden_stdlib::StdLibrary;
+
+use super::{TransactionKernel, TransactionProgramError};
+
+// CONSTANTS
+// ================================================================================================
+
+macro_rules! kernel_module_source {
+    ($name:literal) => {
+        include_str!(concat!(env!("OUT_DIR"), "/asm/kernels/transaction/lib/", $name, ".masm"))
+    };
+}
+
+/// The kernel procedure which must be executed by the prologue fragment.
+const PROLOGUE_PROCEDURE: &str = "kernel::prologue::prepare_transaction";
+
+/// The kernel procedure which must be executed by the epilogue fragment.
+const EPILOGUE_PROCEDURE: &str = "kernel::epilogue::finalize_transaction";
+
+/// The sources of the modules of the transaction kernel, as assembled into the kernel.
+const KERNEL_MODULES: [(&str, &str); 10] = [
+    ("account", kernel_module_source!("account")),
+    ("asset", kernel_module_source!("asset")),
+    ("asset_vault", kernel_module_source!("asset_vault")),
+    ("constants", kernel_module_source!("constants")),
+    ("epilogue", kernel_module_source!("epilogue")),
+    ("faucet", kernel_module_source!("faucet")),
+    ("memory", kernel_module_source!("memory")),
+    ("note", kernel_module_source!("note")),
+    ("prologue", kernel_module_source!("prologue")),
+    ("tx", kernel_module_source!("tx")),
+];
+
+/// The modules imported by the transaction program assembled from fragments.
+const PROGRAM_IMPORTS: &str = "\
+use.std::utils
+use.kernel::epilogue
+use.kernel::memory
+use.kernel::note
+use.kernel::prologue
+";
+
+// TRANSACTION PROGRAM FRAGMENTS
+// ================================================================================================
+
+/// The source code of the stages of a transaction program.
+///
+/// The standard transaction program (see [TransactionKernel::main()]) executes the following
+/// stages in sequence:
+/// - the prologue, which executes `prologue::prepare_transaction`.
+/// - the note processing loop, which executes the scripts of the input notes.
+/// - the transaction script processing, which executes the transaction script, if any.
+/// - the epilogue, which executes `epilogue::finalize_transaction` and leaves the outputs of the
+///   transaction on the stack.
+///
+/// Each fragment is a sequence of instructions which may invoke procedures of the `std::utils`,
+/// `kernel::epilogue`, `kernel::memory`, `kernel::note` and `kernel::prologue` modules, and which
+/// may use a single procedure local. The fragments are assembled, in order, into the body of the
+/// `main` procedure of the program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionProgramFragments {
+    prologue: String,
+    note_processing: String,
+    tx_script_processing: String,
+    epilogue: String,
+}
+
+impl TransactionProgramFragments {
+    /// Returns new [TransactionProgramFragments] instantiated from the source code of the
+    /// individual stages of a transaction program.
+    pub fn new(
+        prologue: impl Into<String>,
+        note_processing: impl Into<String>,
+        tx_script_processing: impl Into<String>,
+        epilogue: impl Into<String>,
+    ) -> Self {
+        Self {
+            prologue: prologue.into(),
+            note_processing: note_processing.into(),
+            tx_script_processing: tx_script_processing.into(),
+            epilogue: epilogue.into(),
+        }
+    }
+
+    /// Returns the source code of the prologue.
+    pub fn prologue(&self) -> &str {
+        &self.prologue
+    }
+
+    /// Returns the source code of the note processing loop.
+    pub fn note_processing(&self) -> &str {
+        &self.note_processing
+    }
+
+    /// Returns the source code of the transaction script processing.
+    pub fn tx_script_processing(&self) -> &str {
+        &self.tx_script_processing
+    }
+
+    /// Returns the source code of the epilogue.
+    pub fn epilogue(&self) -> &str {
+        &self.epilogue
+    }
+}
+
+// TRANSACTION KERNEL
+// ================================================================================================
+
+impl TransactionKernel {
+    /// Assembles a transaction program from the provided fragments.
+    ///
+    /// The program is assembled against the transaction kernel, and thus it can be executed in
+    /// place of [TransactionKernel::main()]. Assembling the fragments of the standard transaction
+    /// program results in a program with the same MAST root as [TransactionKernel::main()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the fragments cannot be assembled, e.g., because it invokes an unknown procedure.
+    /// - The prologue fragment does not execute `prologue::prepare_transaction` or the epilogue
+    ///   fragment does not execute `epilogue::finalize_transaction`.
+    pub fn compile_from_fragments(
+        fragments: &TransactionProgramFragments,
+    ) -> Result<Program, TransactionProgramError> {
+        let source_manager: Arc<dyn SourceManager> = Arc::new(DefaultSourceManager::default());
+        let assembler = Assembler::with_kernel(source_manager.clone(), Self::kernel())
+            .with_debug_mode(cfg!(feature = "with-debug-info"))
+            .with_library(StdLibrary::default())
+            .expect("failed to load std-lib");
+        let modules = kernel_modules(source_manager.as_ref());
+        let kernel_library = assembler
+            .clone()
+            .assemble_library(modules.clone())
+            .expect("failed to assemble kernel modules");
+
+        // the kernel modules are assembled together with the program, exactly as for the standard
+        // transaction program, so that the MAST of the program does not depend on how it has
+        // been assembled
+        let assembler = modules
+            .into_iter()
+            .try_fold(assembler, |assembler, module| assembler.with_module(module))
+            .expect("failed to add kernel modules");
+
+        for (fragment, source, procedure) in [
+            ("prologue", fragments.prologue(), PROLOGUE_PROCEDURE),
+            ("epilogue", fragments.epilogue(), EPILOGUE_PROCEDURE),
+        ] {
+            let program = assemble_program(assembler.clone(), source)?;
+            let procedure_root = kernel_procedure_root(&kernel_library, procedure);
+            if !program.mast_forest().nodes().iter().any(|node| node.digest() == procedure_root) {
+                return Err(TransactionProgramError::MismatchedFragment { fragment, procedure });
+            }
+        }
+
+        assemble_program(
+            assembler,
+            &[
+                fragments.prologue(),
+                fragments.note_processing(),
+                fragments.tx_script_processing(),
+                fragments.epilogue(),
+            ]
+            .join("\n"),
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the parsed modules of the transaction kernel.
+///
+/// # Panics
+/// Panics if the transaction kernel source is not well-formed.
+fn kernel_modules(source_manager: &dyn SourceManager) -> Vec<Module> {
+    KERNEL_MODULES
+        .iter()
+        .map(|(name, source)| {
+            let options = CompileOptions::new(ModuleKind::Library, format!("kernel::{name}"))
+                .expect("invalid kernel module path");
+            source
+                .compile_with_options(source_manager, options)
+                .map(|module| *module)
+                .expect("failed to parse kernel module")
+        })
+        .collect()
+}
+
+/// Assembles a transaction program whose `main` procedure consists of the provided code.
+fn assemble_program(assembler: Assembler, body: &str) -> Result<Program, TransactionProgramError> {
+    let source =
+        format!("{PROGRAM_IMPORTS}\nproc.main.1\n{body}\nend\n\nbegin\n    exec.main\nend\n");
+    assembler
+        .assemble_program(source)
+        .map_err(|err| TransactionProgramError::AssemblyFailed(err.to_string()))
+}
+
+/// Returns the MAST root of the specified procedure of the transaction kernel.
+///
+/// # Panics
+/// Panics if the procedure is not exported by the kernel library.
+fn kernel_procedure_root(kernel_library: &Library, procedure: &str) -> Digest {
+    let export = kernel_library
+        .exports()
+        .find(|export| export.to_string() == procedure)
+        .expect("kernel library exports the procedure");
+    kernel_library.mast_forest()[kernel_library.get_export_node_id(export)].digest()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{TransactionKernel, TransactionProgramError, TransactionProgramFragments};
+
+    const PROLOGUE: &str = "
+        push.0 drop
+        trace.131072
+        exec.prologue::prepare_transaction
+        push.1 drop
+        trace.131073";
+
+    const NOTE_PROCESSING: &str = "
+        push.2 drop
+        trace.131074
+        exec.memory::get_num_input_notes
+        dup exec.memory::get_input_note_ptr loc_store.0
+        eq.0 not
+        while.true
+            push.4 drop
+            trace.131076
+            exec.note::prepare_note
+            dyncall
+            dropw dropw dropw dropw
+            exec.note::increment_current_input_note_ptr
+            loc_load.0 neq
+            push.5 drop
+            trace.131077
+        end
+        exec.note::note_processing_teardown
+        push.3 drop
+        trace.131075";
+
+    const TX_SCRIPT_PROCESSING: &str = "
+        push.6 drop
+        trace.131078
+        exec.memory::get_tx_script_root_ptr
+        padw dup.4 mem_loadw
+        exec.utils::is_empty_word not movdn.4 dropw
+        if.true
+            dyncall
+            dropw dropw dropw dropw
+        else
+            drop
+        end
+        push.7 drop
+        trace.131079";
+
+    const EPILOGUE: &str = "
+        push.8 drop
+        trace.131080
+        exec.epilogue::finalize_transaction
+        push.9 drop
+        trace.131081";
+
+    #[test]
+    fn compile_standard_fragments() {
+        let fragments = TransactionProgramFragments::new(
+            PROLOGUE,
+            NOTE_PROCESSING,
+            TX_SCRIPT_PROCESSING,
+            EPILOGUE,
+        );
+        let program = TransactionKernel::compile_from_fragments(&fragments).unwrap();
+
+        assert_eq!(program.hash(), TransactionKernel::main().hash());
+        assert_eq!(program.kernel(), TransactionKernel::main().kernel());
+    }
+
+    #[test]
+    fn compile_invalid_fragments() {
+        // the prologue and the epilogue are swapped
+        let fragments = TransactionProgramFragments::new(
+            EPILOGUE,
+            NOTE_PROCESSING,
+            TX_SCRIPT_PROCESSING,
+            PROLOGUE,
+        );
+        let err = TransactionKernel::compile_from_fragments(&fragments).unwrap_err();
+        assert!(matches!(
+            err,
+            TransactionProgramError::MismatchedFragment { fragment: "prologue", .. }
+        ));
+
+        // the note processing loop invokes an unknown procedure
+        let fragments = TransactionProgramFragments::new(
+            PROLOGUE,
+            "exec.note::process_notes",
+            TX_SCRIPT_PROCESSING,
+            EPILOGUE,
+        );
+        let err = TransactionKernel::compile_from_fragments(&fragments).unwrap_err();
+        assert!(matches!(err, TransactionProgramError::AssemblyFailed(_)));
+    }
+}
@@ -2,6 +2,7 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::{Account, StorageSlot},
+    notes::NoteConsumptionProof,
     transaction::{ChainMmr, InputNote, TransactionArgs, TransactionInputs, TransactionScript},
     vm::AdviceInputs,
     Digest, Felt, FieldElement, Word, EMPTY_WORD, WORD_SIZE, ZERO,
@@ -205,6 +206,7 @@ fn add_account_to_advice_inputs(
 /// - For each note:
 ///     - The note's details (serial number, script root, and its input / assets hash).
 ///     - The note's private arguments.
+///     - The note's consumption proof, if one is provided.
 ///     - The note's public metadata.
 ///     - The note's public inputs data. Prefixed by its length and padded to an even word length.
 ///     - The note's asset padded. Prefixed by its length and padded to an even word length.
@@ -239,6 +241,13 @@ fn add_input_notes_to_advice_inputs(
 
         inputs.extend_map([(assets.commitment(), assets.to_padded_assets())]);
 
+        if let Some(proof) = tx_args.get_note_consumption_proof(note.id()) {
+            inputs.extend_map([(
+                NoteConsumptionProof::advice_key(recipient.serial_num()),
+                proof.elements().to_vec(),
+            )]);
+        }
+
         // NOTE: keep in sync with the `prologue::process_input_note_details` kernel procedure
         note_data.extend(recipient.serial_num());
         note_data.extend(*recipient.script().hash());
@@ -257,14 +266,15 @@ fn add_input_notes_to_advice_inputs(
         match input_note {
             InputNote::Authenticated { note, proof } => {
                 let block_num = proof.location().block_num();
-                let note_block_header = if block_num == tx_inputs.block_header().block_num() {
-                    tx_inputs.block_header()
-                } else {
-                    tx_inputs
-                        .block_chain()
-                        .get_block(block_num)
-                        .expect("block not found in chain MMR")
-                };
+                let note_block_header =
+                    if block_num == tx_inputs.block_header().block_num().as_u32() {
+                        tx_inputs.block_header()
+                    } else {
+                        tx_inputs
+                            .block_chain()
+                            .get_block(block_num)
+                            .expect("block not found in chain MMR")
+                    };
 
                 // NOTE: keep in sync with the `prologue::process_input_note` kernel procedure
                 // Push the `is_authenticated` flag
@@ -4,7 +4,7 @@ use alloc::{string::ToString, sync::Arc, vec::Vec};
 use miden_objects::accounts::AccountCode;
 use miden_objects::{
     accounts::AccountId,
-    assembly::{Assembler, DefaultSourceManager, KernelLibrary},
+    assembly::{Assembler, DefaultSourceManager, KernelLibrary, Library},
     transaction::{
         OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
     },
@@ -13,7 +13,6 @@ use miden_objects::{
     Digest, Felt, TransactionOutputError, Word, EMPTY_WORD,
 };
 use miden_stdlib::StdLibrary;
-use outputs::EXPIRATION_BLOCK_ELEMENT_IDX;
 
 use super::MidenLib;
 
@@ -26,14 +25,19 @@ mod inputs;
 
 mod outputs;
 pub use outputs::{
-    parse_final_account_header, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
+    parse_final_account_header, EXPIRATION_BLOCK_ELEMENT_IDX, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX,
 };
 
 mod errors;
 pub use errors::{
-    TransactionEventParsingError, TransactionKernelError, TransactionTraceParsingError,
+    TransactionEventParsingError, TransactionKernelError, TransactionProgramError,
+    TransactionTraceParsingError,
 };
 
+mod fragments;
+pub use fragments::TransactionProgramFragments;
+
 mod procedures;
 
 // CONSTANTS
@@ -115,9 +119,18 @@ impl TransactionKernel {
     /// Returns a new Miden assembler instantiated with the transaction kernel and loaded with the
     /// Miden stdlib as well as with miden-lib.
     pub fn assembler() -> Assembler {
+        Self::assembler_with_stdlib(StdLibrary::default().into())
+    }
+
+    /// Returns a new Miden assembler instantiated with the transaction kernel, miden-lib and the
+    /// provided version of the Miden standard library.
+    ///
+    /// This allows pinning the exact standard library code is compiled against, regardless of the
+    /// version of the standard library bundled with this crate.
+    pub fn assembler_with_stdlib(stdlib: Library) -> Assembler {
         let source_manager = Arc::new(DefaultSourceManager::default());
         Assembler::with_kernel(source_manager, Self::kernel())
-            .with_library(StdLibrary::default())
+            .with_library(stdlib)
             .expect("failed to load std-lib")
             .with_library(MidenLib::default())
             .expect("failed to load miden-lib")
@@ -18,9 +18,9 @@ pub const KERNEL0_PROCEDURES: [Digest; 32] = [
     // get_account_id
     digest!(0x6aced4e086435d59, 0xba76f46e942cef62, 0xb200b248f2dcd162, 0x1157061dd5f0d46f),
     // get_account_item
-    digest!(0x17eb77c44f50731a, 0xf3a47bd9edfcc9c9, 0x18ff2a567c82c997, 0x18dae9f808fda222),
+    digest!(0xfc4ecfd81911d4a6, 0x1f73cd19acebe749, 0xcb2014c2d0fc23f5, 0x3e973ac328a48ca6),
     // get_account_map_item
-    digest!(0xe8bc7c2fc9d43a2b, 0x3161e056efe3ea43, 0xbdc7cd1661a4417b, 0x23852218531bdad9),
+    digest!(0x9d0bdec3fcb29c77, 0xec70353c0809df97, 0xce42ae8431967603, 0xa16e00d5fbb25170),
     // get_account_nonce
     digest!(0xacdebda374aa5674, 0x8a0b1304eb02cffb, 0xeb0a5fb1b57207ea, 0xf76bd169121bf949),
     // get_account_vault_commitment
@@ -36,13 +36,13 @@ pub const KERNEL0_PROCEDURES: [Digest; 32] = [
     // set_account_item
     digest!(0x499f79c1362b9a07, 0x1b92269850061e98, 0x796953b19ee46adc, 0xe72dd36a7b0ddcbb),
     // set_account_map_item
-    digest!(0xb725af2709828550, 0xb7ba61b264bdcb95, 0x46fb74585d83ff7a, 0xa5f4990bf3f25698),
+    digest!(0x5161582172946f8a, 0x1ab0a8bdf45432b4, 0x96aaefb14a29fdcc, 0x6b2a1f198ba21ee7),
     // burn_asset
-    digest!(0xe04d74db39cc3b5b, 0x9cc09131a411012b, 0x1359ac8f13bfaf7c, 0xaa61f025022959d9),
+    digest!(0x8645911ce44356c, 0x13c6f2c40804302e, 0xa04f15ff9b254666, 0x182704983bd08d49),
     // get_fungible_faucet_total_issuance
-    digest!(0x9dc9fa65171ea10b, 0x3f7735402fe315d6, 0x7cedd24bf19310ad, 0x197e0ba67054fa98),
+    digest!(0xc669f8b160f02d31, 0xcaf9702f227f7cf4, 0xd8752936429b172c, 0x82f91fd5bb92dd12),
     // mint_asset
-    digest!(0x5f1b1ef4632de4c1, 0xde69adb0010e558b, 0x6c034d21e74f265b, 0x22e4b88e992799a0),
+    digest!(0x5dad53b2ba5e711b, 0x90e53db39dff9b62, 0x827419256c051a81, 0x21344e1d7a9ef79f),
     // add_asset_to_note
     digest!(0xa64a1ece11db2897, 0x892200d8bf530f42, 0x9d03e9da185baad3, 0xebcb1e54b2e23e3c),
     // create_note
@@ -1,9 +1,13 @@
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use core::fmt::{self, Display};
 
 use miden_objects::{
-    accounts::AccountId, notes::NoteId, AccountError, Felt, ProvenTransactionError,
-    TransactionInputError, TransactionOutputError,
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    block::BlockNumber,
+    notes::{NoteId, Nullifier},
+    AccountError, AssetVaultError, Digest, Felt, NoteError, ProvenTransactionError,
+    TransactionInputError, TransactionOutputError, TransactionScriptError,
 };
 use miden_verifier::VerificationError;
 use vm_processor::ExecutionError;
@@ -11,13 +15,25 @@ use vm_processor::ExecutionError;
 #[rustfmt::skip]
 pub mod tx_kernel_errors;
 
+mod word_formatter;
+pub use word_formatter::{PublicInputsFormatter, WordFormatter};
+
 // TRANSACTION EXECUTOR ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionExecutorError {
+    AccountCodeNotUpgradable(AccountId),
+    AdviceSnapshotNotReached(u32),
+    BalanceProjectionFailed(AssetVaultError),
+    DuplicateInputNote(NoteId),
     ExecuteTransactionProgramFailed(ExecutionError),
+    FaucetSupplyOverflow(AccountId),
     FetchTransactionInputsFailed(DataStoreError),
+    IncompatibleNoteAssets {
+        note_id: NoteId,
+        asset: Asset,
+    },
     InconsistentAccountId {
         input_id: AccountId,
         output_id: AccountId,
@@ -26,8 +42,78 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    InsufficientFee {
+        required: FungibleAsset,
+        paid: u64,
+    },
+    InvalidIntermediateAccountState(AccountError),
+    InvalidInternalNotes(TransactionInputError),
     InvalidTransactionOutput(TransactionOutputError),
+    MalformedCreatedNote {
+        index: usize,
+        error: NoteError,
+    },
+    MissingAuthAdvice {
+        account_id: AccountId,
+        auth_procedure: Option<Digest>,
+    },
+    MissingNoteConsumptionProof(NoteId),
+    MissingProcedure {
+        code_commitment: Digest,
+        procedure_root: Digest,
+    },
+    NetPositionFailed(AssetVaultError),
+    NewAccountNonceNotIncremented(AccountId),
+    NoteAlreadySpent {
+        note_id: NoteId,
+        nullifier: Nullifier,
+    },
+    NoteCycleLimitExceeded(NoteId),
+    NoteDiscoveryFailed(DataStoreError),
+    NoteNotYetSpendable {
+        note_id: NoteId,
+        earliest_block: u32,
+        block_ref: u32,
+    },
+    NoteScriptCompilationFailed(NoteError),
+    NoteScriptNotWhitelisted {
+        note_id: Option<NoteId>,
+        script_root: Digest,
+    },
+    ScriptRootMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    StdlibVersionMismatch {
+        code_commitment: Digest,
+        procedure_root: Digest,
+    },
+    StorageUpdateCreatedNotes(usize),
+    TooManyOutputNotes {
+        max: usize,
+        actual: usize,
+    },
     TransactionHostCreationFailed(TransactionHostError),
+    TransactionScriptCompilationFailed(TransactionScriptError),
+    UnchangedAccountCode(Digest),
+    UncreatedInternalNote(NoteId),
+    UnknownKernelProcedure {
+        code_commitment: Digest,
+        procedure_root: Digest,
+    },
+    UnknownNoteConsumptionProof(NoteId),
+}
+
+impl TransactionExecutorError {
+    /// Returns a description of this error in which the words recognized by the provided
+    /// [WordFormatter] are rendered with their labels.
+    ///
+    /// This is primarily useful for [Self::ExecuteTransactionProgramFailed] errors, which
+    /// reference words as raw field elements. For example, using a [PublicInputsFormatter],
+    /// a reference to the initial account hash is rendered as `INITIAL_ACCOUNT_HASH(0x...)`.
+    pub fn format_with(&self, formatter: &dyn WordFormatter) -> String {
+        word_formatter::annotate_words(&self.to_string(), formatter)
+    }
 }
 
 impl fmt::Display for TransactionExecutorError {
@@ -48,8 +134,10 @@ pub enum TransactionProverError {
     InvalidAccountDelta(AccountError),
     InvalidTransactionOutput(TransactionOutputError),
     ProvenTransactionError(ProvenTransactionError),
+    RemoteProvingFailed(String),
     TransactionProgramExecutionFailed(ExecutionError),
     TransactionHostCreationFailed(TransactionHostError),
+    TransportFailed(String),
 }
 
 impl Display for TransactionProverError {
@@ -67,12 +155,18 @@ impl Display for TransactionProverError {
             TransactionProverError::ProvenTransactionError(inner) => {
                 write!(f, "Building proven transaction error: {}", inner)
             },
+            TransactionProverError::RemoteProvingFailed(inner) => {
+                write!(f, "Remote prover failed to prove transaction: {}", inner)
+            },
             TransactionProverError::TransactionProgramExecutionFailed(inner) => {
                 write!(f, "Proving transaction failed: {}", inner)
             },
             TransactionProverError::TransactionHostCreationFailed(inner) => {
                 write!(f, "Failed to create the transaction host: {}", inner)
             },
+            TransactionProverError::TransportFailed(inner) => {
+                write!(f, "Failed to communicate with the remote prover: {}", inner)
+            },
         }
     }
 }
@@ -87,6 +181,10 @@ impl std::error::Error for TransactionProverError {}
 pub enum TransactionVerifierError {
     TransactionVerificationFailed(VerificationError),
     InsufficientProofSecurityLevel(u32, u32),
+    UnexpectedAccountTransition {
+        actual_initial_hash: Digest,
+        actual_final_hash: Digest,
+    },
 }
 
 impl fmt::Display for TransactionVerifierError {
@@ -121,10 +219,15 @@ impl std::error::Error for TransactionHostError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DataStoreError {
     AccountNotFound(AccountId),
-    BlockNotFound(u32),
+    BlockNotFound(BlockNumber),
+    HistoricalStateUnavailable {
+        account_id: AccountId,
+        block_num: BlockNumber,
+    },
     InvalidTransactionInput(TransactionInputError),
     InternalError(String),
     NoteAlreadyConsumed(NoteId),
+    NoteListingUnavailable,
     NoteNotFound(NoteId),
 }
 
@@ -29,6 +29,10 @@ pub const ERR_ACCOUNT_TOO_MANY_PROCEDURES: u32 = 0x00020010;
 pub const ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS: u32 = 0x00020011;
 pub const ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET: u32 = 0x00020001;
 
+pub const ERR_CONDITIONAL_CONSUMER_MISMATCH: u32 = 0x00020058;
+pub const ERR_CONDITIONAL_EARLIEST_BLOCK_NOT_REACHED: u32 = 0x00020059;
+pub const ERR_CONDITIONAL_WRONG_NUMBER_OF_INPUTS: u32 = 0x00020057;
+
 pub const ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME: u32 = 0x00020029;
 
 pub const ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY: u32 = 0x0002002B;
@@ -112,7 +116,7 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS: u32 = 0x0002001C;
 pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: u32 = 0x0002001F;
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: u32 = 0x0002001E;
 
-pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
+pub const TX_KERNEL_ERRORS: [(u32, &str); 90] = [
     (ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH, "Computed account code commitment does not match recorded account code commitment"),
     (ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE, "Account code must be updatable for it to be possible to set new code"),
     (ERR_ACCOUNT_INSUFFICIENT_NUMBER_OF_ONES, "Account ID must contain at least MIN_ACCOUNT_ONES number of ones"),
@@ -132,6 +136,10 @@ pub const TX_KERNEL_ERRORS: [(u32, &str); 87] = [
     (ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS, "Number of account storage slots exceeds the maximum limit of 255"),
     (ERR_ACCOUNT_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET, "The get_fungible_faucet_total_issuance procedure can only be called on a fungible faucet"),
 
+    (ERR_CONDITIONAL_CONSUMER_MISMATCH, "CONDITIONAL note can only be consumed by the account specified in the note inputs"),
+    (ERR_CONDITIONAL_EARLIEST_BLOCK_NOT_REACHED, "CONDITIONAL note can not be consumed as the transaction's reference block is lower than the earliest block"),
+    (ERR_CONDITIONAL_WRONG_NUMBER_OF_INPUTS, "CONDITIONAL scripts expect exactly 2 note inputs"),
+
     (ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME, "Total number of assets in the account and all involved notes must stay the same"),
 
     (ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY, "Asset amount to burn can not exceed the existing total supply"),
@@ -0,0 +1,153 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{transaction::TransactionInputs, Digest, Felt, StarkField, Word, WORD_SIZE};
+
+// WORD FORMATTER
+// ================================================================================================
+
+/// Provides human-readable labels for the words referenced by transaction errors.
+///
+/// Errors returned by the VM reference words (e.g., advice map keys or Merkle roots) either as
+/// lists of field elements or as hex strings. Via
+/// [TransactionExecutorError::format_with()](super::TransactionExecutorError::format_with), every
+/// such word for which the formatter returns a label is rendered as `LABEL(0x...)`.
+///
+/// The trait is implemented for closures, and thus domain-specific labels can be provided
+/// without defining a new type. [PublicInputsFormatter] provides labels for the public inputs of
+/// a transaction.
+pub trait WordFormatter {
+    /// Returns the label of the provided word, or None if the word is not recognized.
+    fn label(&self, word: &Word) -> Option<String>;
+}
+
+impl<F: Fn(&Word) -> Option<String>> WordFormatter for F {
+    fn label(&self, word: &Word) -> Option<String> {
+        self(word)
+    }
+}
+
+// PUBLIC INPUTS FORMATTER
+// ================================================================================================
+
+/// A [WordFormatter] recognizing the words passed to the transaction kernel as public inputs.
+///
+/// The formatter labels the reference block hash (`BLOCK_HASH`), the initial account hash
+/// (`INITIAL_ACCOUNT_HASH`) and the commitment to the input notes (`INPUT_NOTES_COMMITMENT`) of
+/// a transaction. Further labels can be added via [Self::with_label()].
+#[derive(Debug, Clone)]
+pub struct PublicInputsFormatter {
+    labels: Vec<(Word, String)>,
+}
+
+impl PublicInputsFormatter {
+    /// Returns a new [PublicInputsFormatter] for the public inputs of a transaction executed
+    /// with the provided inputs.
+    pub fn new(tx_inputs: &TransactionInputs) -> Self {
+        let account = tx_inputs.account();
+        let labels = vec![
+            (tx_inputs.block_header().hash().into(), "BLOCK_HASH".to_string()),
+            (account.init_hash().into(), "INITIAL_ACCOUNT_HASH".to_string()),
+            (
+                tx_inputs.input_notes().commitment().into(),
+                "INPUT_NOTES_COMMITMENT".to_string(),
+            ),
+        ];
+
+        Self { labels }
+    }
+
+    /// Adds the provided label for the provided word.
+    ///
+    /// Labels added later take precedence over the labels already known to this formatter.
+    pub fn with_label(mut self, word: Word, label: impl Into<String>) -> Self {
+        self.labels.insert(0, (word, label.into()));
+        self
+    }
+}
+
+impl WordFormatter for PublicInputsFormatter {
+    fn label(&self, word: &Word) -> Option<String> {
+        self.labels
+            .iter()
+            .find(|(known, _)| known == word)
+            .map(|(_, label)| label.clone())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the provided message with every word labeled by the formatter rendered as
+/// `LABEL(0x...)`.
+///
+/// Words are recognized if they are written as a list of four field elements (e.g.,
+/// `[1, 2, 3, 4]`) or as a `0x`-prefixed hex string of the word's 32 bytes.
+pub(super) fn annotate_words(message: &str, formatter: &dyn WordFormatter) -> String {
+    let mut output = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find(['[', '0']) {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let parsed = parse_felt_list(rest).or_else(|| parse_hex_word(rest));
+        match parsed.and_then(|(word, len)| formatter.label(&word).map(|label| (word, label, len)))
+        {
+            Some((word, label, len)) => {
+                output.push_str(&format!("{label}({})", Digest::from(word).to_hex()));
+                rest = &rest[len..];
+            },
+            None => {
+                output.push_str(&rest[..1]);
+                rest = &rest[1..];
+            },
+        }
+    }
+    output.push_str(rest);
+
+    output
+}
+
+/// Parses a word written as `[a, b, c, d]` from the start of the provided string, and returns the
+/// word together with the length of its representation.
+fn parse_felt_list(s: &str) -> Option<(Word, usize)> {
+    let end = s.strip_prefix('[')?.find(']')? + 1;
+    let elements = s[1..end]
+        .split(", ")
+        .map(|element| element.parse::<u64>().ok().and_then(canonical_felt))
+        .collect::<Option<Vec<_>>>()?;
+    let word = Word::try_from(elements).ok()?;
+
+    Some((word, end + 1))
+}
+
+/// Parses a word written as a `0x`-prefixed hex string of its 32 bytes from the start of the
+/// provided string, and returns the word together with the length of its representation.
+fn parse_hex_word(s: &str) -> Option<(Word, usize)> {
+    const NUM_HEX_DIGITS: usize = 2 * WORD_SIZE * 8;
+
+    let digits = s.strip_prefix("0x")?;
+    let num_digits = digits.find(|c: char| !c.is_ascii_hexdigit()).unwrap_or(digits.len());
+    if num_digits != NUM_HEX_DIGITS {
+        return None;
+    }
+
+    let mut word = Word::default();
+    for (element, chunk) in word.iter_mut().zip(digits.as_bytes().chunks_exact(16)) {
+        let mut bytes = [0_u8; 8];
+        for (byte, pair) in bytes.iter_mut().zip(chunk.chunks_exact(2)) {
+            let pair = core::str::from_utf8(pair).ok()?;
+            *byte = u8::from_str_radix(pair, 16).ok()?;
+        }
+        *element = canonical_felt(u64::from_le_bytes(bytes))?;
+    }
+
+    Some((word, 2 + NUM_HEX_DIGITS))
+}
+
+/// Returns the field element with the provided value, or None if the value is not canonical.
+fn canonical_felt(value: u64) -> Option<Felt> {
+    (value < Felt::MODULUS).then(|| Felt::new(value))
+}
@@ -0,0 +1,157 @@
+use alloc::sync::Arc;
+
+use miden_lib::transaction::TransactionEvent;
+use miden_objects::{
+    vm::{DebugOptions, RowIndex},
+    Digest,
+};
+use vm_processor::{
+    AdviceExtractor, AdviceInjector, ExecutionError, Host, HostResponse, MastForest, ProcessState,
+    RecAdviceProvider,
+};
+
+use super::ExecutionOutput;
+use crate::TransactionHost;
+
+// ADVICE SNAPSHOT
+// ================================================================================================
+
+/// A copy of the advice provider captured while a transaction was executed.
+///
+/// Created by
+/// [TransactionExecutor::execute_transaction_with_advice_snapshot()](super::TransactionExecutor::execute_transaction_with_advice_snapshot).
+/// The snapshot is captured without suspending the execution, which runs to completion
+/// regardless of it.
+#[derive(Debug, Clone)]
+pub struct AdviceSnapshot {
+    clk: RowIndex,
+    advice_provider: RecAdviceProvider,
+}
+
+impl AdviceSnapshot {
+    /// Returns the clock cycle at which the advice provider was captured.
+    ///
+    /// This is the first cycle at or after the requested one at which the VM interacted with the
+    /// host. Since the advice provider can be modified only through such interactions, its state
+    /// is the same for every cycle between the requested one and the returned one.
+    pub fn clk(&self) -> RowIndex {
+        self.clk
+    }
+
+    /// Returns the advice provider in its state at [Self::clk()].
+    pub fn advice_provider(&self) -> &RecAdviceProvider {
+        &self.advice_provider
+    }
+}
+
+// OBSERVING HOST
+// ================================================================================================
+
+/// An observation reported by an [ObservingHost] while a transaction is executed.
+pub(super) enum Observation {
+    /// An output of the transaction, reported as it occurs.
+    Output(ExecutionOutput),
+    /// The state of the advice provider at the requested clock cycle.
+    Snapshot(AdviceSnapshot),
+}
+
+/// A function receiving the observations made while a transaction is executed.
+pub(super) type Observer<'a> = &'a mut dyn FnMut(Observation);
+
+/// A [Host] which forwards all requests to a [TransactionHost], and reports a copy of its advice
+/// provider to the observer before serving the first request made at or after the snapshot clock
+/// cycle.
+///
+/// Every event successfully handled by the transaction host is reported to the observer as well.
+/// Observations are reported even if the execution fails afterwards.
+pub(super) struct ObservingHost<'a> {
+    host: &'a mut TransactionHost<RecAdviceProvider>,
+    snapshot_clk: Option<u32>,
+    observer: Option<Observer<'a>>,
+}
+
+impl<'a> ObservingHost<'a> {
+    /// Returns a new [ObservingHost] wrapping the provided host.
+    ///
+    /// If `observer` is `None`, all requests are forwarded without capturing any state.
+    pub fn new(
+        host: &'a mut TransactionHost<RecAdviceProvider>,
+        snapshot_clk: Option<u32>,
+        observer: Option<Observer<'a>>,
+    ) -> Self {
+        Self { host, snapshot_clk, observer }
+    }
+
+    fn check_snapshot<S: ProcessState>(&mut self, process: &S) {
+        if let (Some(snapshot_clk), Some(observer)) = (self.snapshot_clk, &mut self.observer) {
+            let clk = process.clk();
+            if clk >= RowIndex::from(snapshot_clk) {
+                let advice_provider = self.host.advice_provider().clone();
+                observer(Observation::Snapshot(AdviceSnapshot { clk, advice_provider }));
+                self.snapshot_clk = None;
+            }
+        }
+    }
+}
+
+impl Host for ObservingHost<'_> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_snapshot(process);
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_snapshot(process);
+        self.host.set_advice(process, injector)
+    }
+
+    fn get_mast_forest(&self, node_digest: &Digest) -> Option<Arc<MastForest>> {
+        self.host.get_mast_forest(node_digest)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_snapshot(process);
+        let response = self.host.on_event(process, event_id)?;
+        if let Some(observer) = &mut self.observer {
+            let event = TransactionEvent::try_from(event_id)
+                .expect("event was already handled by the transaction host");
+            observer(Observation::Output(ExecutionOutput::Event(event)));
+        }
+        Ok(response)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_snapshot(process);
+        self.host.on_debug(process, options)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.check_snapshot(process);
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        self.check_snapshot(process);
+        self.host.on_assert_failed(process, err_code)
+    }
+}
@@ -0,0 +1,61 @@
+use alloc::vec::Vec;
+
+use miden_objects::transaction::{InputNote, InputNotes, TransactionInputs};
+
+// AUTH POLICY
+// ================================================================================================
+
+/// Specifies how a [TransactionExecutor](super::TransactionExecutor) authenticates the input notes
+/// of the transactions it executes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuthPolicy {
+    /// Input notes with inclusion proofs are authenticated against the chain MMR by the
+    /// transaction kernel.
+    #[default]
+    Full,
+
+    /// All input notes are accepted on faith: inclusion proofs are discarded and the notes are
+    /// consumed as unauthenticated notes.
+    ///
+    /// Note that this changes what the transaction proves. The input notes of the transaction are
+    /// committed to via their hashes instead of their nullifiers only, and thus the commitment to
+    /// the input notes differs from the one of the same transaction executed under
+    /// [AuthPolicy::Full]. The resulting
+    /// [ProvenTransaction](miden_objects::transaction::ProvenTransaction) records all input
+    /// notes as unauthenticated, and these notes must still be authenticated by the rollup
+    /// kernels before the transaction can be included in a block. This mode is intended
+    /// for local testing in trusted environments.
+    Trusted,
+}
+
+impl AuthPolicy {
+    /// Applies this policy to the provided transaction inputs.
+    pub(super) fn apply(&self, tx_inputs: TransactionInputs) -> TransactionInputs {
+        match self {
+            AuthPolicy::Full => tx_inputs,
+            AuthPolicy::Trusted => {
+                let (account, account_seed, block_header, block_chain, input_notes) =
+                    tx_inputs.into_parts();
+                let input_notes = input_notes
+                    .into_vec()
+                    .into_iter()
+                    .map(|input_note| match input_note {
+                        InputNote::Authenticated { note, .. } => InputNote::unauthenticated(note),
+                        note @ InputNote::Unauthenticated { .. } => note,
+                    })
+                    .collect::<Vec<_>>();
+                let input_notes =
+                    InputNotes::new(input_notes).expect("input notes were already validated");
+
+                TransactionInputs::new(
+                    account,
+                    account_seed,
+                    block_header,
+                    block_chain,
+                    input_notes,
+                )
+                .expect("transaction inputs were already validated")
+            },
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use alloc::vec::Vec;
+
+use miden_lib::notes::{SwapNote, WellKnownNote};
+use miden_objects::{
+    assets::AssetVault,
+    notes::{Note, NoteId},
+    AssetVaultError,
+};
+
+// BALANCE PROJECTION
+// ================================================================================================
+
+/// The projected state of the vault of an account after a transaction has been executed
+/// successfully, computed without executing the transaction.
+///
+/// The effects of the standard note scripts (see [WellKnownNote]) on the vault of the consuming
+/// account are known statically: P2ID and P2IDR notes deposit all of their assets into the vault,
+/// while SWAP notes deposit the offered asset and withdraw the requested asset. The effects of
+/// custom note scripts and of transaction scripts (e.g., minting assets or creating notes) depend
+/// on their execution, and so they are not reflected in the projected vault. Such a projection
+/// is incomplete, which is indicated by [Self::is_complete()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceProjection {
+    vault: AssetVault,
+    dynamic_notes: Vec<NoteId>,
+    has_dynamic_tx_script: bool,
+}
+
+impl BalanceProjection {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Projects the state of the provided vault after consuming the provided notes and, if
+    /// `has_tx_script` is set, executing a transaction script.
+    ///
+    /// # Errors
+    /// Returns an error if the effects of the standard note scripts cannot be applied to the
+    /// vault, e.g., if the vault does not hold the asset requested by a SWAP note. A transaction
+    /// consuming these notes would fail.
+    pub(super) fn new<'a>(
+        mut vault: AssetVault,
+        notes: impl IntoIterator<Item = &'a Note>,
+        has_tx_script: bool,
+    ) -> Result<Self, AssetVaultError> {
+        let mut dynamic_notes = Vec::new();
+        for note in notes {
+            match WellKnownNote::from_note(note) {
+                Some(WellKnownNote::P2ID | WellKnownNote::P2IDR) => {
+                    for asset in note.assets().iter() {
+                        vault.add_asset(*asset)?;
+                    }
+                },
+                Some(WellKnownNote::SWAP) => match SwapNote::try_from(note.clone()) {
+                    Ok(swap_note) => {
+                        vault.add_asset(swap_note.offered_asset())?;
+                        vault.remove_asset(swap_note.requested_asset())?;
+                    },
+                    Err(_) => dynamic_notes.push(note.id()),
+                },
+                None => dynamic_notes.push(note.id()),
+            }
+        }
+
+        Ok(Self {
+            vault,
+            dynamic_notes,
+            has_dynamic_tx_script: has_tx_script,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the projected vault of the account.
+    ///
+    /// If the projection is incomplete, the vault reflects only the statically determinable
+    /// effects of the transaction.
+    pub fn vault(&self) -> &AssetVault {
+        &self.vault
+    }
+
+    /// Returns true if all effects of the transaction on the vault of the account are reflected
+    /// in the projected vault.
+    pub fn is_complete(&self) -> bool {
+        self.dynamic_notes.is_empty() && !self.has_dynamic_tx_script
+    }
+
+    /// Returns the IDs of the input notes whose effects on the vault could not be determined
+    /// statically.
+    pub fn dynamic_notes(&self) -> &[NoteId] {
+        &self.dynamic_notes
+    }
+
+    /// Returns true if the effects of the transaction script on the vault could not be determined
+    /// statically.
+    pub fn has_dynamic_tx_script(&self) -> bool {
+        self.has_dynamic_tx_script
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes self and returns the projected vault of the account.
+    pub fn into_vault(self) -> AssetVault {
+        self.vault
+    }
+}
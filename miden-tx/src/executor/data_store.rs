@@ -1,7 +1,13 @@
 #[cfg(feature = "async")]
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
-use miden_objects::{accounts::AccountId, notes::NoteId, transaction::TransactionInputs};
+use miden_objects::{
+    accounts::{Account, AccountId},
+    block::BlockNumber,
+    notes::{NoteId, NoteTag},
+    transaction::{InputNote, TransactionInputs},
+};
 use winter_maybe_async::*;
 
 use crate::DataStoreError;
@@ -11,6 +17,36 @@ use crate::DataStoreError;
 
 /// The [DataStore] trait defines the interface that transaction objects use to fetch data
 /// required for transaction execution.
+///
+/// When the `async` feature is enabled, the methods of this trait are async and the returned
+/// futures are not required to be [Send]. Together with building without the `std` feature, this
+/// allows implementing a data store on top of JavaScript APIs and running the
+/// [TransactionExecutor](super::TransactionExecutor) in a browser (e.g., on the
+/// `wasm32-unknown-unknown` target). A skeleton of such a data store could look as follows:
+///
+/// ```ignore
+/// struct JsDataStore {
+///     // a handle to a JavaScript object providing the chain data
+///     store: wasm_bindgen::JsValue,
+/// }
+///
+/// #[async_trait::async_trait(?Send)]
+/// impl DataStore for JsDataStore {
+///     async fn get_transaction_inputs(
+///         &self,
+///         account_id: AccountId,
+///         block_ref: BlockNumber,
+///         notes: &[NoteId],
+///     ) -> Result<TransactionInputs, DataStoreError> {
+///         // call into JavaScript, await the returned promise and deserialize the result
+///         let bytes = fetch_transaction_inputs(&self.store, account_id, block_ref.as_u32(), notes)
+///             .await
+///             .map_err(|err| DataStoreError::InternalError(format!("{err:?}")))?;
+///         TransactionInputs::read_from_bytes(&bytes)
+///             .map_err(|err| DataStoreError::InternalError(err.to_string()))
+///     }
+/// }
+/// ```
 #[maybe_async_trait]
 pub trait DataStore {
     /// Returns account, chain, and input note data required to execute a transaction against
@@ -20,6 +56,9 @@ pub trait DataStore {
     /// recorded in the chain. In general, it is recommended that bock_ref corresponds to the
     /// latest block available in the data store.
     ///
+    /// `notes` may be empty, in which case the returned inputs must contain an empty set of input
+    /// notes.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
@@ -32,7 +71,71 @@ pub trait DataStore {
     fn get_transaction_inputs(
         &self,
         account_id: AccountId,
-        block_ref: u32,
+        block_ref: BlockNumber,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError>;
+
+    /// Returns the state of the account with the specified ID as of the end of the block with the
+    /// specified number, i.e., the state of the account after all transactions included in this
+    /// block have been applied.
+    ///
+    /// This is used to inspect past account states via
+    /// [TransactionExecutor::read_historical()](super::TransactionExecutor::read_historical).
+    /// Since many data stores keep only the latest state of each account, the default
+    /// implementation returns [DataStoreError::HistoricalStateUnavailable].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The data store does not keep the state of the account as of the specified block.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_account_at(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<Account, DataStoreError> {
+        Err(DataStoreError::HistoricalStateUnavailable { account_id, block_num: block_ref })
+    }
+
+    /// Returns all notes recorded in the chain by the block with the specified number which have
+    /// not been consumed yet.
+    ///
+    /// This is used by note discovery strategies (see [NoteDiscovery](super::NoteDiscovery)).
+    /// Since not every data store can enumerate the notes it holds, the default implementation
+    /// returns [DataStoreError::NoteListingUnavailable].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The data store cannot enumerate the notes it holds.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_unconsumed_notes(
+        &self,
+        _block_ref: BlockNumber,
+    ) -> Result<Vec<InputNote>, DataStoreError> {
+        Err(DataStoreError::NoteListingUnavailable)
+    }
+
+    /// Returns the notes recorded in the chain by the block with the specified number which have
+    /// not been consumed yet and carry any of the specified tags.
+    ///
+    /// The default implementation filters the notes returned by [Self::get_unconsumed_notes()];
+    /// data stores indexing notes by tag should override it.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The data store cannot enumerate the notes it holds.
+    /// - The data store encountered some internal error
+    #[maybe_async]
+    fn get_notes_by_tags(
+        &self,
+        block_ref: BlockNumber,
+        tags: &[NoteTag],
+    ) -> Result<Vec<InputNote>, DataStoreError> {
+        let notes = maybe_await!(self.get_unconsumed_notes(block_ref))?;
+        Ok(notes
+            .into_iter()
+            .filter(|note| tags.contains(&note.note().metadata().tag()))
+            .collect())
+    }
 }
@@ -0,0 +1,285 @@
+use alloc::{sync::Arc, vec::Vec};
+
+use miden_lib::transaction::TransactionEvent;
+use miden_objects::{
+    notes::{Note, Nullifier},
+    transaction::TransactionScript,
+    Digest, MAX_OUTPUT_NOTES_PER_TX, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES,
+};
+use vm_processor::ExecutionOptions;
+
+use super::{AuthPolicy, NoteAssetPolicy, ScriptWhitelist};
+use crate::host::{DebugRecord, DebugSink, EventHook};
+
+// EXECUTION CONTEXT
+// ================================================================================================
+
+/// A function returning true if the note with the specified nullifier has already been consumed.
+type NullifierChecker = Arc<dyn Fn(&Nullifier) -> bool + Send + Sync>;
+
+/// A function rewriting the transaction script of a transaction before it is executed.
+type ScriptTransform = Arc<dyn Fn(TransactionScript) -> TransactionScript + Send + Sync>;
+
+/// Configuration applied by a [TransactionExecutor](super::TransactionExecutor) to a single
+/// transaction execution.
+///
+/// The context bundles the execution options of the VM (cycle limit, debug mode and tracing), the
+/// policies used to validate transaction inputs and outputs, and the hooks invoked during
+/// execution. Every executor holds a default context which is configured via the `with_*` methods
+/// of the executor; a different context can be provided for an individual transaction via
+/// [TransactionExecutor::execute_transaction_with_context()](super::TransactionExecutor::execute_transaction_with_context).
+///
+/// All hooks and the script whitelist are reference-counted, and thus cloning a context is cheap.
+#[derive(Clone)]
+pub struct ExecutionContext {
+    pub(super) exec_options: ExecutionOptions,
+    pub(super) note_script_whitelist: Option<Arc<ScriptWhitelist>>,
+    pub(super) max_output_notes: usize,
+    pub(super) auth_policy: AuthPolicy,
+    pub(super) nullifier_checker: Option<NullifierChecker>,
+    pub(super) note_asset_policy: Option<Arc<dyn NoteAssetPolicy>>,
+    pub(super) event_hook: Option<EventHook>,
+    pub(super) debug_sink: Option<DebugSink>,
+    pub(super) script_transform: Option<ScriptTransform>,
+    pub(super) validate_created_notes: bool,
+    pub(super) capture_note_states: bool,
+    pub(super) per_note_cycle_limit: Option<u32>,
+    pub(super) unordered_notes: bool,
+    pub(super) block_timestamp: Option<u32>,
+    pub(super) internal_notes: Vec<Note>,
+    pub(super) expected_tx_script_root: Option<Digest>,
+    pub(super) retain_recorder: bool,
+    pub(super) snapshot_clk: Option<u32>,
+}
+
+impl ExecutionContext {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ExecutionContext] with the default configuration.
+    ///
+    /// By default, transactions are executed with the maximum cycle limit, without debug mode and
+    /// tracing, with [AuthPolicy::Full], and without any hooks or optional validations.
+    pub fn new() -> Self {
+        Self {
+            exec_options: ExecutionOptions::new(
+                Some(MAX_TX_EXECUTION_CYCLES),
+                MIN_TX_EXECUTION_CYCLES,
+                false,
+                false,
+            )
+            .expect("Must not fail while max cycles is more than min trace length"),
+            note_script_whitelist: None,
+            max_output_notes: MAX_OUTPUT_NOTES_PER_TX,
+            auth_policy: AuthPolicy::Full,
+            nullifier_checker: None,
+            note_asset_policy: None,
+            event_hook: None,
+            debug_sink: None,
+            script_transform: None,
+            validate_created_notes: false,
+            capture_note_states: false,
+            per_note_cycle_limit: None,
+            unordered_notes: false,
+            block_timestamp: None,
+            internal_notes: Vec::new(),
+            expected_tx_script_root: None,
+            retain_recorder: false,
+            snapshot_clk: None,
+        }
+    }
+
+    /// Sets the maximum number of cycles a transaction may take to execute.
+    ///
+    /// The limit is clamped to the range between [MIN_TX_EXECUTION_CYCLES] and
+    /// [MAX_TX_EXECUTION_CYCLES]. A transaction exceeding the limit fails with
+    /// [ExecutionError::CycleLimitExceeded](vm_processor::ExecutionError::CycleLimitExceeded).
+    pub fn with_max_cycles(mut self, max_cycles: u32) -> Self {
+        self.exec_options = ExecutionOptions::new(
+            Some(max_cycles.clamp(MIN_TX_EXECUTION_CYCLES, MAX_TX_EXECUTION_CYCLES)),
+            self.exec_options.expected_cycles(),
+            self.exec_options.enable_tracing(),
+            self.exec_options.enable_debugging(),
+        )
+        .expect("max cycles must not be less than min trace length");
+        self
+    }
+
+    /// Enables or disables debug mode.
+    ///
+    /// See [TransactionExecutor::with_debug_mode()](super::TransactionExecutor::with_debug_mode).
+    pub fn with_debug_mode(mut self, in_debug_mode: bool) -> Self {
+        self.exec_options = ExecutionOptions::new(
+            Some(self.exec_options.max_cycles()),
+            self.exec_options.expected_cycles(),
+            self.exec_options.enable_tracing(),
+            in_debug_mode,
+        )
+        .expect("failed to clone execution options");
+        self
+    }
+
+    /// Enables tracing.
+    ///
+    /// See [TransactionExecutor::with_tracing()](super::TransactionExecutor::with_tracing).
+    pub fn with_tracing(mut self) -> Self {
+        self.exec_options = self.exec_options.with_tracing();
+        self
+    }
+
+    /// Sets the maximum number of cycles the execution of a single input note may take, and
+    /// enables tracing.
+    ///
+    /// See [TransactionExecutor::with_per_note_cycle_limit()](super::TransactionExecutor::with_per_note_cycle_limit).
+    pub fn with_per_note_cycle_limit(mut self, max_cycles: u32) -> Self {
+        self.per_note_cycle_limit = Some(max_cycles);
+        self.with_tracing()
+    }
+
+    /// Allows the input notes of a transaction to be consumed in a different order than requested.
+    ///
+    /// See [TransactionExecutor::with_unordered_notes()](super::TransactionExecutor::with_unordered_notes).
+    pub fn with_unordered_notes(mut self) -> Self {
+        self.unordered_notes = true;
+        self
+    }
+
+    /// Overrides the timestamp of the reference block of a transaction.
+    ///
+    /// See [TransactionExecutor::with_block_timestamp()](super::TransactionExecutor::with_block_timestamp).
+    #[cfg(any(feature = "testing", test))]
+    pub fn with_block_timestamp(mut self, timestamp: u32) -> Self {
+        self.block_timestamp = Some(timestamp);
+        self
+    }
+
+    /// Adds notes created by a transaction to the notes consumed by the same transaction, and
+    /// enables tracing.
+    ///
+    /// See [TransactionExecutor::with_internal_notes()](super::TransactionExecutor::with_internal_notes).
+    pub fn with_internal_notes(mut self, notes: Vec<Note>) -> Self {
+        self.internal_notes = notes;
+        self.with_tracing()
+    }
+
+    /// Restricts the notes which can be consumed to the notes whose scripts are in the provided
+    /// [ScriptWhitelist].
+    ///
+    /// See [TransactionExecutor::with_note_script_whitelist()](super::TransactionExecutor::with_note_script_whitelist).
+    pub fn with_note_script_whitelist(mut self, whitelist: ScriptWhitelist) -> Self {
+        self.note_script_whitelist = Some(Arc::new(whitelist));
+        self
+    }
+
+    /// Sets the maximum number of output notes a transaction may create.
+    ///
+    /// See [TransactionExecutor::with_max_output_notes()](super::TransactionExecutor::with_max_output_notes).
+    pub fn with_max_output_notes(mut self, max_output_notes: usize) -> Self {
+        self.max_output_notes = max_output_notes.min(MAX_OUTPUT_NOTES_PER_TX);
+        self
+    }
+
+    /// Sets the [AuthPolicy] used to authenticate the input notes of a transaction.
+    pub fn with_auth_policy(mut self, auth_policy: AuthPolicy) -> Self {
+        self.auth_policy = auth_policy;
+        self
+    }
+
+    /// Sets a function used to check whether the input notes of a transaction have already been
+    /// consumed.
+    ///
+    /// See [TransactionExecutor::with_nullifier_checker()](super::TransactionExecutor::with_nullifier_checker).
+    pub fn with_nullifier_checker(
+        mut self,
+        is_spent: impl Fn(&Nullifier) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.nullifier_checker = Some(Arc::new(is_spent));
+        self
+    }
+
+    /// Sets the [NoteAssetPolicy] used to check the assets of the notes consumed by a
+    /// transaction.
+    ///
+    /// See [TransactionExecutor::with_note_asset_policy()](super::TransactionExecutor::with_note_asset_policy).
+    pub fn with_note_asset_policy(mut self, policy: impl NoteAssetPolicy + 'static) -> Self {
+        self.note_asset_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Sets a function invoked with every event emitted by the transaction kernel.
+    ///
+    /// The hook is invoked before the event is handled by the transaction host, and cannot
+    /// influence the execution of the transaction.
+    pub fn with_event_hook(
+        mut self,
+        hook: impl Fn(&TransactionEvent) + Send + Sync + 'static,
+    ) -> Self {
+        self.event_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a function receiving the output of `debug` and `trace` instructions.
+    ///
+    /// See [TransactionExecutor::with_debug_sink()](super::TransactionExecutor::with_debug_sink).
+    pub fn with_debug_sink(mut self, sink: impl Fn(DebugRecord) + Send + Sync + 'static) -> Self {
+        self.debug_sink = Some(Arc::new(sink));
+        self
+    }
+
+    /// Sets a function rewriting the transaction script of a transaction before it is executed.
+    ///
+    /// See [TransactionExecutor::with_script_transform()](super::TransactionExecutor::with_script_transform).
+    pub fn with_script_transform(
+        mut self,
+        transform: impl Fn(TransactionScript) -> TransactionScript + Send + Sync + 'static,
+    ) -> Self {
+        self.script_transform = Some(Arc::new(transform));
+        self
+    }
+
+    /// Sets the root which the transaction script of a transaction is expected to have.
+    ///
+    /// See [TransactionExecutor::with_expected_tx_script_root()](super::TransactionExecutor::with_expected_tx_script_root).
+    pub fn with_expected_tx_script_root(mut self, root: Digest) -> Self {
+        self.expected_tx_script_root = Some(root);
+        self
+    }
+
+    /// Enables validation of the notes created by a transaction.
+    ///
+    /// See [TransactionExecutor::with_created_note_validation()](super::TransactionExecutor::with_created_note_validation).
+    pub fn with_created_note_validation(mut self) -> Self {
+        self.validate_created_notes = true;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the maximum number of cycles a transaction may take to execute.
+    pub fn max_cycles(&self) -> u32 {
+        self.exec_options.max_cycles()
+    }
+
+    /// Returns the [AuthPolicy] used to authenticate the input notes of a transaction.
+    pub fn auth_policy(&self) -> AuthPolicy {
+        self.auth_policy
+    }
+
+    /// Returns the maximum number of output notes a transaction may create.
+    pub fn max_output_notes(&self) -> usize {
+        self.max_output_notes
+    }
+
+    /// Returns the maximum number of cycles the execution of a single input note may take, if
+    /// limited.
+    pub fn per_note_cycle_limit(&self) -> Option<u32> {
+        self.per_note_cycle_limit
+    }
+}
+
+impl Default for ExecutionContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
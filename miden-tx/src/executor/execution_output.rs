@@ -0,0 +1,25 @@
+use alloc::boxed::Box;
+
+use miden_lib::transaction::TransactionEvent;
+use miden_objects::transaction::OutputNote;
+
+// EXECUTION OUTPUT
+// ================================================================================================
+
+/// An output of a transaction reported by
+/// [TransactionExecutor::execute_transaction_with_sink()](super::TransactionExecutor::execute_transaction_with_sink).
+///
+/// Outputs are reported in a deterministic order: all events in the order in which they were
+/// emitted by the transaction kernel, followed by all created notes in the order of their indices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionOutput {
+    /// An event emitted by the transaction kernel, reported once it has been handled by the
+    /// transaction host.
+    Event(TransactionEvent),
+
+    /// A note created by the transaction.
+    ///
+    /// Since assets can be added to a note until the transaction completes, notes are reported
+    /// only after the transaction program has been executed successfully.
+    Note(Box<OutputNote>),
+}
@@ -0,0 +1,123 @@
+use miden_lib::notes::scripts;
+use miden_objects::{
+    accounts::AccountId,
+    assets::FungibleAsset,
+    transaction::{ExecutedTransaction, OutputNote, TransactionMeasurements},
+    AssetError, Felt,
+};
+
+use crate::TransactionExecutorError;
+
+// FEE POLICY
+// ================================================================================================
+
+/// Defines how the fee for executing a transaction is computed.
+///
+/// Fees are computed from the [TransactionMeasurements] of an executed transaction, which allows
+/// different networks to charge for execution at different rates. Transaction measurements are
+/// collected only by executors with tracing enabled (see
+/// [TransactionExecutor::with_tracing()](super::TransactionExecutor::with_tracing)).
+///
+/// Note that since the output notes of a transaction are committed to by the transaction kernel,
+/// the fee cannot be appended to an already executed transaction. Instead, the fee computed by a
+/// policy is expected to be paid by the transaction itself (e.g., by a note to the fee collector
+/// created in the transaction script). Whether an executed transaction pays the fee can be
+/// validated via [FeePolicy::check_fee_paid()].
+pub trait FeePolicy: Send + Sync {
+    /// Returns the fee for executing a transaction with the provided measurements, or `None` if
+    /// the transaction is free.
+    fn compute_fee(&self, measurements: &TransactionMeasurements) -> Option<FungibleAsset>;
+
+    /// Returns the fee for executing the provided transaction, or `None` if the transaction is
+    /// free.
+    fn compute_transaction_fee(&self, transaction: &ExecutedTransaction) -> Option<FungibleAsset> {
+        self.compute_fee(transaction.measurements())
+    }
+
+    /// Checks that the provided transaction pays the fee computed by this policy to the specified
+    /// fee collector.
+    ///
+    /// The fee is considered paid by the assets of the fee faucet carried by the P2ID notes
+    /// created by the transaction for the fee collector. Only notes whose details are known to
+    /// the executor (i.e., [OutputNote::Full] notes) are taken into account, since the script and
+    /// inputs of other notes cannot be inspected.
+    ///
+    /// # Errors
+    /// Returns an error if the notes created for the fee collector carry less than the fee.
+    fn check_fee_paid(
+        &self,
+        transaction: &ExecutedTransaction,
+        collector: AccountId,
+    ) -> Result<(), TransactionExecutorError> {
+        let Some(fee) = self.compute_transaction_fee(transaction) else {
+            return Ok(());
+        };
+
+        let p2id_root = scripts::p2id().hash();
+        let collector_inputs = [Felt::from(collector)];
+        let paid = transaction
+            .output_notes()
+            .iter()
+            .filter_map(|note| match note {
+                OutputNote::Full(note) => Some(note),
+                _ => None,
+            })
+            .filter(|note| {
+                note.script().hash() == p2id_root && note.inputs().values() == collector_inputs
+            })
+            .fold(0u64, |paid, note| {
+                paid.saturating_add(note.assets().fungible_amount(fee.faucet_id()))
+            });
+
+        if paid < fee.amount() {
+            return Err(TransactionExecutorError::InsufficientFee { required: fee, paid });
+        }
+
+        Ok(())
+    }
+}
+
+// LINEAR FEE POLICY
+// ================================================================================================
+
+/// A [FeePolicy] charging a base fee plus a fixed rate per execution cycle, denominated in the
+/// asset of the specified fungible faucet.
+///
+/// The resulting fee is capped at [FungibleAsset::MAX_AMOUNT].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinearFeePolicy {
+    faucet_id: AccountId,
+    base_fee: u64,
+    fee_per_cycle: u64,
+}
+
+impl LinearFeePolicy {
+    /// Returns a new [LinearFeePolicy] charging `base_fee + fee_per_cycle * cycles` of the asset
+    /// issued by the specified faucet.
+    ///
+    /// # Errors
+    /// Returns an error if `faucet_id` is not the ID of a fungible faucet.
+    pub fn new(
+        faucet_id: AccountId,
+        base_fee: u64,
+        fee_per_cycle: u64,
+    ) -> Result<Self, AssetError> {
+        FungibleAsset::new(faucet_id, 0)?;
+        Ok(Self { faucet_id, base_fee, fee_per_cycle })
+    }
+}
+
+impl FeePolicy for LinearFeePolicy {
+    fn compute_fee(&self, measurements: &TransactionMeasurements) -> Option<FungibleAsset> {
+        let amount = self
+            .fee_per_cycle
+            .saturating_mul(measurements.total_cycles() as u64)
+            .saturating_add(self.base_fee)
+            .min(FungibleAsset::MAX_AMOUNT);
+        if amount == 0 {
+            return None;
+        }
+
+        Some(FungibleAsset::new(self.faucet_id, amount).expect("fee amount is valid"))
+    }
+}
@@ -0,0 +1,79 @@
+use core::fmt;
+
+use miden_lib::transaction::{
+    EXPIRATION_BLOCK_ELEMENT_IDX, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
+};
+use miden_objects::{vm::StackOutputs, Digest};
+
+// FINAL STACK
+// ================================================================================================
+
+/// The operand stack of the VM at the end of a transaction execution, as returned by
+/// [TransactionExecutor::execute_transaction_with_stack()](super::TransactionExecutor::execute_transaction_with_stack).
+///
+/// The transaction kernel leaves the following values at the top of the stack:
+///
+/// ```text
+/// [OUTPUT_NOTES_COMMITMENT, FINAL_ACCOUNT_HASH, expiration_block_num, 0, 0, 0, 0, 0, 0, 0]
+/// ```
+///
+/// The labeled values are accessible via the methods of this struct, while the raw stack is
+/// available via [Self::stack_outputs()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalStack {
+    stack_outputs: StackOutputs,
+}
+
+impl FinalStack {
+    pub(super) fn new(stack_outputs: StackOutputs) -> Self {
+        Self { stack_outputs }
+    }
+
+    /// Returns the commitment to the notes created by the transaction.
+    pub fn output_notes_commitment(&self) -> Digest {
+        self.word(OUTPUT_NOTES_COMMITMENT_WORD_IDX)
+    }
+
+    /// Returns the hash of the account state after the transaction was executed.
+    pub fn final_account_hash(&self) -> Digest {
+        self.word(FINAL_ACCOUNT_HASH_WORD_IDX)
+    }
+
+    /// Returns the block number at which the transaction expires.
+    ///
+    /// The kernel outputs `u32::MAX` if no expiration delta was set by the transaction.
+    pub fn expiration_block_num(&self) -> u32 {
+        self.stack_outputs
+            .get_stack_item(EXPIRATION_BLOCK_ELEMENT_IDX)
+            .expect("final stack contains the expiration block number")
+            .as_int() as u32
+    }
+
+    /// Returns the raw outputs of the transaction program.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    /// Consumes this struct and returns the raw outputs of the transaction program.
+    pub fn into_stack_outputs(self) -> StackOutputs {
+        self.stack_outputs
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn word(&self, word_idx: usize) -> Digest {
+        self.stack_outputs
+            .get_stack_word(word_idx * 4)
+            .expect("final stack contains the kernel output words")
+            .into()
+    }
+}
+
+impl fmt::Display for FinalStack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "OUTPUT_NOTES_COMMITMENT: {}", self.output_notes_commitment().to_hex())?;
+        writeln!(f, "FINAL_ACCOUNT_HASH: {}", self.final_account_hash().to_hex())?;
+        write!(f, "expiration_block_num: {}", self.expiration_block_num())
+    }
+}
@@ -0,0 +1,16 @@
+use miden_objects::notes::NoteId;
+
+// ACCOUNT STATE ORIGIN
+// ================================================================================================
+
+/// Identifies the point of a transaction execution at which an intermediate account state was
+/// captured by
+/// [TransactionExecutor::execute_transaction_with_intermediate_states()](super::TransactionExecutor::execute_transaction_with_intermediate_states).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountStateOrigin {
+    /// The state of the account after the execution of the specified input note.
+    Note(NoteId),
+
+    /// The final state of the account after the transaction was executed.
+    Transaction,
+}
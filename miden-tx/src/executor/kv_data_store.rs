@@ -0,0 +1,256 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{string::String, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    block::BlockNumber,
+    notes::{NoteId, Nullifier},
+    transaction::{ChainMmr, InputNote, InputNotes, TransactionInputs},
+    utils::serde::{Deserializable, Serializable},
+    BlockHeader, Word,
+};
+use winter_maybe_async::*;
+
+use super::DataStore;
+use crate::DataStoreError;
+
+// KEY-VALUE STORE TRAIT
+// ================================================================================================
+
+/// A generic key-value store which can be used as a backend for [KvDataStore].
+pub trait KvStore {
+    /// Returns the value stored under the specified key, or `None` if there is no such value.
+    fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Stores the provided value under the specified key, replacing the previous value if any.
+    fn put(&mut self, key: &str, value: Vec<u8>);
+}
+
+// KEY-VALUE DATA STORE
+// ================================================================================================
+
+/// A [DataStore] backed by a generic [KvStore].
+///
+/// The data required to execute transactions is stored under the following keys, where `{id}` is
+/// the hex-encoded ID of an account, note or nullifier, and `{block_num}` is the decimal number of
+/// a block:
+/// - [Self::ACCOUNT_KEY_PREFIX]`{id}`: the serialized [Account].
+/// - [Self::ACCOUNT_SEED_KEY_PREFIX]`{id}`: the serialized seed of a new account, if any.
+/// - [Self::ACCOUNT_AT_KEY_PREFIX]`{id}:{block_num}`: the serialized [Account] as of the end of the
+///   block, if historical states of the account are kept.
+/// - [Self::BLOCK_HEADER_KEY_PREFIX]`{block_num}`: the serialized [BlockHeader].
+/// - [Self::CHAIN_MMR_KEY_PREFIX]`{block_num}`: the serialized [ChainMmr] to be used by
+///   transactions executed against the block, i.e., a chain MMR for all preceding blocks.
+/// - [Self::NOTE_KEY_PREFIX]`{id}`: the serialized [InputNote].
+/// - [Self::NOTE_INDEX_KEY]: the serialized list of the IDs of all stored notes.
+/// - [Self::NULLIFIER_KEY_PREFIX]`{id}`: an empty value marking the note with this nullifier as
+///   consumed.
+///
+/// All values are encoded using their [Serializable] implementations.
+pub struct KvDataStore<K> {
+    store: K,
+}
+
+impl<K: KvStore> KvDataStore<K> {
+    /// Key prefix of serialized accounts.
+    pub const ACCOUNT_KEY_PREFIX: &'static str = "account:";
+
+    /// Key prefix of serialized seeds of new accounts.
+    pub const ACCOUNT_SEED_KEY_PREFIX: &'static str = "account_seed:";
+
+    /// Key prefix of serialized historical account states.
+    pub const ACCOUNT_AT_KEY_PREFIX: &'static str = "account_at:";
+
+    /// Key prefix of serialized block headers.
+    pub const BLOCK_HEADER_KEY_PREFIX: &'static str = "block_header:";
+
+    /// Key prefix of serialized chain MMRs.
+    pub const CHAIN_MMR_KEY_PREFIX: &'static str = "chain_mmr:";
+
+    /// Key prefix of serialized input notes.
+    pub const NOTE_KEY_PREFIX: &'static str = "note:";
+
+    /// Key of the list of the IDs of all stored notes.
+    pub const NOTE_INDEX_KEY: &'static str = "note_index";
+
+    /// Key prefix of nullifiers of consumed notes.
+    pub const NULLIFIER_KEY_PREFIX: &'static str = "nullifier:";
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [KvDataStore] backed by the provided key-value store.
+    pub fn new(store: K) -> Self {
+        Self { store }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the underlying key-value store.
+    pub fn store(&self) -> &K {
+        &self.store
+    }
+
+    /// Consumes self and returns the underlying key-value store.
+    pub fn into_store(self) -> K {
+        self.store
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Stores the provided account, together with its seed if the account is new.
+    pub fn insert_account(&mut self, account: &Account, account_seed: Option<Word>) {
+        let account_id = account.id().to_hex();
+        self.store
+            .put(&format!("{}{account_id}", Self::ACCOUNT_KEY_PREFIX), account.to_bytes());
+        if let Some(seed) = account_seed {
+            self.store
+                .put(&format!("{}{account_id}", Self::ACCOUNT_SEED_KEY_PREFIX), seed.to_bytes());
+        }
+    }
+
+    /// Stores the provided account as the state of the account as of the end of the block with
+    /// the specified number.
+    ///
+    /// This does not affect the latest state of the account stored via [Self::insert_account()].
+    pub fn insert_account_at(&mut self, account: &Account, block_num: BlockNumber) {
+        self.store.put(
+            &format!("{}{}:{block_num}", Self::ACCOUNT_AT_KEY_PREFIX, account.id().to_hex()),
+            account.to_bytes(),
+        );
+    }
+
+    /// Stores the provided block header together with the chain MMR to be used by transactions
+    /// executed against this block.
+    pub fn insert_block(&mut self, block_header: &BlockHeader, chain_mmr: &ChainMmr) {
+        let block_num = block_header.block_num();
+        self.store.put(
+            &format!("{}{block_num}", Self::BLOCK_HEADER_KEY_PREFIX),
+            block_header.to_bytes(),
+        );
+        self.store
+            .put(&format!("{}{block_num}", Self::CHAIN_MMR_KEY_PREFIX), chain_mmr.to_bytes());
+    }
+
+    /// Stores the provided input note and adds it to the note index.
+    pub fn insert_note(&mut self, note: &InputNote) {
+        self.store
+            .put(&format!("{}{}", Self::NOTE_KEY_PREFIX, note.id().to_hex()), note.to_bytes());
+
+        let mut note_ids = self.note_index().expect("note index was written by this store");
+        if !note_ids.contains(&note.id()) {
+            note_ids.push(note.id());
+            self.store.put(Self::NOTE_INDEX_KEY, note_ids.to_bytes());
+        }
+    }
+
+    /// Marks the note with the provided nullifier as consumed.
+    pub fn insert_nullifier(&mut self, nullifier: Nullifier) {
+        self.store
+            .put(&format!("{}{}", Self::NULLIFIER_KEY_PREFIX, nullifier.to_hex()), Vec::new());
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the IDs of all stored notes, in the order in which they were first stored.
+    fn note_index(&self) -> Result<Vec<NoteId>, DataStoreError> {
+        Ok(self.read(Self::NOTE_INDEX_KEY.into())?.unwrap_or_default())
+    }
+
+    /// Returns true if the note with the provided nullifier is marked as consumed.
+    fn is_consumed(&self, nullifier: Nullifier) -> bool {
+        self.store
+            .get(&format!("{}{}", Self::NULLIFIER_KEY_PREFIX, nullifier.to_hex()))
+            .is_some()
+    }
+
+    /// Returns the value stored under the specified key deserialized into `T`, or `None` if there
+    /// is no such value.
+    fn read<T: Deserializable>(&self, key: String) -> Result<Option<T>, DataStoreError> {
+        self.store
+            .get(&key)
+            .map(|bytes| {
+                T::read_from_bytes(&bytes).map_err(|err| {
+                    DataStoreError::InternalError(format!("failed to deserialize {key}: {err}"))
+                })
+            })
+            .transpose()
+    }
+}
+
+#[maybe_async_trait]
+impl<K: KvStore> DataStore for KvDataStore<K> {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let account: Account = self
+            .read(format!("{}{}", Self::ACCOUNT_KEY_PREFIX, account_id.to_hex()))?
+            .ok_or(DataStoreError::AccountNotFound(account_id))?;
+        let account_seed: Option<Word> =
+            self.read(format!("{}{}", Self::ACCOUNT_SEED_KEY_PREFIX, account_id.to_hex()))?;
+
+        let block_header: BlockHeader = self
+            .read(format!("{}{block_ref}", Self::BLOCK_HEADER_KEY_PREFIX))?
+            .ok_or(DataStoreError::BlockNotFound(block_ref))?;
+        let chain_mmr: ChainMmr = self
+            .read(format!("{}{block_ref}", Self::CHAIN_MMR_KEY_PREFIX))?
+            .ok_or(DataStoreError::BlockNotFound(block_ref))?;
+
+        let mut input_notes = Vec::with_capacity(notes.len());
+        for note_id in notes {
+            let note: InputNote = self
+                .read(format!("{}{}", Self::NOTE_KEY_PREFIX, note_id.to_hex()))?
+                .ok_or(DataStoreError::NoteNotFound(*note_id))?;
+            if self.is_consumed(note.note().nullifier()) {
+                return Err(DataStoreError::NoteAlreadyConsumed(*note_id));
+            }
+            input_notes.push(note);
+        }
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(account, account_seed, block_header, chain_mmr, input_notes)
+            .map_err(DataStoreError::InvalidTransactionInput)
+    }
+
+    #[maybe_async]
+    fn get_account_at(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<Account, DataStoreError> {
+        self.read(format!("{}{}:{block_ref}", Self::ACCOUNT_AT_KEY_PREFIX, account_id.to_hex()))?
+            .ok_or(DataStoreError::HistoricalStateUnavailable { account_id, block_num: block_ref })
+    }
+
+    /// Returns the unconsumed notes in the note index. Unauthenticated notes are returned
+    /// regardless of the specified block, while authenticated notes are returned only if they were
+    /// created in the specified block or an earlier one.
+    #[maybe_async]
+    fn get_unconsumed_notes(
+        &self,
+        block_ref: BlockNumber,
+    ) -> Result<Vec<InputNote>, DataStoreError> {
+        let mut notes = Vec::new();
+        for note_id in self.note_index()? {
+            let note: InputNote = self
+                .read(format!("{}{}", Self::NOTE_KEY_PREFIX, note_id.to_hex()))?
+                .ok_or(DataStoreError::NoteNotFound(note_id))?;
+            let recorded = note
+                .location()
+                .map_or(true, |location| location.block_num() <= block_ref.as_u32());
+            if recorded && !self.is_consumed(note.note().nullifier()) {
+                notes.push(note);
+            }
+        }
+        Ok(notes)
+    }
+}
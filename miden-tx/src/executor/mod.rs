@@ -1,26 +1,89 @@
-use alloc::{collections::BTreeSet, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeSet, string::ToString, sync::Arc, vec::Vec};
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    accounts::upgrade::BasicCodeUpgrade, transaction::TransactionKernel, AuthScheme, StdLibrary,
+};
 use miden_objects::{
-    accounts::{AccountCode, AccountId},
-    assembly::Library,
-    notes::NoteId,
-    transaction::{ExecutedTransaction, TransactionArgs, TransactionInputs},
+    accounts::{AccountCode, AccountId, AccountType},
+    assembly::{mast::MastNode, Library},
+    block::{BlockHeader, BlockNumber},
+    notes::{Note, NoteConsumptionProof, NoteExecutionHint, NoteId, NoteScript, Nullifier},
+    transaction::{
+        ExecutedTransaction, InputNote, InputNotes, OutputNote, OutputNotes, TransactionArgs,
+        TransactionInputs, TransactionMeasurements, TransactionScript, TransactionWitness,
+    },
     vm::StackOutputs,
-    MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
+    Digest, Felt, Hasher, NoteError, TransactionScriptError, Word, MAX_TX_EXECUTION_CYCLES,
+    MIN_TX_EXECUTION_CYCLES, ZERO,
 };
-use vm_processor::{ExecutionOptions, RecAdviceProvider};
+use vm_processor::{ExecutionError, MastForestStore, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{TransactionExecutorError, TransactionHost};
-use crate::auth::TransactionAuthenticator;
+use crate::{
+    auth::TransactionAuthenticator,
+    errors::tx_kernel_errors::ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT,
+    host::DebugRecord, DataStoreError,
+};
+
+mod auth_policy;
+pub use auth_policy::AuthPolicy;
+
+mod balance_projection;
+pub use balance_projection::BalanceProjection;
 
 mod data_store;
 pub use data_store::DataStore;
 
+mod execution_context;
+pub use execution_context::ExecutionContext;
+
+mod execution_output;
+pub use execution_output::ExecutionOutput;
+
+mod fee_policy;
+pub use fee_policy::{FeePolicy, LinearFeePolicy};
+
+mod final_stack;
+pub use final_stack::FinalStack;
+
+mod net_position;
+pub use net_position::NetPosition;
+
+mod note_asset_policy;
+pub use note_asset_policy::{AccountTypeAssetPolicy, NoteAssetPolicy};
+
+mod note_discovery;
+pub use note_discovery::{FullScanDiscovery, NoteDiscovery, TagBasedDiscovery};
+
+mod note_order;
+pub use note_order::optimize_note_order;
+
+mod intermediate_states;
+pub use intermediate_states::AccountStateOrigin;
+
+mod kv_data_store;
+pub use kv_data_store::{KvDataStore, KvStore};
+
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod script_cache;
+pub use script_cache::ScriptCacheStats;
+use script_cache::{ScriptCacheKey, TransactionScriptCache};
+
+mod script_whitelist;
+pub use script_whitelist::ScriptWhitelist;
+
+#[cfg(feature = "std")]
+mod warmup;
+#[cfg(feature = "std")]
+pub use warmup::{CodeWarmup, WarmupReport};
+
+mod advice_snapshot;
+pub use advice_snapshot::AdviceSnapshot;
+use advice_snapshot::{Observation, Observer, ObservingHost};
+
 // TRANSACTION EXECUTOR
 // ================================================================================================
 
@@ -40,7 +103,16 @@ pub struct TransactionExecutor {
     /// Holds the code of all accounts loaded into this transaction executor via the
     /// [Self::load_account_code()] method.
     account_codes: BTreeSet<AccountCode>,
-    exec_options: ExecutionOptions,
+    /// The version of the Miden standard library pinned via [Self::with_stdlib()].
+    stdlib: Library,
+    /// The default [ExecutionContext] used by all execution methods except
+    /// [Self::execute_transaction_with_context()].
+    context: ExecutionContext,
+    /// Holds the transaction scripts compiled via [Self::compile_tx_script()].
+    tx_script_cache: TransactionScriptCache,
+    /// The strategy used to discover the notes consumable by an account, set via
+    /// [Self::with_note_discovery()].
+    note_discovery: Arc<dyn NoteDiscovery>,
 }
 
 impl TransactionExecutor {
@@ -59,14 +131,11 @@ impl TransactionExecutor {
             data_store,
             mast_store: Arc::new(TransactionMastStore::new()),
             authenticator,
-            exec_options: ExecutionOptions::new(
-                Some(MAX_TX_EXECUTION_CYCLES),
-                MIN_TX_EXECUTION_CYCLES,
-                false,
-                false,
-            )
-            .expect("Must not fail while max cycles is more than min trace length"),
             account_codes: BTreeSet::new(),
+            stdlib: StdLibrary::default().into(),
+            context: ExecutionContext::new(),
+            tx_script_cache: TransactionScriptCache::default(),
+            note_discovery: Arc::new(FullScanDiscovery),
         }
     }
 
@@ -76,14 +145,7 @@ impl TransactionExecutor {
     /// account code) will be compiled and executed in debug mode. This will ensure that all debug
     /// instructions present in the original source code are executed.
     pub fn with_debug_mode(mut self, in_debug_mode: bool) -> Self {
-        self.exec_options = ExecutionOptions::new(
-            Some(self.exec_options.max_cycles()),
-            self.exec_options.expected_cycles(),
-            self.exec_options.enable_tracing(),
-            in_debug_mode,
-        )
-        .expect("failed to clone execution options");
-
+        self.context = self.context.with_debug_mode(in_debug_mode);
         self
     }
 
@@ -93,10 +155,319 @@ impl TransactionExecutor {
     /// transaction kernel complete. This enables collecting basic stats about how long different
     /// stages of transaction execution take.
     pub fn with_tracing(mut self) -> Self {
-        self.exec_options = self.exec_options.with_tracing();
+        self.context = self.context.with_tracing();
+        self
+    }
+
+    /// Pins the version of the Miden standard library used by this executor.
+    ///
+    /// The MAST of the provided library is loaded into the MAST store of this executor, and
+    /// [Self::check_stdlib_compatibility()] checks account code against it. Code executed by this
+    /// executor should be compiled against the same library, e.g., via
+    /// [TransactionKernel::assembler_with_stdlib()]. By default, the standard library bundled with
+    /// miden-lib is used. Since the transaction kernel and miden-lib depend on the bundled standard
+    /// library, it remains loaded into the MAST store.
+    pub fn with_stdlib(mut self, stdlib: Library) -> Self {
+        self.mast_store.insert(stdlib.mast_forest().clone());
+        self.stdlib = stdlib;
+        self
+    }
+
+    /// Restricts the notes which can be consumed by transactions executed by this executor to the
+    /// notes whose scripts are in the provided [ScriptWhitelist].
+    ///
+    /// The check is performed before a transaction is executed, and a transaction consuming a note
+    /// with a script outside of the whitelist is rejected with
+    /// [TransactionExecutorError::NoteScriptNotWhitelisted]. The whitelist is also enforced when
+    /// note scripts are compiled via [Self::compile_note_script()], in which case the error does
+    /// not identify a note. By default, notes with any script can be compiled and consumed.
+    pub fn with_note_script_whitelist(mut self, whitelist: ScriptWhitelist) -> Self {
+        self.context = self.context.with_note_script_whitelist(whitelist);
+        self
+    }
+
+    /// Sets the maximum number of cycles the execution of a single input note of the transactions
+    /// executed by this executor may take.
+    ///
+    /// The cycles taken by each note are counted from the start of its execution, and thus every
+    /// note has its own budget within the overall cycle limit of the transaction (see
+    /// [ExecutionContext::with_max_cycles()]). A transaction whose note exceeds the limit fails
+    /// with [TransactionExecutorError::NoteCycleLimitExceeded]. Since note boundaries are
+    /// reported via `trace` instructions, this enables tracing (see [Self::with_tracing()]).
+    ///
+    /// The limit is checked whenever the note emits a kernel event and at the end of its
+    /// execution, and thus a note may run past the limit until the next such point before the
+    /// execution is aborted.
+    pub fn with_per_note_cycle_limit(mut self, max_cycles: u32) -> Self {
+        self.context = self.context.with_per_note_cycle_limit(max_cycles);
+        self
+    }
+
+    /// Allows the input notes of the transactions executed by this executor to be consumed in a
+    /// different order than requested.
+    ///
+    /// The notes fetched from the [DataStore] are reordered via [optimize_note_order()] before the
+    /// transaction is executed, and the executed transaction reflects the order in which the notes
+    /// were consumed. This must only be enabled if the result of the transactions does not depend
+    /// on the order of their input notes: the order determines the commitment to the input notes
+    /// (and thus the transaction ID) and may determine the order of the created notes. By
+    /// default, notes are consumed in the requested order.
+    pub fn with_unordered_notes(mut self) -> Self {
+        self.context = self.context.with_unordered_notes();
+        self
+    }
+
+    /// Overrides the timestamp of the reference block of the transactions executed by this
+    /// executor.
+    ///
+    /// The block header fetched from the [DataStore] is replaced with a header which differs only
+    /// in the provided timestamp, and thus the transaction kernel, the executed transaction and
+    /// the reference block hash committed to by the transaction all reflect the overridden
+    /// timestamp. Since such a block header is not part of the chain, transactions executed with
+    /// an overridden timestamp cannot be included in a block. This is intended for making
+    /// time-dependent tests reproducible, and is available only with the `testing` feature.
+    #[cfg(any(feature = "testing", test))]
+    pub fn with_block_timestamp(mut self, timestamp: u32) -> Self {
+        self.context = self.context.with_block_timestamp(timestamp);
+        self
+    }
+
+    /// Sets the maximum number of output notes a transaction executed by this executor may create.
+    ///
+    /// A transaction creating more notes than allowed is rejected with
+    /// [TransactionExecutorError::TooManyOutputNotes]. By default, the limit is
+    /// [MAX_OUTPUT_NOTES_PER_TX](miden_objects::MAX_OUTPUT_NOTES_PER_TX), which is also enforced
+    /// by the transaction kernel; values above this limit have no effect.
+    pub fn with_max_output_notes(mut self, max_output_notes: usize) -> Self {
+        self.context = self.context.with_max_output_notes(max_output_notes);
+        self
+    }
+
+    /// Sets the [AuthPolicy] used to authenticate the input notes of the transactions executed by
+    /// this executor.
+    ///
+    /// By default, the policy is [AuthPolicy::Full].
+    pub fn with_auth_policy(mut self, auth_policy: AuthPolicy) -> Self {
+        self.context = self.context.with_auth_policy(auth_policy);
+        self
+    }
+
+    /// Sets the [NoteAssetPolicy] used to check the assets of the notes consumed by transactions
+    /// executed by this executor.
+    ///
+    /// Before a transaction is executed, every asset of every input note is checked against the
+    /// policy, and the transaction is rejected with
+    /// [TransactionExecutorError::IncompatibleNoteAssets] if any of them is not compatible with
+    /// the account. By default, no policy is applied.
+    pub fn with_note_asset_policy(mut self, policy: impl NoteAssetPolicy + 'static) -> Self {
+        self.context = self.context.with_note_asset_policy(policy);
         self
     }
 
+    /// Sets a function used to check whether the input notes of a transaction have already been
+    /// consumed.
+    ///
+    /// The function is called with the nullifier of every input note before the transaction is
+    /// executed, and should return true if the note with this nullifier has already been
+    /// consumed (e.g., if the nullifier is present in an external nullifier set). Transactions
+    /// consuming such notes are rejected with [TransactionExecutorError::NoteAlreadySpent].
+    pub fn with_nullifier_checker(
+        mut self,
+        is_spent: impl Fn(&Nullifier) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.context = self.context.with_nullifier_checker(is_spent);
+        self
+    }
+
+    /// Sets a function receiving the output of `debug` and `trace` instructions executed during
+    /// transactions.
+    ///
+    /// Each instruction produces a [DebugRecord] containing the clock cycle at which it was
+    /// executed and a description of the requested VM state (for `debug` instructions) or the
+    /// trace ID (for `trace` instructions). `debug` instructions are executed only when the
+    /// executor is in debug mode (see [Self::with_debug_mode()]) and `trace` instructions only when
+    /// tracing is enabled (see [Self::with_tracing()]); otherwise, the sink is not invoked.
+    /// Trace IDs used by the transaction kernel are not forwarded to the sink.
+    pub fn with_debug_sink(mut self, sink: impl Fn(DebugRecord) + Send + Sync + 'static) -> Self {
+        self.context = self.context.with_debug_sink(sink);
+        self
+    }
+
+    /// Sets a function rewriting the transaction scripts of the transactions executed by this
+    /// executor.
+    ///
+    /// The function is called with the transaction script of every transaction which has one,
+    /// before the transaction inputs are prepared, and the script it returns is executed instead.
+    /// This can be used to enforce invariants, e.g., by wrapping the provided script with
+    /// mandatory checks. Since the transaction arguments are updated with the returned script, the
+    /// executed transaction and its witness reflect the rewritten script. By default, transaction
+    /// scripts are executed as provided.
+    pub fn with_script_transform(
+        mut self,
+        transform: impl Fn(TransactionScript) -> TransactionScript + Send + Sync + 'static,
+    ) -> Self {
+        self.context = self.context.with_script_transform(transform);
+        self
+    }
+
+    /// Enables validation of the notes created by transactions executed by this executor.
+    ///
+    /// After a transaction is executed, the metadata of each created note is checked to contain a
+    /// valid sender ID and a tag consistent with the note type, and the recipient of each created
+    /// note (if known) is checked to be non-zero. A transaction creating a malformed note is
+    /// rejected with [TransactionExecutorError::MalformedCreatedNote].
+    pub fn with_created_note_validation(mut self) -> Self {
+        self.context = self.context.with_created_note_validation();
+        self
+    }
+
+    /// Adds notes created by the transactions executed by this executor to the notes consumed by
+    /// the same transactions, and enables tracing.
+    ///
+    /// Since these notes do not exist in the chain, they are consumed as unauthenticated notes
+    /// after the notes fetched from the [DataStore], in the provided order. Each of them must be
+    /// created by the transaction before it is consumed (i.e., by the script of a note consumed
+    /// earlier), and thus can only be consumed together with the note which creates it; otherwise
+    /// the execution fails with [TransactionExecutorError::UncreatedInternalNote]. The check is
+    /// performed when the execution of a note starts, which requires tracing (see
+    /// [Self::with_tracing()]). Internal notes are subject to the same checks as the other input
+    /// notes, e.g., they must satisfy the note script whitelist and the note asset policy, if any.
+    pub fn with_internal_notes(mut self, notes: Vec<Note>) -> Self {
+        self.context = self.context.with_internal_notes(notes);
+        self
+    }
+
+    /// Sets the root which the transaction scripts of the transactions executed by this executor
+    /// are expected to have.
+    ///
+    /// This binds a script root submitted by a user to the script which is actually executed.
+    /// Before a transaction is executed, the root of its transaction script is compared against
+    /// the expected root, and the transaction is rejected with
+    /// [TransactionExecutorError::ScriptRootMismatch] if they differ. The check is performed
+    /// against the script provided with the transaction arguments, i.e., before it is rewritten by
+    /// a script transform (see [Self::with_script_transform()]). Transactions without a
+    /// transaction script are not affected.
+    pub fn with_expected_tx_script_root(mut self, root: Digest) -> Self {
+        self.context = self.context.with_expected_tx_script_root(root);
+        self
+    }
+
+    /// Sets the [NoteDiscovery] strategy used by [Self::available_notes()] and
+    /// [Self::consume_all()] to discover the notes consumable by an account.
+    ///
+    /// By default, the [FullScanDiscovery] strategy is used.
+    pub fn with_note_discovery(mut self, note_discovery: impl NoteDiscovery + 'static) -> Self {
+        self.note_discovery = Arc::new(note_discovery);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of the Miden standard library pinned by this executor.
+    ///
+    /// The version is the commitment to the procedures exported by the library, and thus differs
+    /// between any two libraries exporting different code.
+    pub fn stdlib_version(&self) -> Digest {
+        *self.stdlib.digest()
+    }
+
+    /// Returns the statistics of the cache of transaction scripts compiled via
+    /// [Self::compile_tx_script()].
+    pub fn tx_script_cache_stats(&self) -> ScriptCacheStats {
+        self.tx_script_cache.stats()
+    }
+
+    /// Checks whether the provided account code was compiled against the standard library pinned
+    /// by this executor.
+    ///
+    /// The check is advisory and does not prevent the code from being loaded or executed; callers
+    /// may treat a failure as a warning. Note that the check can only detect references to
+    /// procedures which the pinned standard library does not provide.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The code references a procedure of the bundled standard library which is not provided by
+    ///   the pinned library ([TransactionExecutorError::StdlibVersionMismatch]).
+    /// - The code references any other procedure which is not available in the MAST store of this
+    ///   executor, e.g., a procedure of a library which has not been loaded yet
+    ///   ([TransactionExecutorError::MissingProcedure]).
+    pub fn check_stdlib_compatibility(
+        &self,
+        code: &AccountCode,
+    ) -> Result<(), TransactionExecutorError> {
+        let pinned_procedures: BTreeSet<Digest> =
+            self.stdlib.mast_forest().procedure_digests().collect();
+        let bundled_stdlib = StdLibrary::default();
+        let bundled_procedures: BTreeSet<Digest> =
+            bundled_stdlib.mast_forest().procedure_digests().collect();
+
+        // a procedure consisting only of a reference to another procedure is represented by an
+        // external node, and thus only non-external nodes are defined by the code itself
+        let mast = code.mast();
+        let (external_nodes, own_nodes): (Vec<_>, Vec<_>) =
+            mast.nodes().iter().partition(|node| node.is_external());
+        let own_nodes: BTreeSet<Digest> = own_nodes.into_iter().map(|node| node.digest()).collect();
+        for procedure_root in external_nodes.into_iter().map(|node| node.digest()) {
+            if pinned_procedures.contains(&procedure_root) || own_nodes.contains(&procedure_root) {
+                continue;
+            }
+            if bundled_procedures.contains(&procedure_root) {
+                return Err(TransactionExecutorError::StdlibVersionMismatch {
+                    code_commitment: code.commitment(),
+                    procedure_root,
+                });
+            }
+            if self.mast_store.get(&procedure_root).is_none() {
+                return Err(TransactionExecutorError::MissingProcedure {
+                    code_commitment: code.commitment(),
+                    procedure_root,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the roots of the procedures of the transaction kernel used by this executor.
+    pub fn kernel_procedure_roots(&self) -> &'static [Digest] {
+        TransactionKernel::PROCEDURES[0]
+    }
+
+    /// Checks whether all kernel procedures called by the provided account code are provided by
+    /// the transaction kernel used by this executor.
+    ///
+    /// Account code compiled against a different version of the kernel may reference kernel
+    /// procedures via roots which this executor does not recognize, in which case executing the
+    /// code would fail only once such a procedure is called.
+    ///
+    /// Note that merging the MAST forests of account components turns syscalls into regular
+    /// calls, and thus only kernel calls of code whose MAST was not merged are checked.
+    ///
+    /// # Errors
+    /// Returns [TransactionExecutorError::UnknownKernelProcedure] for the first kernel procedure
+    /// called by the code which is not provided by the kernel.
+    pub fn verify_kernel_calls(&self, code: &AccountCode) -> Result<(), TransactionExecutorError> {
+        let kernel_procedures: BTreeSet<Digest> =
+            self.kernel_procedure_roots().iter().copied().collect();
+
+        let mast = code.mast();
+        for node in mast.nodes() {
+            let MastNode::Call(call_node) = node else {
+                continue;
+            };
+            let procedure_root = mast[call_node.callee()].digest();
+            if call_node.is_syscall() && !kernel_procedures.contains(&procedure_root) {
+                return Err(TransactionExecutorError::UnknownKernelProcedure {
+                    code_commitment: code.commitment(),
+                    procedure_root,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -110,6 +481,42 @@ impl TransactionExecutor {
         self.account_codes.insert(code.clone());
     }
 
+    /// Loads the code of the provided accounts into this executor ahead of executing
+    /// transactions against them, and returns a report with the time taken for each code and in
+    /// total.
+    ///
+    /// Each code is loaded as via [Self::load_account_code()] unless it has already been loaded,
+    /// and is then checked via [Self::check_stdlib_compatibility()]. Since the check is advisory,
+    /// codes which fail it are loaded as well, and the failures are reported in the returned
+    /// [WarmupReport]. Account code is compiled when the account is created, and thus this
+    /// front-loads the cost of registering the procedures of each code with the MAST store of
+    /// this executor.
+    #[cfg(feature = "std")]
+    pub fn warmup(&mut self, codes: &[(AccountId, AccountCode)]) -> WarmupReport {
+        let start = std::time::Instant::now();
+        let codes = codes
+            .iter()
+            .map(|(account_id, code)| {
+                let code_start = std::time::Instant::now();
+                let already_loaded = self.account_codes.contains(code);
+                if !already_loaded {
+                    self.load_account_code(code);
+                }
+                let result = self.check_stdlib_compatibility(code);
+
+                CodeWarmup::new(
+                    *account_id,
+                    code.commitment(),
+                    code_start.elapsed(),
+                    already_loaded,
+                    result,
+                )
+            })
+            .collect();
+
+        WarmupReport::new(codes, start.elapsed())
+    }
+
     /// Loads the provided library code into the internal MAST forest store.
     ///
     /// TODO: this is a work-around to support accounts which were complied with user-defined
@@ -118,6 +525,96 @@ impl TransactionExecutor {
         self.mast_store.insert(library.mast_forest().clone());
     }
 
+    // TRANSACTION SCRIPT COMPILATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the provided transaction script source code into a [TransactionScript] with the
+    /// provided inputs.
+    ///
+    /// The script is compiled against the transaction kernel, the standard library pinned by this
+    /// executor, miden-lib and the provided libraries (e.g., the libraries of the components of
+    /// the account the script is executed against), in the debug mode of this executor. Code of
+    /// the provided libraries which is not part of the account code must be loaded into the MAST
+    /// store of this executor before the script is executed.
+    ///
+    /// Compiled scripts are cached by the hash of their source code together with the libraries
+    /// they are linked against, and thus compiling the same script again, even with different
+    /// inputs, does not recompile it. See [Self::tx_script_cache_stats()].
+    ///
+    /// # Errors
+    /// Returns an error if the script cannot be compiled.
+    pub fn compile_tx_script(
+        &self,
+        source: &str,
+        inputs: impl IntoIterator<Item = (Word, Vec<Felt>)>,
+        libraries: &[Library],
+    ) -> Result<TransactionScript, TransactionExecutorError> {
+        let in_debug_mode = self.context.exec_options.enable_debugging();
+        let key = ScriptCacheKey {
+            source_hash: Hasher::hash(source.as_bytes()),
+            stdlib_version: self.stdlib_version(),
+            library_digests: libraries.iter().map(|library| *library.digest()).collect(),
+            in_debug_mode,
+        };
+
+        let program = self
+            .tx_script_cache
+            .get_or_compile(key, || {
+                let assembler = TransactionKernel::assembler_with_stdlib(self.stdlib.clone())
+                    .with_debug_mode(in_debug_mode);
+                libraries
+                    .iter()
+                    .try_fold(assembler, |assembler, library| assembler.with_library(library))
+                    .and_then(|assembler| assembler.assemble_program(source))
+                    .map_err(|report| TransactionScriptError::AssemblyError(report.into()))
+            })
+            .map_err(TransactionExecutorError::TransactionScriptCompilationFailed)?;
+
+        Ok(TransactionScript::new(program, inputs))
+    }
+
+    /// Compiles the provided source code into a note script.
+    ///
+    /// The script is compiled in the same way as transaction scripts compiled via
+    /// [Self::compile_tx_script()], i.e., against the standard library pinned by this executor,
+    /// miden-lib and the provided libraries, in the debug mode of this executor. Note scripts are
+    /// not cached.
+    ///
+    /// If a note script whitelist is set (see [Self::with_note_script_whitelist()]), the root of
+    /// the compiled script is checked against it, and thus scripts which could not be consumed by
+    /// this executor are rejected when they are compiled rather than when they are consumed.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script cannot be compiled.
+    /// - A note script whitelist is set and the root of the compiled script is not in it
+    ///   ([TransactionExecutorError::NoteScriptNotWhitelisted]).
+    pub fn compile_note_script(
+        &self,
+        source: &str,
+        libraries: &[Library],
+    ) -> Result<NoteScript, TransactionExecutorError> {
+        let assembler = TransactionKernel::assembler_with_stdlib(self.stdlib.clone())
+            .with_debug_mode(self.context.exec_options.enable_debugging());
+        let note_script = libraries
+            .iter()
+            .try_fold(assembler, |assembler, library| assembler.with_library(library))
+            .map_err(|report| NoteError::NoteScriptAssemblyError(report.into()))
+            .and_then(|assembler| NoteScript::compile(source, assembler))
+            .map_err(TransactionExecutorError::NoteScriptCompilationFailed)?;
+
+        if let Some(whitelist) = &self.context.note_script_whitelist {
+            if !whitelist.is_allowed(&note_script.hash()) {
+                return Err(TransactionExecutorError::NoteScriptNotWhitelisted {
+                    note_id: None,
+                    script_root: note_script.hash(),
+                });
+            }
+        }
+
+        Ok(note_script)
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -128,20 +625,642 @@ impl TransactionExecutor {
     /// and compile the transaction into an executable program. Then, it executes the transaction
     /// program and creates an [ExecutedTransaction] object.
     ///
+    /// Transactions which do not consume any notes are fully supported: when `notes` is empty, the
+    /// transaction executes only the prologue, the optional transaction script and the epilogue,
+    /// and the commitment to the input notes is set to the empty digest. This is the expected way
+    /// of invoking account methods directly (e.g., to update account storage via a transaction
+    /// script).
+    ///
     /// # Errors:
     /// Returns an error if:
+    /// - If the same note ID appears in `notes` more than once.
     /// - If required data can not be fetched from the [DataStore].
+    /// - If any of the input notes is timelocked until a block after the reference block.
+    /// - If a nullifier checker is set and any of the input notes has already been consumed.
+    /// - If a note script whitelist is set and any of the input notes has a script which is not in
+    ///   the whitelist.
+    /// - If a note asset policy is set and any of the input notes carries an asset which is not
+    ///   compatible with the account.
+    /// - If an expected transaction script root is set and the transaction script has a different
+    ///   root.
+    /// - If the execution of an input note exceeds the per-note cycle limit, if set, or if an
+    ///   internal note is consumed before it is created.
+    /// - If the transaction creates more output notes than allowed by this executor.
+    /// - If the transaction is executed against a new account and does not increment its nonce.
+    /// - If the transaction requests a signature which is neither provided via the advice inputs
+    ///   nor by an authenticator.
+    /// - If created note validation is enabled and the transaction creates a malformed note.
+    /// - If the transaction mints an amount which would overflow the total issuance of the faucet.
+    /// - If a note consumption proof is provided for a note which is not consumed by the
+    ///   transaction, or if a note script requests a consumption proof which was not provided.
+    /// - If the transaction program fails for any other reason.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
         account_id: AccountId,
-        block_ref: u32,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, &self.context, None)?;
+        Ok(result.executed_transaction)
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments using the provided
+    /// [ExecutionContext] instead of the default context of this executor, and returns an
+    /// [ExecutedTransaction].
+    ///
+    /// This allows configuring individual transactions (e.g., with a tighter cycle limit or a
+    /// dedicated event hook) without creating a separate executor. Apart from that, this method
+    /// behaves exactly as [Self::execute_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()], with the
+    /// policies configured in the provided context applied instead of the ones of this executor.
+    #[maybe_async]
+    pub fn execute_transaction_with_context(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
         notes: &[NoteId],
         tx_args: TransactionArgs,
+        context: &ExecutionContext,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, context, None)?;
+        Ok(result.executed_transaction)
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns the
+    /// resulting [ExecutedTransaction] together with the advice recorder used during execution.
+    ///
+    /// The returned [RecAdviceProvider] is in its post-execution state and can be used to inspect
+    /// which advice data (e.g., Merkle nodes) was requested by the VM while executing the
+    /// transaction. Apart from that, this method behaves exactly as
+    /// [Self::execute_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn execute_transaction_retaining_recorder(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(ExecutedTransaction, RecAdviceProvider), TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let mut context = self.context.clone();
+        context.retain_recorder = true;
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, &context, None)?;
+        let advice_recorder = result.advice_recorder.expect("advice recorder was retained");
+        Ok((result.executed_transaction, advice_recorder))
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns the
+    /// resulting [ExecutedTransaction] together with a snapshot of the advice provider taken at
+    /// the specified clock cycle.
+    ///
+    /// The returned [AdviceSnapshot] contains a copy of the [RecAdviceProvider] as it was at the
+    /// first interaction of the VM with the host at or after `clk`. The execution is not
+    /// suspended when the snapshot is taken, and taking it does not affect the execution; thus,
+    /// the resulting [ExecutedTransaction] (and its witness) is identical to the one produced by
+    /// [Self::execute_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the transaction completes without interacting with the host at or after `clk`.
+    /// - For any of the other reasons listed for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn execute_transaction_with_advice_snapshot(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+        clk: u32,
+    ) -> Result<(ExecutedTransaction, AdviceSnapshot), TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let mut context = self.context.clone();
+        context.snapshot_clk = Some(clk);
+        let mut snapshot = None;
+        let mut observer = |observation| {
+            if let Observation::Snapshot(advice_snapshot) = observation {
+                snapshot = Some(advice_snapshot);
+            }
+        };
+        let result =
+            self.execute_transaction_inputs(tx_inputs, tx_args, &context, Some(&mut observer))?;
+
+        let snapshot = snapshot.ok_or(TransactionExecutorError::AdviceSnapshotNotReached(clk))?;
+        Ok((result.executed_transaction, snapshot))
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns the
+    /// resulting [ExecutedTransaction] together with the hashes of the intermediate states of the
+    /// account.
+    ///
+    /// The returned list contains the hash of the account state after each input note was
+    /// executed, in the order in which the notes were executed, followed by the hash of the final
+    /// account state (which also reflects the changes made by the transaction script and the
+    /// epilogue). Thus, the last entry is always equal to the hash of the final account of the
+    /// returned transaction.
+    ///
+    /// Intermediate states are captured at the end of every note execution, which requires
+    /// tracing; thus, tracing is enabled for the transaction regardless of the configuration of
+    /// this executor. Apart from that, this method behaves exactly as
+    /// [Self::execute_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn execute_transaction_with_intermediate_states(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(ExecutedTransaction, Vec<(AccountStateOrigin, Digest)>), TransactionExecutorError>
+    {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let mut context = self.context.clone().with_tracing();
+        context.capture_note_states = true;
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, &context, None)?;
+        let executed_transaction = result.executed_transaction;
+
+        let final_hash = executed_transaction.final_account().hash();
+        let states = result
+            .note_states
+            .into_iter()
+            .map(|(note_id, hash)| (AccountStateOrigin::Note(note_id), hash))
+            .chain([(AccountStateOrigin::Transaction, final_hash)])
+            .collect();
+
+        Ok((executed_transaction, states))
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns the
+    /// resulting [TransactionWitness] together with the final stack of the transaction program.
+    ///
+    /// Both are obtained from a single execution of the transaction kernel. The returned
+    /// [FinalStack] exposes the raw outputs of the VM as well as the values placed on the stack by
+    /// the kernel epilogue (the output notes commitment, the final account hash and the expiration
+    /// block number), which makes this method useful for debugging kernel outputs. Apart from
+    /// that, this method behaves exactly as [Self::execute_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn execute_transaction_with_stack(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(TransactionWitness, FinalStack), TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, &self.context, None)?;
+        Ok((result.executed_transaction.into(), FinalStack::new(result.stack_outputs)))
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments, reporting its
+    /// outputs to the provided sink, and returns the resulting [TransactionWitness].
+    ///
+    /// Events emitted by the transaction kernel are reported as they are handled. Since assets can
+    /// be added to a created note until the transaction completes, created notes are reported
+    /// once the transaction program has been executed, in the order of their indices. Thus, the
+    /// sink observes the outputs of a transaction in a deterministic order. The created notes are
+    /// moved into the sink rather than copied, but they are all held in memory until the
+    /// transaction completes, so this method does not bound the memory required to execute
+    /// transactions creating many notes. Apart from the account delta and the outputs reported to
+    /// the sink, the returned witness contains all the data required to re-execute and prove the
+    /// transaction.
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()]. If the
+    /// execution fails, the events reported to the sink before the failure should be discarded,
+    /// and no notes are reported.
+    #[maybe_async]
+    pub fn execute_transaction_with_sink(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+        mut sink: impl FnMut(ExecutionOutput),
+    ) -> Result<TransactionWitness, TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        let mut observer = |observation| {
+            if let Observation::Output(output) = observation {
+                sink(output);
+            }
+        };
+        let result = self.execute_transaction_inputs(
+            tx_inputs,
+            tx_args,
+            &self.context,
+            Some(&mut observer),
+        )?;
+
+        let (_, tx_outputs, tx_witness, _) = result.executed_transaction.into_parts();
+        for note in tx_outputs.output_notes.into_vec() {
+            sink(ExecutionOutput::Note(Box::new(note)));
+        }
+
+        Ok(tx_witness)
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments, and returns the
+    /// resulting [TransactionWitness], the [TransactionMeasurements] of the execution, and the
+    /// notes created by the transaction.
+    ///
+    /// All outputs are derived from a single execution of the transaction program. Only created
+    /// notes whose details are fully known (i.e., [OutputNote::Full] notes) are returned, in the
+    /// order of their indices.
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()]. The
+    /// variant of the returned [TransactionExecutorError] identifies the phase which failed, e.g.,
+    /// [TransactionExecutorError::FetchTransactionInputsFailed] if the inputs could not be fetched,
+    /// [TransactionExecutorError::ExecuteTransactionProgramFailed] if the transaction program
+    /// failed, or [TransactionExecutorError::InvalidTransactionOutput] if the outputs of the
+    /// program could not be processed.
+    #[maybe_async]
+    pub fn execute_transaction_full(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<(TransactionWitness, TransactionMeasurements, Vec<Note>), TransactionExecutorError>
+    {
+        let executed_transaction =
+            maybe_await!(self.execute_transaction(account_id, block_ref, notes, tx_args))?;
+
+        let (_, tx_outputs, tx_witness, tx_measurements) = executed_transaction.into_parts();
+        let created_notes = tx_outputs
+            .output_notes
+            .iter()
+            .filter_map(|note| match note {
+                OutputNote::Full(note) => Some(note.clone()),
+                OutputNote::Partial(_) | OutputNote::Header(_) => None,
+            })
+            .collect();
+
+        Ok((tx_witness, tx_measurements, created_notes))
+    }
+
+    /// Executes a transaction which runs the provided transaction script against the specified
+    /// account without consuming any notes, and returns the resulting [ExecutedTransaction].
+    ///
+    /// This is a convenience for account configuration updates: the script is expected to update
+    /// the storage of the account and increment its nonce, and thus the account delta of the
+    /// resulting transaction contains only storage and nonce changes. If `strict` is set, the
+    /// transaction is rejected with [TransactionExecutorError::StorageUpdateCreatedNotes] if the
+    /// script creates any output notes.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If `strict` is set and the transaction creates output notes.
+    /// - For any of the other reasons listed for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn execute_storage_update(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        tx_script: TransactionScript,
+        strict: bool,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, &[]))?;
+
+        let tx_args = TransactionArgs::with_tx_script(tx_script);
+        let executed_transaction = self
+            .execute_transaction_inputs(tx_inputs, tx_args, &self.context, None)?
+            .executed_transaction;
+
+        let num_output_notes = executed_transaction.output_notes().num_notes();
+        if strict && num_output_notes > 0 {
+            return Err(TransactionExecutorError::StorageUpdateCreatedNotes(num_output_notes));
+        }
+
+        Ok(executed_transaction)
+    }
+
+    /// Executes a transaction which replaces the code of the specified account with the provided
+    /// code, and returns the resulting [ExecutedTransaction].
+    ///
+    /// The transaction runs the standard migration script: it passes the commitment to the new
+    /// code to the `set_code` procedure of the [BasicCodeUpgrade] component, and then invokes the
+    /// authentication procedure of the account, which increments the nonce of the account. The
+    /// new code takes effect once the transaction has been executed, and thus the final account
+    /// header of the returned transaction contains the commitment to the new code.
+    ///
+    /// The new code is checked for compatibility with the standard library pinned by this
+    /// executor before the transaction is executed (see [Self::check_stdlib_compatibility()]).
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the account is not a regular account with updatable code, if its code does not include
+    ///   the [BasicCodeUpgrade] component, or if it does not use any of the known authentication
+    ///   schemes.
+    /// - If the new code is identical to the current code of the account.
+    /// - If the new code is not compatible with the pinned standard library.
+    /// - If the migration script cannot be compiled.
+    /// - For any of the other reasons listed for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn execute_code_migration(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        new_code: AccountCode,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, &[]))?;
+
+        let account = tx_inputs.account();
+        let auth_scheme = AuthScheme::from_account(account)
+            .filter(|_| account_id.account_type() == AccountType::RegularAccountUpdatableCode)
+            .filter(|_| BasicCodeUpgrade::is_included_in(account.code()))
+            .ok_or(TransactionExecutorError::AccountCodeNotUpgradable(account_id))?;
+        if new_code.commitment() == account.code().commitment() {
+            return Err(TransactionExecutorError::UnchangedAccountCode(new_code.commitment()));
+        }
+        self.check_stdlib_compatibility(&new_code)?;
+
+        let tx_script = TransactionScript::compile(
+            format!(
+                "begin push.{code_commitment} call.{set_code} dropw call.{auth} end",
+                code_commitment = new_code
+                    .commitment()
+                    .iter()
+                    .map(|element| element.as_int().to_string())
+                    .collect::<Vec<_>>()
+                    .join("."),
+                set_code = BasicCodeUpgrade::set_code_procedure_root(),
+                auth = auth_scheme.procedure_root(),
+            ),
+            [],
+            TransactionKernel::assembler_with_stdlib(self.stdlib.clone()),
+        )
+        .map_err(TransactionExecutorError::TransactionScriptCompilationFailed)?;
+
+        let tx_args = TransactionArgs::with_tx_script(tx_script);
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, &self.context, None)?;
+        Ok(result.executed_transaction)
+    }
+
+    /// Projects the vault of the specified account after a transaction consuming the specified
+    /// notes and executing a transaction script (if `tx_script` is provided) has been executed
+    /// successfully, without executing the transaction.
+    ///
+    /// Only the statically determinable effects of the transaction are reflected in the returned
+    /// [BalanceProjection]; if the input notes use custom scripts or a transaction script is
+    /// provided, the projection is marked as incomplete (see [BalanceProjection::is_complete()]).
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the same note ID appears in `notes` more than once.
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the statically determinable effects of the transaction cannot be applied to the vault
+    ///   of the account, i.e., the transaction would fail.
+    #[maybe_async]
+    pub fn project_balance_change(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+        tx_script: Option<&TransactionScript>,
+    ) -> Result<BalanceProjection, TransactionExecutorError> {
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, notes))?;
+
+        BalanceProjection::new(
+            tx_inputs.account().vault().clone(),
+            tx_inputs.input_notes().iter().map(|note| note.note()),
+            tx_script.is_some(),
+        )
+        .map_err(TransactionExecutorError::BalanceProjectionFailed)
+    }
+
+    /// Returns the IDs of the notes recorded in the chain by the specified block which may be
+    /// consumed by the specified account, as discovered by the [NoteDiscovery] strategy of this
+    /// executor.
+    ///
+    /// # Errors:
+    /// Returns an error if the notes can not be discovered, e.g., because the [DataStore] cannot
+    /// enumerate the notes it holds ([TransactionExecutorError::NoteDiscoveryFailed]).
+    #[maybe_async]
+    pub fn available_notes(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<Vec<NoteId>, TransactionExecutorError> {
+        maybe_await!(self.note_discovery.discover(self.data_store.as_ref(), account_id, block_ref))
+            .map_err(TransactionExecutorError::NoteDiscoveryFailed)
+    }
+
+    /// Returns the net position of the specified account, i.e., the assets in its vault together
+    /// with the assets of the notes returned by [Self::available_notes()].
+    ///
+    /// The returned [NetPosition] distinguishes the settled assets (in the vault of the account as
+    /// of the specified block) from the pending ones (carried by the discovered notes), and sums
+    /// them per faucet. No transaction is executed, and thus the pending assets include the
+    /// assets of notes which may eventually fail to be consumed.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the notes can not be discovered.
+    /// - If required data can not be fetched from the [DataStore].
+    /// - If the assets cannot be summed, e.g., if the total amount of a fungible asset exceeds the
+    ///   maximum fungible asset amount.
+    #[maybe_async]
+    pub fn net_position(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<NetPosition, TransactionExecutorError> {
+        let notes = maybe_await!(self.available_notes(account_id, block_ref))?;
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, &notes))?;
+
+        NetPosition::new(
+            tx_inputs.account().vault().clone(),
+            tx_inputs.input_notes().iter().map(|note| note.note()),
+        )
+        .map_err(TransactionExecutorError::NetPositionFailed)
+    }
+
+    /// Executes a transaction consuming all notes returned by [Self::available_notes()] and
+    /// returns the resulting [ExecutedTransaction].
+    ///
+    /// The notes are consumed in the order in which they were discovered. Apart from that, this
+    /// method behaves exactly as [Self::execute_transaction()].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the notes can not be discovered.
+    /// - If any of the discovered notes cannot be consumed, or for any of the other reasons listed
+    ///   for [Self::execute_transaction()].
+    #[maybe_async]
+    pub fn consume_all(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let notes = maybe_await!(self.available_notes(account_id, block_ref))?;
+        maybe_await!(self.execute_transaction(account_id, block_ref, &notes, tx_args))
+    }
+
+    /// Executes a read-only transaction against the state of the specified account as of the end
+    /// of the block with the specified number, and returns the resulting [ExecutedTransaction].
+    ///
+    /// The historical account state is fetched via [DataStore::get_account_at()], while the block
+    /// header and chain MMR are fetched via [DataStore::get_transaction_inputs()]. The transaction
+    /// consumes no notes and executes no transaction script, and so it does not modify the state
+    /// of the account. The returned transaction can be used to inspect the account state as it was
+    /// at the specified block, e.g., via its initial account and advice witness; it is not meant
+    /// to be proven or submitted to the network.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the data store does not keep historical account states, or does not have the state of
+    ///   the account as of the specified block ([DataStoreError::HistoricalStateUnavailable]).
+    /// - If any other required data can not be fetched from the [DataStore].
+    #[maybe_async]
+    pub fn read_historical(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let account = maybe_await!(self.data_store.get_account_at(account_id, block_ref))
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+        let tx_inputs = maybe_await!(self.fetch_transaction_inputs(account_id, block_ref, &[]))?;
+
+        let (_, account_seed, block_header, block_chain, input_notes) = tx_inputs.into_parts();
+        let account_seed = account_seed.filter(|_| account.is_new());
         let tx_inputs =
-            maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
-                .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
+            TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+                .map_err(|err| {
+                TransactionExecutorError::FetchTransactionInputsFailed(
+                    DataStoreError::InvalidTransactionInput(err),
+                )
+            })?;
+
+        let result = self.execute_transaction_inputs(
+            tx_inputs,
+            TransactionArgs::default(),
+            &self.context,
+            None,
+        )?;
+        Ok(result.executed_transaction)
+    }
+
+    /// Executes a transaction against the provided [TransactionInputs] and returns an
+    /// [ExecutedTransaction].
+    ///
+    /// Unlike [Self::execute_transaction()], this method does not fetch any data from the
+    /// [DataStore]. This allows the transaction inputs and arguments to be prepared on one machine
+    /// (e.g., a machine with access to the chain state), serialized, and executed on another one
+    /// (e.g., an air-gapped signing machine). Both [TransactionInputs] and [TransactionArgs] are
+    /// validated for internal consistency when deserialized.
+    ///
+    /// # Errors:
+    /// Returns an error for any of the reasons listed for [Self::execute_transaction()], except
+    /// for those related to fetching data from the [DataStore].
+    pub fn execute_prepared_transaction(
+        &self,
+        tx_inputs: TransactionInputs,
+        tx_args: TransactionArgs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let result = self.execute_transaction_inputs(tx_inputs, tx_args, &self.context, None)?;
+        Ok(result.executed_transaction)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Fetches the inputs of a transaction consuming the specified notes from the [DataStore].
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - If the same note ID appears in `notes` more than once.
+    /// - If required data can not be fetched from the [DataStore].
+    #[maybe_async]
+    fn fetch_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, TransactionExecutorError> {
+        validate_note_ids(notes)?;
+
+        maybe_await!(self.data_store.get_transaction_inputs(account_id, block_ref, notes))
+            .map_err(TransactionExecutorError::FetchTransactionInputsFailed)
+    }
+
+    /// Validates the provided inputs against the policies configured in the provided context,
+    /// executes the transaction kernel against them and returns the resulting [ExecutionResult].
+    ///
+    /// The result contains the optional outputs requested by the context as well. If an observer
+    /// is provided, the events emitted by the kernel are reported to it as they are handled, and
+    /// so is a copy of the advice recorder in its state at the snapshot clock cycle set in the
+    /// context (if any), even if the execution fails past that cycle.
+    fn execute_transaction_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+        mut tx_args: TransactionArgs,
+        context: &ExecutionContext,
+        observer: Option<Observer>,
+    ) -> Result<ExecutionResult, TransactionExecutorError> {
+        let tx_inputs = if context.unordered_notes
+            || context.block_timestamp.is_some()
+            || !context.internal_notes.is_empty()
+        {
+            let (account, account_seed, block_header, block_chain, input_notes) =
+                tx_inputs.into_parts();
+            let block_header = match context.block_timestamp {
+                Some(timestamp) => override_block_timestamp(&block_header, timestamp),
+                None => block_header,
+            };
+            let input_notes = if context.unordered_notes {
+                optimize_note_order(input_notes)
+            } else {
+                input_notes
+            };
+            let input_notes = append_internal_notes(input_notes, &context.internal_notes)?;
+            TransactionInputs::new(account, account_seed, block_header, block_chain, input_notes)
+                .expect("transaction inputs were already validated")
+        } else {
+            tx_inputs
+        };
+
+        // internal notes are appended beforehand so that they are subject to the same checks
+        validate_note_timelocks(&tx_inputs)?;
+        if let Some(is_spent) = &context.nullifier_checker {
+            validate_nullifiers(&tx_inputs, is_spent.as_ref())?;
+        }
+        if let Some(whitelist) = &context.note_script_whitelist {
+            validate_note_scripts(&tx_inputs, whitelist)?;
+        }
+        if let Some(policy) = &context.note_asset_policy {
+            validate_note_assets(&tx_inputs, policy.as_ref())?;
+        }
+        validate_note_consumption_proofs(&tx_inputs, &tx_args)?;
+        if let Some(expected) = context.expected_tx_script_root {
+            validate_tx_script_root(&tx_args, expected)?;
+        }
+        let tx_inputs = context.auth_policy.apply(tx_inputs);
+        if let Some((transform, tx_script)) =
+            context.script_transform.as_ref().zip(tx_args.tx_script().cloned())
+        {
+            tx_args.set_tx_script(transform(tx_script));
+        }
 
         let (stack_inputs, advice_inputs) =
             TransactionKernel::prepare_inputs(&tx_inputs, &tx_args, None);
@@ -157,16 +1276,57 @@ impl TransactionExecutor {
             self.authenticator.clone(),
             self.account_codes.iter().map(|code| code.commitment()).collect(),
         )
-        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
+        .map_err(TransactionExecutorError::TransactionHostCreationFailed)?
+        .with_debug_sink(context.debug_sink.clone())
+        .with_event_hook(context.event_hook.clone())
+        .with_note_state_capture(context.capture_note_states)
+        .with_note_cycle_limit(context.per_note_cycle_limit)
+        .with_internal_notes(context.internal_notes.iter().map(Note::id).collect());
 
         // execute the transaction kernel
         let result = vm_processor::execute(
             &TransactionKernel::main(),
             stack_inputs,
-            &mut host,
-            self.exec_options,
+            ObservingHost::new(
+                &mut host,
+                context.snapshot_clk,
+                observer.map(|observer| observer as Observer),
+            ),
+            context.exec_options,
         )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+        .map_err(|err| match err {
+            ExecutionError::EventError(_) if host.exceeded_note_cycle_limit().is_some() => {
+                let note_id = host.exceeded_note_cycle_limit().expect("note cycle limit exceeded");
+                TransactionExecutorError::NoteCycleLimitExceeded(note_id)
+            },
+            ExecutionError::EventError(_) if host.uncreated_internal_note().is_some() => {
+                let note_id = host.uncreated_internal_note().expect("internal note not created");
+                TransactionExecutorError::UncreatedInternalNote(note_id)
+            },
+            // without an authenticator, the signature could only have been requested from the
+            // advice provider
+            ExecutionError::FailedSignatureGeneration(_) if self.authenticator.is_none() => {
+                TransactionExecutorError::MissingAuthAdvice {
+                    account_id: tx_inputs.account().id(),
+                    auth_procedure: AuthScheme::from_account(tx_inputs.account())
+                        .map(|auth_scheme| auth_scheme.procedure_root()),
+                }
+            },
+            ExecutionError::FailedAssertion { err_code, .. }
+                if err_code == ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT =>
+            {
+                TransactionExecutorError::FaucetSupplyOverflow(tx_inputs.account().id())
+            },
+            ExecutionError::AdviceMapKeyNotFound(key) => {
+                match find_missing_consumption_proof(&tx_inputs, &tx_args, key) {
+                    Some(note_id) => TransactionExecutorError::MissingNoteConsumptionProof(note_id),
+                    None => TransactionExecutorError::ExecuteTransactionProgramFailed(
+                        ExecutionError::AdviceMapKeyNotFound(key),
+                    ),
+                }
+            },
+            err => TransactionExecutorError::ExecuteTransactionProgramFailed(err),
+        })?;
 
         // Attempt to retrieve used account codes based on the advice map
         let account_codes = self
@@ -180,19 +1340,278 @@ impl TransactionExecutor {
             })
             .collect();
 
-        build_executed_transaction(
+        let advice_recorder = context.retain_recorder.then(|| host.advice_provider().clone());
+
+        let note_states = host
+            .take_note_states()
+            .into_iter()
+            .map(|(note_id, account_delta)| {
+                let account = account_delta
+                    .apply_to(tx_inputs.account())
+                    .map_err(TransactionExecutorError::InvalidIntermediateAccountState)?;
+                Ok((note_id, account.hash()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let stack_outputs = result.stack_outputs().clone();
+        let executed_transaction = build_executed_transaction(
             tx_args,
             tx_inputs,
-            result.stack_outputs().clone(),
+            stack_outputs.clone(),
             host,
             account_codes,
-        )
+        )?;
+
+        // a new account is established only once its nonce is incremented; the kernel does not
+        // require this for transactions which leave the account unchanged
+        if executed_transaction.initial_account().is_new()
+            && executed_transaction.final_account().nonce() == ZERO
+        {
+            return Err(TransactionExecutorError::NewAccountNonceNotIncremented(
+                executed_transaction.account_id(),
+            ));
+        }
+
+        let num_output_notes = executed_transaction.output_notes().num_notes();
+        if num_output_notes > context.max_output_notes {
+            return Err(TransactionExecutorError::TooManyOutputNotes {
+                max: context.max_output_notes,
+                actual: num_output_notes,
+            });
+        }
+
+        if context.validate_created_notes {
+            validate_created_notes(executed_transaction.output_notes())?;
+        }
+
+        Ok(ExecutionResult {
+            executed_transaction,
+            advice_recorder,
+            note_states,
+            stack_outputs,
+        })
     }
 }
 
+// EXECUTION RESULT
+// ================================================================================================
+
+/// The outcome of a transaction execution, together with the optional outputs requested via the
+/// [ExecutionContext].
+struct ExecutionResult {
+    /// The executed transaction.
+    executed_transaction: ExecutedTransaction,
+    /// A copy of the advice recorder in its post-execution state, if requested by the context.
+    advice_recorder: Option<RecAdviceProvider>,
+    /// The hashes of the account states after each input note was executed, if requested by the
+    /// context.
+    note_states: Vec<(NoteId, Digest)>,
+    /// The raw outputs of the transaction program.
+    stack_outputs: StackOutputs,
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Checks that the transaction script of the transaction, if any, has the expected root.
+fn validate_tx_script_root(
+    tx_args: &TransactionArgs,
+    expected: Digest,
+) -> Result<(), TransactionExecutorError> {
+    match tx_args.tx_script() {
+        Some(tx_script) if tx_script.hash() != expected => {
+            Err(TransactionExecutorError::ScriptRootMismatch { expected, actual: tx_script.hash() })
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Checks that none of the input notes of the transaction is timelocked past the reference block.
+///
+/// A note is timelocked if its execution hint is [NoteExecutionHint::AfterBlock], in which case it
+/// can be consumed only by transactions executed against the specified block or a later one. The
+/// check relies only on the note metadata, and thus cannot be bypassed by the note script.
+fn validate_note_timelocks(tx_inputs: &TransactionInputs) -> Result<(), TransactionExecutorError> {
+    let block_ref = tx_inputs.block_header().block_num().as_u32();
+    for input_note in tx_inputs.input_notes() {
+        if let NoteExecutionHint::AfterBlock { block_num } =
+            input_note.note().metadata().execution_hint()
+        {
+            if block_ref < block_num {
+                return Err(TransactionExecutorError::NoteNotYetSpendable {
+                    note_id: input_note.id(),
+                    earliest_block: block_num,
+                    block_ref,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends the provided notes, which are created by the transaction itself, to the input notes of
+/// the transaction as unauthenticated notes.
+fn append_internal_notes(
+    input_notes: InputNotes<InputNote>,
+    internal_notes: &[Note],
+) -> Result<InputNotes<InputNote>, TransactionExecutorError> {
+    if internal_notes.is_empty() {
+        return Ok(input_notes);
+    }
+
+    let mut notes = input_notes.into_vec();
+    notes.extend(internal_notes.iter().cloned().map(InputNote::unauthenticated));
+    InputNotes::new(notes).map_err(TransactionExecutorError::InvalidInternalNotes)
+}
+
+/// Returns a copy of the provided block header with the timestamp set to the provided value.
+fn override_block_timestamp(block_header: &BlockHeader, timestamp: u32) -> BlockHeader {
+    BlockHeader::new(
+        block_header.version(),
+        block_header.prev_hash(),
+        block_header.block_num().as_u32(),
+        block_header.chain_root(),
+        block_header.account_root(),
+        block_header.nullifier_root(),
+        block_header.note_root(),
+        block_header.tx_hash(),
+        block_header.kernel_root(),
+        block_header.proof_hash(),
+        timestamp,
+    )
+}
+
+/// Checks that the provided list of note IDs does not contain duplicates.
+///
+/// Since a note ID commits to the note's recipient and assets, two notes with the same nullifier
+/// always have the same ID. Thus, this rejects any attempt to consume the same note twice before
+/// the notes are fetched from the data store. [InputNotes](miden_objects::transaction::InputNotes)
+/// enforce the same invariant on nullifiers for transactions executed from prepared inputs.
+fn validate_note_ids(notes: &[NoteId]) -> Result<(), TransactionExecutorError> {
+    let mut seen_notes = BTreeSet::new();
+    for note_id in notes {
+        if !seen_notes.insert(note_id) {
+            return Err(TransactionExecutorError::DuplicateInputNote(*note_id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that all notes created by the transaction are well-formed.
+///
+/// A created note is well-formed if its sender is a valid account ID, its tag is consistent with
+/// its note type, and its recipient (when known) is not the zero digest.
+fn validate_created_notes(output_notes: &OutputNotes) -> Result<(), TransactionExecutorError> {
+    for (index, note) in output_notes.iter().enumerate() {
+        let metadata = note.metadata();
+        AccountId::try_from(Felt::from(metadata.sender()))
+            .map_err(NoteError::InvalidNoteSender)
+            .and_then(|_| metadata.tag().validate(metadata.note_type()))
+            .and_then(|_| match note.recipient_digest() {
+                Some(recipient) if recipient == Digest::default() => {
+                    Err(NoteError::EmptyNoteRecipient)
+                },
+                _ => Ok(()),
+            })
+            .map_err(|error| TransactionExecutorError::MalformedCreatedNote { index, error })?;
+    }
+
+    Ok(())
+}
+
+/// Checks that none of the input notes of the transaction has already been consumed.
+///
+/// The nullifiers are computed from the note data in the same way as in the transaction kernel.
+fn validate_nullifiers(
+    tx_inputs: &TransactionInputs,
+    is_spent: &(dyn Fn(&Nullifier) -> bool + Send + Sync),
+) -> Result<(), TransactionExecutorError> {
+    for input_note in tx_inputs.input_notes() {
+        let nullifier = input_note.note().nullifier();
+        if is_spent(&nullifier) {
+            return Err(TransactionExecutorError::NoteAlreadySpent {
+                note_id: input_note.id(),
+                nullifier,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the scripts of all input notes of the transaction are in the provided whitelist.
+fn validate_note_scripts(
+    tx_inputs: &TransactionInputs,
+    whitelist: &ScriptWhitelist,
+) -> Result<(), TransactionExecutorError> {
+    for input_note in tx_inputs.input_notes() {
+        let script_root = input_note.note().script().hash();
+        if !whitelist.is_allowed(&script_root) {
+            return Err(TransactionExecutorError::NoteScriptNotWhitelisted {
+                note_id: Some(input_note.id()),
+                script_root,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that all assets of the input notes of the transaction are compatible with the account
+/// according to the provided policy.
+fn validate_note_assets(
+    tx_inputs: &TransactionInputs,
+    policy: &dyn NoteAssetPolicy,
+) -> Result<(), TransactionExecutorError> {
+    for input_note in tx_inputs.input_notes() {
+        for asset in input_note.note().assets().iter() {
+            if !policy.is_compatible(tx_inputs.account(), asset) {
+                return Err(TransactionExecutorError::IncompatibleNoteAssets {
+                    note_id: input_note.id(),
+                    asset: *asset,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that all note consumption proofs in the provided arguments are provided for input notes
+/// of the transaction.
+fn validate_note_consumption_proofs(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+) -> Result<(), TransactionExecutorError> {
+    for (note_id, _) in tx_args.note_consumption_proofs() {
+        if !tx_inputs.input_notes().iter().any(|input_note| input_note.id() == *note_id) {
+            return Err(TransactionExecutorError::UnknownNoteConsumptionProof(*note_id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the ID of the input note without a consumption proof whose consumption proof would be
+/// stored under the specified advice map key, if any.
+fn find_missing_consumption_proof(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    key: Word,
+) -> Option<NoteId> {
+    tx_inputs
+        .input_notes()
+        .iter()
+        .map(|input_note| input_note.note())
+        .find(|note| {
+            tx_args.get_note_consumption_proof(note.id()).is_none()
+                && Word::from(NoteConsumptionProof::advice_key(note.serial_num())) == key
+        })
+        .map(|note| note.id())
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction(
     tx_args: TransactionArgs,
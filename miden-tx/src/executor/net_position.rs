@@ -0,0 +1,83 @@
+use miden_objects::{accounts::AccountId, assets::AssetVault, notes::Note, AssetVaultError};
+
+// NET POSITION
+// ================================================================================================
+
+/// The assets held by an account together with the assets of the notes it may consume, as
+/// returned by [TransactionExecutor::net_position()](super::TransactionExecutor::net_position).
+///
+/// The position is split into the settled portion (the assets in the vault of the account) and the
+/// pending portion (the assets carried by the notes discovered for the account). Unlike a
+/// [BalanceProjection](super::BalanceProjection), the pending portion contains all assets of the
+/// notes, regardless of the effects of the note scripts on the vault of the consuming account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetPosition {
+    settled: AssetVault,
+    pending: AssetVault,
+    total: AssetVault,
+}
+
+impl NetPosition {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the net position of an account with the provided vault which may consume the
+    /// provided notes.
+    ///
+    /// # Errors
+    /// Returns an error if the assets cannot be added together, e.g., if the sum of the fungible
+    /// assets issued by a faucet exceeds the maximum fungible asset amount.
+    pub(super) fn new<'a>(
+        settled: AssetVault,
+        notes: impl IntoIterator<Item = &'a Note>,
+    ) -> Result<Self, AssetVaultError> {
+        let mut pending = AssetVault::default();
+        for note in notes {
+            for asset in note.assets().iter() {
+                pending.add_asset(*asset)?;
+            }
+        }
+
+        let mut total = settled.clone();
+        for asset in pending.assets() {
+            total.add_asset(asset)?;
+        }
+
+        Ok(Self { settled, pending, total })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the assets in the vault of the account.
+    pub fn settled(&self) -> &AssetVault {
+        &self.settled
+    }
+
+    /// Returns the assets carried by the notes the account may consume.
+    pub fn pending(&self) -> &AssetVault {
+        &self.pending
+    }
+
+    /// Returns the sum of the settled and the pending assets.
+    pub fn total(&self) -> &AssetVault {
+        &self.total
+    }
+
+    /// Returns the settled and the pending balance of the fungible asset issued by the specified
+    /// faucet.
+    ///
+    /// # Errors
+    /// Returns an error if the specified ID is not an ID of a fungible asset faucet.
+    pub fn balance(&self, faucet_id: AccountId) -> Result<(u64, u64), AssetVaultError> {
+        Ok((self.settled.get_balance(faucet_id)?, self.pending.get_balance(faucet_id)?))
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes self and returns the sum of the settled and the pending assets.
+    pub fn into_total(self) -> AssetVault {
+        self.total
+    }
+}
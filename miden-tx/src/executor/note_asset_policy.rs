@@ -0,0 +1,35 @@
+use miden_objects::{accounts::Account, assets::Asset};
+
+// NOTE ASSET POLICY
+// ================================================================================================
+
+/// Defines which assets the notes consumed by an account may carry.
+///
+/// The policy is applied before a transaction is executed, and so it can be used to reject notes
+/// whose assets the consuming account cannot hold without executing the transaction. Since note
+/// scripts determine what happens to the assets of a note, the policy is applied to all assets of
+/// all input notes, regardless of whether they are actually deposited into the account.
+///
+/// Different rules can be applied to different accounts by inspecting the type of the provided
+/// account.
+pub trait NoteAssetPolicy: Send + Sync {
+    /// Returns true if a note carrying the provided asset may be consumed by the provided account.
+    fn is_compatible(&self, account: &Account, asset: &Asset) -> bool;
+}
+
+// ACCOUNT TYPE ASSET POLICY
+// ================================================================================================
+
+/// A [NoteAssetPolicy] enforcing the default rules for the standard account types.
+///
+/// Regular accounts may consume notes carrying any assets, while faucet accounts may consume only
+/// notes carrying assets issued by the faucet itself (e.g., notes burning the assets of the
+/// faucet).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccountTypeAssetPolicy;
+
+impl NoteAssetPolicy for AccountTypeAssetPolicy {
+    fn is_compatible(&self, account: &Account, asset: &Asset) -> bool {
+        !account.is_faucet() || asset.faucet_id() == account.id()
+    }
+}
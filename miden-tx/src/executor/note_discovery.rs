@@ -0,0 +1,119 @@
+#[cfg(feature = "async")]
+use alloc::boxed::Box;
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    block::BlockNumber,
+    notes::{NoteId, NoteTag},
+};
+use winter_maybe_async::*;
+
+use super::DataStore;
+use crate::DataStoreError;
+
+// NOTE DISCOVERY TRAIT
+// ================================================================================================
+
+/// Defines how the notes which may be consumed by an account are discovered.
+///
+/// Clients discover consumable notes in different ways, e.g., by subscribing to a set of note tags
+/// or by scanning all notes known to them. A [NoteDiscovery] strategy decouples this policy from
+/// the [DataStore] the notes are fetched from, and is used by
+/// [TransactionExecutor::available_notes()](super::TransactionExecutor::available_notes) and
+/// [TransactionExecutor::consume_all()](super::TransactionExecutor::consume_all).
+#[maybe_async_trait]
+pub trait NoteDiscovery {
+    /// Returns the IDs of the notes recorded in the chain by the block with the specified number
+    /// which may be consumed by the account with the specified ID.
+    ///
+    /// The notes are fetched from the provided data store. The returned notes are not guaranteed
+    /// to be consumable; the order of the IDs is the order in which the notes should be consumed.
+    ///
+    /// # Errors
+    /// Returns an error if the notes or the account could not be fetched from the data store.
+    #[maybe_async]
+    fn discover(
+        &self,
+        data_store: &dyn DataStore,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<Vec<NoteId>, DataStoreError>;
+}
+
+// TAG-BASED DISCOVERY
+// ================================================================================================
+
+/// A [NoteDiscovery] strategy which discovers the notes carrying any of a set of subscribed
+/// [NoteTag]s.
+///
+/// The notes are fetched via [DataStore::get_notes_by_tags()], and thus data stores indexing notes
+/// by tag can serve this strategy without scanning all notes. The account consuming the notes is
+/// not taken into account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagBasedDiscovery {
+    tags: BTreeSet<NoteTag>,
+}
+
+impl TagBasedDiscovery {
+    /// Returns a new [TagBasedDiscovery] subscribed to the provided tags.
+    pub fn new(tags: impl IntoIterator<Item = NoteTag>) -> Self {
+        Self { tags: tags.into_iter().collect() }
+    }
+
+    /// Returns the tags this strategy is subscribed to.
+    pub fn tags(&self) -> &BTreeSet<NoteTag> {
+        &self.tags
+    }
+}
+
+#[maybe_async_trait]
+impl NoteDiscovery for TagBasedDiscovery {
+    #[maybe_async]
+    fn discover(
+        &self,
+        data_store: &dyn DataStore,
+        _account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<Vec<NoteId>, DataStoreError> {
+        let tags = self.tags.iter().copied().collect::<Vec<_>>();
+        let notes = maybe_await!(data_store.get_notes_by_tags(block_ref, &tags))?;
+        Ok(notes.iter().map(|note| note.id()).collect())
+    }
+}
+
+// FULL SCAN DISCOVERY
+// ================================================================================================
+
+/// A [NoteDiscovery] strategy which scans all unconsumed notes and discovers the ones likely to be
+/// consumable by the account.
+///
+/// The notes are fetched via [DataStore::get_unconsumed_notes()], and the latest state of the
+/// account via [DataStore::get_transaction_inputs()]. A note is discovered if
+/// [Note::is_likely_consumable_by()](miden_objects::notes::Note::is_likely_consumable_by)
+/// returns true for the account, i.e., if it does not target another account and does not call
+/// procedures which are not exposed by the account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FullScanDiscovery;
+
+#[maybe_async_trait]
+impl NoteDiscovery for FullScanDiscovery {
+    #[maybe_async]
+    fn discover(
+        &self,
+        data_store: &dyn DataStore,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+    ) -> Result<Vec<NoteId>, DataStoreError> {
+        let tx_inputs =
+            maybe_await!(data_store.get_transaction_inputs(account_id, block_ref, &[]))?;
+        let account_code = tx_inputs.account().code();
+
+        let notes = maybe_await!(data_store.get_unconsumed_notes(block_ref))?;
+        Ok(notes
+            .iter()
+            .filter(|note| note.note().is_likely_consumable_by(account_id, account_code))
+            .map(|note| note.id())
+            .collect())
+    }
+}
@@ -0,0 +1,36 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    transaction::{InputNote, InputNotes},
+    Digest,
+};
+
+// NOTE ORDER
+// ================================================================================================
+
+/// Reorders the provided input notes such that notes sharing a script are consumed one after
+/// another.
+///
+/// Groups of notes are ordered by the first occurrence of their script in the provided notes, and
+/// notes within a group keep their relative order. Thus, the order is left unchanged if notes
+/// sharing a script are already adjacent, and applying this function repeatedly yields the same
+/// order.
+///
+/// The order of input notes is reflected in the commitment to the input notes and may affect the
+/// order of the notes created by the transaction, as well as the result of note scripts which
+/// depend on the state left behind by previously consumed notes. This function must therefore
+/// only be applied to notes whose consumption is known to be order-independent (see
+/// [TransactionExecutor::with_unordered_notes()](super::TransactionExecutor::with_unordered_notes)).
+pub fn optimize_note_order(input_notes: InputNotes<InputNote>) -> InputNotes<InputNote> {
+    let mut groups: Vec<(Digest, Vec<InputNote>)> = Vec::new();
+    for input_note in input_notes.into_vec() {
+        let script_root = input_note.note().script().hash();
+        match groups.iter_mut().find(|(root, _)| *root == script_root) {
+            Some((_, notes)) => notes.push(input_note),
+            None => groups.push((script_root, vec![input_note])),
+        }
+    }
+
+    let input_notes = groups.into_iter().flat_map(|(_, notes)| notes).collect::<Vec<_>>();
+    InputNotes::new(input_notes).expect("input notes were already validated")
+}
@@ -0,0 +1,83 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use miden_lib::utils::sync::RwLock;
+use miden_objects::{vm::Program, Digest};
+
+// SCRIPT CACHE STATS
+// ================================================================================================
+
+/// Statistics of the transaction script cache of a
+/// [TransactionExecutor](super::TransactionExecutor).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptCacheStats {
+    hits: u64,
+    misses: u64,
+}
+
+impl ScriptCacheStats {
+    /// Returns the number of compilations which were served from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of compilations which were not served from the cache, i.e., for which
+    /// the script was compiled.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+}
+
+// SCRIPT CACHE KEY
+// ================================================================================================
+
+/// Identifies a compiled transaction script.
+///
+/// The MAST of a compiled script depends not only on its source code, but also on the libraries
+/// it is linked against and on whether it is compiled in debug mode.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct ScriptCacheKey {
+    pub source_hash: Digest,
+    pub stdlib_version: Digest,
+    pub library_digests: Vec<Digest>,
+    pub in_debug_mode: bool,
+}
+
+// TRANSACTION SCRIPT CACHE
+// ================================================================================================
+
+/// A cache of the programs of compiled transaction scripts.
+///
+/// Only the programs are cached since the inputs of transaction scripts do not affect their
+/// compilation.
+#[derive(Default)]
+pub(super) struct TransactionScriptCache {
+    programs: RwLock<BTreeMap<ScriptCacheKey, Program>>,
+    stats: RwLock<ScriptCacheStats>,
+}
+
+impl TransactionScriptCache {
+    /// Returns the program compiled for the specified key, compiling and caching it via `compile`
+    /// if it is not in the cache.
+    ///
+    /// Scripts which fail to compile are not cached.
+    pub fn get_or_compile<E>(
+        &self,
+        key: ScriptCacheKey,
+        compile: impl FnOnce() -> Result<Program, E>,
+    ) -> Result<Program, E> {
+        if let Some(program) = self.programs.read().get(&key) {
+            self.stats.write().hits += 1;
+            return Ok(program.clone());
+        }
+
+        self.stats.write().misses += 1;
+        let program = compile()?;
+        self.programs.write().insert(key, program.clone());
+        Ok(program)
+    }
+
+    /// Returns the statistics of this cache.
+    pub fn stats(&self) -> ScriptCacheStats {
+        *self.stats.read()
+    }
+}
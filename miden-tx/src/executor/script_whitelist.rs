@@ -0,0 +1,64 @@
+use alloc::collections::BTreeSet;
+
+use miden_objects::Digest;
+
+// SCRIPT WHITELIST
+// ================================================================================================
+
+/// A set of note script roots which a [TransactionExecutor](super::TransactionExecutor) is allowed
+/// to consume.
+///
+/// When a whitelist is attached to the executor, every input note of a transaction is checked
+/// against it before the transaction is executed, and transactions consuming notes with scripts
+/// outside of the whitelist are rejected. Note scripts compiled via
+/// [TransactionExecutor::compile_note_script()](super::TransactionExecutor::compile_note_script)
+/// are checked against the whitelist as well. Since the check compares only the MAST roots of note
+/// scripts, it is cheap to perform.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptWhitelist {
+    roots: BTreeSet<Digest>,
+}
+
+impl ScriptWhitelist {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ScriptWhitelist] containing the provided note script roots.
+    pub fn new(roots: impl IntoIterator<Item = Digest>) -> Self {
+        Self { roots: roots.into_iter().collect() }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if a note script with the specified MAST root is allowed to be consumed.
+    pub fn is_allowed(&self, root: &Digest) -> bool {
+        self.roots.contains(root)
+    }
+
+    /// Returns the number of script roots in this whitelist.
+    pub fn num_roots(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns an iterator over the script roots in this whitelist.
+    pub fn iter(&self) -> impl Iterator<Item = &Digest> {
+        self.roots.iter()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the specified script root to this whitelist.
+    ///
+    /// Returns true if the root was not already in the whitelist.
+    pub fn insert(&mut self, root: Digest) -> bool {
+        self.roots.insert(root)
+    }
+}
+
+impl FromIterator<Digest> for ScriptWhitelist {
+    fn from_iter<T: IntoIterator<Item = Digest>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
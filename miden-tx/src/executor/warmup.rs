@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use miden_objects::{accounts::AccountId, Digest};
+
+use crate::TransactionExecutorError;
+
+// CODE WARMUP
+// ================================================================================================
+
+/// The result of loading the code of a single account into a
+/// [TransactionExecutor](super::TransactionExecutor) via
+/// [TransactionExecutor::warmup()](super::TransactionExecutor::warmup).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeWarmup {
+    account_id: AccountId,
+    code_commitment: Digest,
+    duration: Duration,
+    already_loaded: bool,
+    result: Result<(), TransactionExecutorError>,
+}
+
+impl CodeWarmup {
+    pub(super) fn new(
+        account_id: AccountId,
+        code_commitment: Digest,
+        duration: Duration,
+        already_loaded: bool,
+        result: Result<(), TransactionExecutorError>,
+    ) -> Self {
+        Self {
+            account_id,
+            code_commitment,
+            duration,
+            already_loaded,
+            result,
+        }
+    }
+
+    /// Returns the ID of the account whose code was loaded.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the commitment to the loaded code.
+    pub fn code_commitment(&self) -> Digest {
+        self.code_commitment
+    }
+
+    /// Returns the time taken to load and check the code.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns true if the code had already been loaded into the executor, in which case it was
+    /// not loaded again.
+    pub fn already_loaded(&self) -> bool {
+        self.already_loaded
+    }
+
+    /// Returns the result of checking the code against the executor.
+    ///
+    /// The code is loaded even if the check fails, since the check is advisory (see
+    /// [TransactionExecutor::check_stdlib_compatibility()](super::TransactionExecutor::check_stdlib_compatibility)).
+    pub fn result(&self) -> &Result<(), TransactionExecutorError> {
+        &self.result
+    }
+}
+
+// WARMUP REPORT
+// ================================================================================================
+
+/// The report of loading the code of multiple accounts into a
+/// [TransactionExecutor](super::TransactionExecutor) via
+/// [TransactionExecutor::warmup()](super::TransactionExecutor::warmup).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarmupReport {
+    codes: Vec<CodeWarmup>,
+    duration: Duration,
+}
+
+impl WarmupReport {
+    pub(super) fn new(codes: Vec<CodeWarmup>, duration: Duration) -> Self {
+        Self { codes, duration }
+    }
+
+    /// Returns the results for the individual account codes, in the order in which they were
+    /// provided.
+    pub fn codes(&self) -> &[CodeWarmup] {
+        &self.codes
+    }
+
+    /// Returns the total time taken by the warmup.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns an iterator over the results for the account codes whose check failed.
+    pub fn failures(&self) -> impl Iterator<Item = &CodeWarmup> {
+        self.codes.iter().filter(|code| code.result.is_err())
+    }
+}
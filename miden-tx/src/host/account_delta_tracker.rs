@@ -1,6 +1,6 @@
 use miden_objects::{
-    accounts::{AccountDelta, AccountHeader, AccountStorageDelta, AccountVaultDelta},
-    Felt, ZERO,
+    accounts::{Account, AccountDelta, AccountHeader, AccountStorageDelta, AccountVaultDelta},
+    AccountError, Felt, ZERO,
 };
 // ACCOUNT DELTA TRACKER
 // ================================================================================================
@@ -41,6 +41,25 @@ impl AccountDeltaTracker {
         AccountDelta::new(self.storage, self.vault, nonce_delta).expect("invalid account delta")
     }
 
+    /// Returns the state of the provided account with the changes tracked so far applied to it.
+    ///
+    /// Unlike building an [AccountDelta], this does not require the nonce to be incremented, and
+    /// thus can be used to obtain intermediate account states during transaction execution.
+    pub fn apply_to(&self, account: &Account) -> Result<Account, AccountError> {
+        let mut vault = account.vault().clone();
+        vault.apply_delta(&self.vault).map_err(AccountError::AssetVaultUpdateError)?;
+        let mut storage = account.storage().clone();
+        storage.apply_delta(&self.storage)?;
+
+        Ok(Account::from_parts(
+            account.id(),
+            vault,
+            storage,
+            account.code().clone(),
+            self.init_nonce + self.nonce_delta,
+        ))
+    }
+
     /// Tracks nonce delta.
     pub fn increment_nonce(&mut self, value: Felt) {
         self.nonce_delta += value;
@@ -0,0 +1,113 @@
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+use miden_objects::vm::{DebugOptions, RowIndex};
+use vm_processor::{ContextId, Felt, ProcessState, Word};
+
+// DEBUG SINK
+// ================================================================================================
+
+/// A function receiving the output of `debug` and `trace` instructions executed during a
+/// transaction.
+pub type DebugSink = Arc<dyn Fn(DebugRecord) + Send + Sync>;
+
+/// A single record emitted by a `debug` or `trace` instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugRecord {
+    clk: RowIndex,
+    ctx: ContextId,
+    message: String,
+}
+
+impl DebugRecord {
+    /// Returns a new [DebugRecord] for the specified message emitted by the provided process.
+    pub(super) fn new<S: ProcessState>(process: &S, message: String) -> Self {
+        Self {
+            clk: process.clk(),
+            ctx: process.ctx(),
+            message,
+        }
+    }
+
+    /// Returns a new [DebugRecord] describing the part of the VM state specified by the provided
+    /// debug options.
+    pub(super) fn from_debug_options<S: ProcessState>(process: &S, options: &DebugOptions) -> Self {
+        let message = match options {
+            DebugOptions::StackAll => format_stack(process, None),
+            DebugOptions::StackTop(n) => format_stack(process, Some(*n as usize)),
+            DebugOptions::MemAll => {
+                let mem = process.get_mem_state(process.ctx());
+                format_mem(mem.into_iter().map(|(addr, word)| (addr, Some(word))))
+            },
+            DebugOptions::MemInterval(start, end) => format_mem(
+                (*start..=*end)
+                    .map(|addr| (addr as u64, process.get_mem_value(process.ctx(), addr))),
+            ),
+            DebugOptions::LocalInterval(start, end, num_locals) => {
+                let (start, end, num_locals) = (*start as u32, *end as u32, *num_locals as u32);
+                // locals are stored right below the frame pointer of the current procedure
+                let locals_offset = (process.fmp() as u32 + 1).saturating_sub(num_locals);
+                let (start, end) = if start == 0 && end == u16::MAX as u32 {
+                    (0, num_locals.saturating_sub(1))
+                } else {
+                    (start, end)
+                };
+                let locals = (start..=end).map(|index| {
+                    (index as u64, process.get_mem_value(process.ctx(), index + locals_offset))
+                });
+                format!("locals: {}", format_mem(locals))
+            },
+        };
+
+        Self::new(process, message)
+    }
+
+    /// Returns the clock cycle at which the record was emitted.
+    pub fn clk(&self) -> RowIndex {
+        self.clk
+    }
+
+    /// Returns the execution context in which the record was emitted.
+    pub fn ctx(&self) -> ContextId {
+        self.ctx
+    }
+
+    /// Returns the message of this record.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Formats the top `n` items of the stack, or the whole stack if `n` is not provided.
+fn format_stack<S: ProcessState>(process: &S, n: Option<usize>) -> String {
+    let stack = process.get_stack_state();
+    let num_items = n.unwrap_or(stack.len()).min(stack.len());
+
+    let mut message = format!("stack: {}", format_elements(&stack[..num_items]));
+    if num_items < stack.len() {
+        message.push_str(&format!(" ({} more items)", stack.len() - num_items));
+    }
+    message
+}
+
+/// Formats the provided memory words as a list of `address: word` pairs.
+fn format_mem(mem: impl Iterator<Item = (u64, Option<Word>)>) -> String {
+    let words = mem
+        .map(|(addr, word)| match word {
+            Some(word) => format!("{addr}: {}", format_elements(&word)),
+            None => format!("{addr}: EMPTY"),
+        })
+        .collect::<Vec<_>>();
+    format!("[{}]", words.join(", "))
+}
+
+fn format_elements(elements: &[Felt]) -> String {
+    let elements = elements.iter().map(|element| element.to_string()).collect::<Vec<_>>();
+    format!("[{}]", elements.join(", "))
+}
@@ -6,28 +6,35 @@ use alloc::{
 };
 
 use miden_lib::transaction::{
-    memory::{CURRENT_INPUT_NOTE_PTR, NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR},
+    memory::{
+        CURRENT_ACCOUNT_DATA_PTR, CURRENT_INPUT_NOTE_PTR, NATIVE_ACCOUNT_DATA_PTR,
+        NATIVE_NUM_ACCT_STORAGE_SLOTS_PTR,
+    },
     TransactionEvent, TransactionKernelError, TransactionTrace,
 };
 use miden_objects::{
     accounts::{AccountDelta, AccountHeader},
     assets::Asset,
     notes::NoteId,
-    transaction::{OutputNote, TransactionMeasurements},
-    vm::RowIndex,
+    transaction::{OutputNote, StorageAccessSet, TransactionMeasurements},
+    vm::{DebugOptions, RowIndex},
     Digest, Hasher,
 };
 use vm_processor::{
-    AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId, ExecutionError, Felt,
-    Host, HostResponse, MastForest, MastForestStore, ProcessState,
+    AdviceExtractor, AdviceInjector, AdviceProvider, AdviceSource, ContextId, DefaultHost,
+    ExecutionError, Felt, Host, HostResponse, MastForest, MastForestStore, MemAdviceProvider,
+    ProcessState,
 };
 
 mod account_delta_tracker;
-use account_delta_tracker::AccountDeltaTracker;
+pub(crate) use account_delta_tracker::AccountDeltaTracker;
 
 mod account_procs;
 pub use account_procs::AccountProcedureIndexMap;
 
+mod debug_sink;
+pub use debug_sink::{DebugRecord, DebugSink};
+
 mod note_builder;
 use note_builder::OutputNoteBuilder;
 
@@ -42,6 +49,9 @@ use crate::{
 // TRANSACTION HOST
 // ================================================================================================
 
+/// A function invoked with every event emitted by the transaction kernel.
+pub type EventHook = Arc<dyn Fn(&TransactionEvent) + Send + Sync>;
+
 /// Transaction host is responsible for handling [Host] requests made by a transaction kernel.
 ///
 /// Transaction hosts are created on a per-transaction basis. That is, a transaction host is meant
@@ -88,6 +98,41 @@ pub struct TransactionHost<A> {
     ///
     /// This map is initialized at construction time from the [KERNEL_ERRORS] array.
     error_messages: BTreeMap<u32, &'static str>,
+
+    /// Receives the output of `debug` instructions and of `trace` instructions not used by the
+    /// transaction kernel.
+    ///
+    /// If not set, the output of `debug` instructions is handled as by the default [Host]
+    /// implementation, and `trace` instructions not used by the kernel result in an error.
+    debug_sink: Option<DebugSink>,
+
+    /// Invoked with every event emitted by the transaction kernel before the event is handled.
+    event_hook: Option<EventHook>,
+
+    /// Snapshots of the account state changes taken after each input note was executed.
+    ///
+    /// Snapshots are taken by the [TransactionHost::on_trace()] handler only if this field is set.
+    note_states: Option<Vec<(NoteId, AccountDeltaTracker)>>,
+
+    /// The maximum number of cycles the execution of a single input note may take.
+    ///
+    /// The limit is checked by the [TransactionHost::on_trace()] and [TransactionHost::on_event()]
+    /// handlers, and thus requires tracing to be enabled for the transaction.
+    note_cycle_limit: Option<u32>,
+
+    /// The ID of the input note whose execution exceeded the note cycle limit, if any.
+    exceeded_note_cycle_limit: Option<NoteId>,
+
+    /// The IDs of the input notes which must be created by the transaction before they are
+    /// consumed.
+    ///
+    /// This is checked by the [TransactionHost::on_trace()] handler, and thus requires tracing to
+    /// be enabled for the transaction.
+    internal_notes: BTreeSet<NoteId>,
+
+    /// The ID of the input note which was consumed before being created by the transaction, if
+    /// any.
+    uncreated_internal_note: Option<NoteId>,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
@@ -117,9 +162,78 @@ impl<A: AdviceProvider> TransactionHost<A> {
             tx_progress: TransactionProgress::default(),
             generated_signatures: BTreeMap::new(),
             error_messages: kernel_assertion_errors,
+            debug_sink: None,
+            event_hook: None,
+            note_states: None,
+            note_cycle_limit: None,
+            exceeded_note_cycle_limit: None,
+            internal_notes: BTreeSet::new(),
+            uncreated_internal_note: None,
         })
     }
 
+    /// Sets the [DebugSink] receiving the output of `debug` and `trace` instructions executed by
+    /// the transaction.
+    pub fn with_debug_sink(mut self, debug_sink: Option<DebugSink>) -> Self {
+        self.debug_sink = debug_sink;
+        self
+    }
+
+    /// Sets the [EventHook] invoked with every event emitted by the transaction kernel.
+    pub fn with_event_hook(mut self, event_hook: Option<EventHook>) -> Self {
+        self.event_hook = event_hook;
+        self
+    }
+
+    /// Enables capturing the account state changes made after each input note was executed.
+    ///
+    /// The changes are captured at the end of the execution of each note, and thus require
+    /// tracing to be enabled for the transaction.
+    pub fn with_note_state_capture(mut self, enabled: bool) -> Self {
+        self.note_states = enabled.then(Vec::new);
+        self
+    }
+
+    /// Sets the maximum number of cycles the execution of a single input note may take.
+    ///
+    /// The number of cycles taken by a note is checked whenever the note emits an event and at the
+    /// end of its execution, and thus the limit requires tracing to be enabled for the
+    /// transaction. A note exceeding the limit causes the execution to fail with an
+    /// [ExecutionError::EventError].
+    pub fn with_note_cycle_limit(mut self, note_cycle_limit: Option<u32>) -> Self {
+        self.note_cycle_limit = note_cycle_limit;
+        self
+    }
+
+    /// Returns the ID of the input note whose execution exceeded the note cycle limit, if any.
+    pub(crate) fn exceeded_note_cycle_limit(&self) -> Option<NoteId> {
+        self.exceeded_note_cycle_limit
+    }
+
+    /// Sets the IDs of the input notes which must be created by the transaction before they are
+    /// consumed.
+    pub fn with_internal_notes(mut self, internal_notes: BTreeSet<NoteId>) -> Self {
+        self.internal_notes = internal_notes;
+        self
+    }
+
+    /// Returns the ID of the input note which was consumed before being created by the
+    /// transaction, if any.
+    pub(crate) fn uncreated_internal_note(&self) -> Option<NoteId> {
+        self.uncreated_internal_note
+    }
+
+    /// Returns the account state changes captured after each input note was executed, in the
+    /// order in which the notes were executed.
+    pub(crate) fn take_note_states(&mut self) -> Vec<(NoteId, AccountDeltaTracker)> {
+        self.note_states.as_mut().map(core::mem::take).unwrap_or_default()
+    }
+
+    /// Returns a reference to the advice provider of this host.
+    pub fn advice_provider(&self) -> &A {
+        &self.adv_provider
+    }
+
     /// Consumes `self` and returns the advice provider, account delta, output notes, generated
     /// signatures, and transaction progress.
     pub fn into_parts(
@@ -266,10 +380,11 @@ impl<A: AdviceProvider> TransactionHost<A> {
         ];
 
         // update the delta tracker only if the current and new values are different
+        let slot_index = slot_index.as_int() as u8;
         if current_slot_value != new_slot_value {
-            let slot_index = slot_index.as_int() as u8;
             self.account_delta.storage_delta().set_item(slot_index, new_slot_value);
         }
+        self.tx_progress.record_storage_write(slot_index);
 
         Ok(())
     }
@@ -317,6 +432,42 @@ impl<A: AdviceProvider> TransactionHost<A> {
             new_map_key.into(),
             new_map_value,
         );
+        self.tx_progress.record_storage_write(slot_index);
+
+        Ok(())
+    }
+
+    /// Extracts the index of the storage slot being read from the process state and records the
+    /// read if the slot belongs to the native account.
+    ///
+    /// The event is emitted by the kernel for every read of the storage of the current account,
+    /// including the reads the kernel makes on behalf of the account (e.g., of the total issuance
+    /// of a faucet), just as writes are recorded for every update of the storage. Reads of the
+    /// storage of foreign accounts are not recorded.
+    ///
+    /// Expected stack state: [slot_index, ...]
+    pub fn on_account_storage_get_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        let current_account_ptr = process
+            .get_mem_value(process.ctx(), CURRENT_ACCOUNT_DATA_PTR)
+            .map(|word| word[0].as_int());
+        if current_account_ptr != Some(NATIVE_ACCOUNT_DATA_PTR as u64) {
+            return Ok(());
+        }
+
+        // get slot index from the stack and make sure it is valid
+        let slot_index = process.get_stack_item(0);
+        let num_storage_slot = Self::get_num_storage_slots(process)?;
+        if slot_index.as_int() >= num_storage_slot {
+            return Err(TransactionKernelError::InvalidStorageSlotIndex {
+                max: num_storage_slot,
+                actual: slot_index.as_int(),
+            });
+        }
+
+        self.tx_progress.record_storage_read(slot_index.as_int() as u8);
 
         Ok(())
     }
@@ -440,6 +591,49 @@ impl<A: AdviceProvider> TransactionHost<A> {
         }
     }
 
+    /// Checks that the input note which is currently being executed, if any, has not exceeded the
+    /// note cycle limit at the specified cycle.
+    ///
+    /// # Errors
+    /// Returns an error if the note has taken more cycles than allowed by the note cycle limit.
+    fn check_note_cycle_limit(&mut self, clk: RowIndex) -> Result<(), ExecutionError> {
+        let Some(note_cycle_limit) = self.note_cycle_limit else {
+            return Ok(());
+        };
+        let Some((note_id, interval)) = self.tx_progress.note_execution().last() else {
+            return Ok(());
+        };
+
+        match interval.start() {
+            Some(start) if interval.end().is_none() && clk - start > note_cycle_limit as usize => {
+                self.exceeded_note_cycle_limit = Some(*note_id);
+                Err(ExecutionError::EventError(format!(
+                    "execution of note {note_id} exceeded the limit of {note_cycle_limit} cycles"
+                )))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the specified input note, if it must be created by the transaction, has already
+    /// been created.
+    ///
+    /// # Errors
+    /// Returns an error if the note must be created by the transaction but none of the notes
+    /// created so far has the specified ID.
+    fn check_internal_note_created(&mut self, note_id: NoteId) -> Result<(), ExecutionError> {
+        if self.internal_notes.contains(&note_id)
+            && !self.output_notes.values().any(|note| note.id() == note_id)
+        {
+            self.uncreated_internal_note = Some(note_id);
+            return Err(ExecutionError::EventError(format!(
+                "note {note_id} is consumed before it is created by the transaction"
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Returns the number of storage slots initialized for the current account.
     ///
     /// # Errors
@@ -495,6 +689,12 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
             )));
         }
 
+        if let Some(event_hook) = &self.event_hook {
+            event_hook(&event);
+        }
+
+        self.check_note_cycle_limit(process.clk())?;
+
         match event {
             TransactionEvent::AccountVaultBeforeAddAsset => Ok(()),
             TransactionEvent::AccountVaultAfterAddAsset => {
@@ -530,19 +730,42 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
 
             TransactionEvent::NoteBeforeAddAsset => self.on_note_before_add_asset(process),
             TransactionEvent::NoteAfterAddAsset => Ok(()),
+
+            TransactionEvent::AccountStorageGetItem => self.on_account_storage_get_item(process),
         }
         .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
         Ok(HostResponse::None)
     }
 
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        match &self.debug_sink {
+            Some(debug_sink) => {
+                debug_sink(DebugRecord::from_debug_options(process, options));
+                Ok(HostResponse::None)
+            },
+            // without a sink, the output is handled as by any other host
+            None => DefaultHost::<MemAdviceProvider>::default().on_debug(process, options),
+        }
+    }
+
     fn on_trace<S: ProcessState>(
         &mut self,
         process: &S,
         trace_id: u32,
     ) -> Result<HostResponse, ExecutionError> {
-        let event = TransactionTrace::try_from(trace_id)
-            .map_err(|err| ExecutionError::EventError(err.to_string()))?;
+        let event = match (TransactionTrace::try_from(trace_id), &self.debug_sink) {
+            (Ok(event), _) => event,
+            (Err(_), Some(debug_sink)) => {
+                debug_sink(DebugRecord::new(process, format!("trace.{trace_id}")));
+                return Ok(HostResponse::None);
+            },
+            (Err(err), None) => return Err(ExecutionError::EventError(err.to_string())),
+        };
 
         use TransactionTrace::*;
         match event {
@@ -554,9 +777,21 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
                 let note_id = Self::get_current_note_id(process)?.expect(
                     "Note execution interval measurement is incorrect: check the placement of the start and the end of the interval",
                 );
+                self.check_internal_note_created(note_id)?;
                 self.tx_progress.start_note_execution(process.clk(), note_id);
             },
-            NoteExecutionEnd => self.tx_progress.end_note_execution(process.clk()),
+            NoteExecutionEnd => {
+                self.check_note_cycle_limit(process.clk())?;
+                self.tx_progress.end_note_execution(process.clk());
+                if let Some(note_states) = &mut self.note_states {
+                    let (note_id, _) = self
+                        .tx_progress
+                        .note_execution()
+                        .last()
+                        .expect("note execution must have started");
+                    note_states.push((*note_id, self.account_delta.clone()));
+                }
+            },
             TxScriptProcessingStart => self.tx_progress.start_tx_script_processing(process.clk()),
             TxScriptProcessingEnd => self.tx_progress.end_tx_script_processing(process.clk()),
             EpilogueStart => self.tx_progress.start_epilogue(process.clk()),
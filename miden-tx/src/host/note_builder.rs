@@ -2,7 +2,9 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     assets::Asset,
-    notes::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, PartialNote},
+    notes::{
+        Note, NoteAssets, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, PartialNote,
+    },
 };
 
 use super::{AdviceProvider, Digest, Felt, OutputNote, TransactionKernelError};
@@ -133,6 +135,11 @@ impl OutputNoteBuilder {
         Ok(())
     }
 
+    /// Returns the ID of the note with the assets added so far.
+    pub fn id(&self) -> NoteId {
+        NoteId::new(self.recipient_digest, self.assets.commitment())
+    }
+
     /// Converts this builder to an [OutputNote].
     ///
     /// Depending on the available information, this may result in [OutputNote::Full] or
@@ -1,12 +1,12 @@
 pub use alloc::vec::Vec;
 
-use super::{NoteId, RowIndex, TransactionMeasurements};
+use super::{NoteId, RowIndex, StorageAccessSet, TransactionMeasurements};
 
 // TRANSACTION PROGRESS
 // ================================================================================================
 
 /// Contains the information about the number of cycles for each of the transaction execution
-/// stages.
+/// stages, and about the storage slots of the native account accessed during execution.
 #[derive(Clone, Default, Debug)]
 pub struct TransactionProgress {
     prologue: CycleInterval,
@@ -14,6 +14,7 @@ pub struct TransactionProgress {
     note_execution: Vec<(NoteId, CycleInterval)>,
     tx_script_processing: CycleInterval,
     epilogue: CycleInterval,
+    storage_accesses: StorageAccessSet,
 }
 
 impl TransactionProgress {
@@ -40,9 +41,21 @@ impl TransactionProgress {
         &self.epilogue
     }
 
+    pub fn storage_accesses(&self) -> &StorageAccessSet {
+        &self.storage_accesses
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
+    pub fn record_storage_read(&mut self, slot_index: u8) {
+        self.storage_accesses.record_read(slot_index);
+    }
+
+    pub fn record_storage_write(&mut self, slot_index: u8) {
+        self.storage_accesses.record_write(slot_index);
+    }
+
     pub fn start_prologue(&mut self, cycle: RowIndex) {
         self.prologue.set_start(cycle);
     }
@@ -108,6 +121,7 @@ impl From<TransactionProgress> for TransactionMeasurements {
             note_execution,
             tx_script_processing,
             epilogue,
+            storage_accesses: tx_progress.storage_accesses,
         }
     }
 }
@@ -132,6 +146,16 @@ impl CycleInterval {
         self.end = Some(e);
     }
 
+    /// Returns the cycle at which the interval started, if it has started.
+    pub fn start(&self) -> Option<RowIndex> {
+        self.start
+    }
+
+    /// Returns the cycle at which the interval ended, if it has ended.
+    pub fn end(&self) -> Option<RowIndex> {
+        self.end
+    }
+
     /// Calculate the length of the interval
     pub fn len(&self) -> usize {
         if let Some(start) = self.start {
@@ -9,7 +9,15 @@ extern crate std;
 pub use miden_objects::transaction::TransactionInputs;
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor, TransactionMastStore};
+pub use executor::{
+    optimize_note_order, AccountStateOrigin, AccountTypeAssetPolicy, AdviceSnapshot, AuthPolicy,
+    BalanceProjection, DataStore, ExecutionContext, ExecutionOutput, FeePolicy, FinalStack,
+    FullScanDiscovery, KvDataStore, KvStore, LinearFeePolicy, NetPosition, NoteAssetPolicy,
+    NoteDiscovery, ScriptCacheStats, ScriptWhitelist, TagBasedDiscovery, TransactionExecutor,
+    TransactionMastStore,
+};
+#[cfg(feature = "std")]
+pub use executor::{CodeWarmup, WarmupReport};
 
 pub mod host;
 pub use host::{TransactionHost, TransactionProgress};
@@ -18,12 +26,12 @@ mod prover;
 pub use prover::{LocalTransactionProver, ProvingOptions, TransactionProver};
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{AccountStateTransition, TransactionVerifier};
 
 mod errors;
 pub use errors::{
-    tx_kernel_errors::TX_KERNEL_ERRORS, AuthenticationError, DataStoreError,
-    TransactionExecutorError, TransactionProverError, TransactionVerifierError,
+    tx_kernel_errors::TX_KERNEL_ERRORS, AuthenticationError, DataStoreError, PublicInputsFormatter,
+    TransactionExecutorError, TransactionProverError, TransactionVerifierError, WordFormatter,
 };
 
 pub mod auth;
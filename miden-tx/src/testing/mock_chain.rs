@@ -440,8 +440,12 @@ impl MockChain {
         for note in notes {
             let input_note = self.available_notes.get(note).unwrap().clone();
             let note_block_num = input_note.location().unwrap().block_num();
-            let block_header = self.blocks.get(note_block_num as usize).unwrap().header();
-            block_headers_map.insert(note_block_num, block_header);
+            // notes created in the reference block are authenticated against its header, which
+            // is not part of the chain MMR (e.g., for transactions against the genesis block)
+            if note_block_num != block_header.block_num().as_u32() {
+                let block_header = self.blocks.get(note_block_num as usize).unwrap().header();
+                block_headers_map.insert(note_block_num, block_header);
+            }
             input_notes.push(input_note);
         }
 
@@ -466,7 +470,7 @@ impl MockChain {
     /// This will also make all the objects currently pending available for use.
     /// If `block_num` is `Some(number)`, `number` will be used as the new block's number
     pub fn seal_block(&mut self, block_num: Option<u32>) -> Block {
-        let next_block_num = self.blocks.last().map_or(0, |b| b.header().block_num() + 1);
+        let next_block_num = self.blocks.last().map_or(0, |b| b.header().block_num().as_u32() + 1);
         let block_num: u32 = if let Some(input_block_num) = block_num {
             if input_block_num < next_block_num {
                 panic!("Input block number should be higher than the last block number");
@@ -555,7 +559,7 @@ impl MockChain {
                             BlockNoteIndex::new(batch_index, note_index).unwrap();
                         let note_path = notes_tree.get_note_path(block_note_index);
                         let note_inclusion_proof = NoteInclusionProof::new(
-                            block.header().block_num(),
+                            block.header().block_num().as_u32(),
                             block_note_index.leaf_index_value(),
                             note_path,
                         )
@@ -8,4 +8,7 @@ pub mod mock_chain;
 pub use tx_context::{TransactionContext, TransactionContextBuilder};
 mod tx_context;
 
+pub use tx_vector::{dump_transaction_vector, TransactionVector};
+mod tx_vector;
+
 pub mod utils;
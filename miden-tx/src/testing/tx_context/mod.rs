@@ -6,6 +6,7 @@ use miden_lib::transaction::TransactionKernel;
 use miden_objects::{
     accounts::{Account, AccountId},
     assembly::Assembler,
+    block::BlockNumber,
     notes::{Note, NoteId},
     transaction::{ExecutedTransaction, InputNote, InputNotes, TransactionArgs, TransactionInputs},
 };
@@ -119,6 +120,10 @@ impl TransactionContext {
     pub fn tx_inputs(&self) -> &TransactionInputs {
         &self.tx_inputs
     }
+
+    pub fn authenticator(&self) -> Option<&MockAuthenticator> {
+        self.authenticator.as_ref()
+    }
 }
 
 unsafe impl Send for TransactionContext {}
@@ -130,13 +135,37 @@ impl DataStore for TransactionContext {
     fn get_transaction_inputs(
         &self,
         account_id: AccountId,
-        block_num: u32,
+        block_num: BlockNumber,
         notes: &[NoteId],
     ) -> Result<TransactionInputs, DataStoreError> {
         assert_eq!(account_id, self.tx_inputs.account().id());
         assert_eq!(block_num, self.tx_inputs.block_header().block_num());
+
+        // the account state may be requested without notes, e.g., for note discovery
+        if notes.is_empty() {
+            let (account, account_seed, block_header, block_chain, _) =
+                self.tx_inputs.clone().into_parts();
+            return TransactionInputs::new(
+                account,
+                account_seed,
+                block_header,
+                block_chain,
+                InputNotes::default(),
+            )
+            .map_err(DataStoreError::InvalidTransactionInput);
+        }
         assert_eq!(notes.len(), self.tx_inputs.input_notes().num_notes());
 
         Ok(self.tx_inputs.clone())
     }
+
+    #[maybe_async]
+    fn get_unconsumed_notes(
+        &self,
+        block_num: BlockNumber,
+    ) -> Result<Vec<InputNote>, DataStoreError> {
+        assert_eq!(block_num, self.tx_inputs.block_header().block_num());
+
+        Ok(self.tx_inputs.input_notes().iter().cloned().collect())
+    }
 }
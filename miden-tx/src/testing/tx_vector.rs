@@ -0,0 +1,126 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use miden_lib::transaction::TransactionKernel;
+use miden_objects::{accounts::AccountId, transaction::ExecutedTransaction, Digest, Felt};
+
+// TRANSACTION VECTOR
+// ================================================================================================
+
+/// A conformance fixture describing the public inputs, the advice inputs and the expected public
+/// outputs of a transaction.
+///
+/// Transaction vectors are meant to be shared with other implementations of the Miden transaction
+/// kernel: given the same inputs, any conforming implementation must arrive at the same outputs.
+/// See [dump_transaction_vector()] for details on how a vector is built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionVector {
+    pub account_id: AccountId,
+    pub initial_account_hash: Digest,
+    pub block_hash: Digest,
+    pub input_notes_commitment: Digest,
+    pub tx_script_root: Option<Digest>,
+    pub program_root: Digest,
+    pub stack_inputs: Vec<Felt>,
+    pub advice_stack: Vec<Felt>,
+    /// Advice map entries sorted by key.
+    pub advice_map: Vec<(Digest, Vec<Felt>)>,
+    /// Inner nodes of the advice Merkle store as `(value, left, right)` tuples, sorted by value.
+    pub merkle_nodes: Vec<(Digest, Digest, Digest)>,
+    pub final_account_hash: Digest,
+    pub output_notes_commitment: Digest,
+}
+
+impl TransactionVector {
+    /// Returns this vector encoded as a JSON object.
+    ///
+    /// The encoding is stable: the keys are always emitted in the same order, digests are encoded
+    /// as hex strings and field elements as decimal strings (as their values may not be
+    /// representable by JSON numbers in all languages).
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        write_field(&mut json, "account_id", &quote(&self.account_id.to_hex()));
+        write_field(&mut json, "initial_account_hash", &digest(&self.initial_account_hash));
+        write_field(&mut json, "block_hash", &digest(&self.block_hash));
+        write_field(&mut json, "input_notes_commitment", &digest(&self.input_notes_commitment));
+        let tx_script_root = self.tx_script_root.as_ref().map_or(String::from("null"), digest);
+        write_field(&mut json, "tx_script_root", &tx_script_root);
+        write_field(&mut json, "program_root", &digest(&self.program_root));
+        write_field(&mut json, "stack_inputs", &felts(&self.stack_inputs));
+        write_field(&mut json, "advice_stack", &felts(&self.advice_stack));
+        let advice_map = self
+            .advice_map
+            .iter()
+            .map(|(key, values)| format!("[{},{}]", digest(key), felts(values)))
+            .collect::<Vec<_>>();
+        write_field(&mut json, "advice_map", &format!("[{}]", advice_map.join(",")));
+        let merkle_nodes = self
+            .merkle_nodes
+            .iter()
+            .map(|(value, left, right)| {
+                format!("[{},{},{}]", digest(value), digest(left), digest(right))
+            })
+            .collect::<Vec<_>>();
+        write_field(&mut json, "merkle_nodes", &format!("[{}]", merkle_nodes.join(",")));
+        write_field(&mut json, "final_account_hash", &digest(&self.final_account_hash));
+        write_field(&mut json, "output_notes_commitment", &digest(&self.output_notes_commitment));
+        json.pop();
+        json.push('}');
+        json
+    }
+}
+
+/// Returns a [TransactionVector] describing the provided executed transaction.
+///
+/// The stack and advice inputs are rebuilt from the transaction inputs and arguments of the
+/// executed transaction exactly as the [TransactionExecutor](crate::TransactionExecutor) builds
+/// them, and the expected outputs are taken from the results of the execution. Identical inputs
+/// always result in identical vectors.
+pub fn dump_transaction_vector(executed_tx: &ExecutedTransaction) -> TransactionVector {
+    let tx_inputs = executed_tx.tx_inputs();
+    let tx_args = executed_tx.tx_args();
+    let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(tx_inputs, tx_args, None);
+
+    let mut merkle_nodes = advice_inputs
+        .merkle_store()
+        .inner_nodes()
+        .map(|node| (node.value, node.left, node.right))
+        .collect::<Vec<_>>();
+    merkle_nodes.sort();
+
+    TransactionVector {
+        account_id: tx_inputs.account().id(),
+        initial_account_hash: tx_inputs.account().init_hash(),
+        block_hash: tx_inputs.block_header().hash(),
+        input_notes_commitment: tx_inputs.input_notes().commitment(),
+        tx_script_root: tx_args.tx_script().map(|script| script.hash()),
+        program_root: TransactionKernel::main().hash(),
+        stack_inputs: stack_inputs.to_vec(),
+        advice_stack: advice_inputs.stack().to_vec(),
+        // the advice map is backed by an ordered map, so its entries are already sorted by key
+        advice_map: advice_inputs.map.into_iter().collect(),
+        merkle_nodes,
+        final_account_hash: executed_tx.final_account().hash(),
+        output_notes_commitment: executed_tx.output_notes().commitment(),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_field(json: &mut String, key: &str, value: &str) {
+    write!(json, "\"{key}\":{value},").expect("writing to a string cannot fail");
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{value}\"")
+}
+
+fn digest(digest: &Digest) -> String {
+    quote(&digest.to_hex())
+}
+
+fn felts(felts: &[Felt]) -> String {
+    let felts = felts.iter().map(|felt| format!("\"{}\"", felt.as_int())).collect::<Vec<_>>();
+    format!("[{}]", felts.join(","))
+}
@@ -1,6 +1,6 @@
 use alloc::{
     collections::{BTreeMap, BTreeSet},
-    string::String,
+    string::{String, ToString},
     sync::Arc,
     vec::Vec,
 };
@@ -9,45 +9,72 @@ use ::assembly::{
     ast::{Module, ModuleKind},
     LibraryPath,
 };
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::{
+    accounts::{faucets::FungibleFaucet, upgrade::BasicCodeUpgrade, wallets::BasicWallet},
+    notes::{create_p2id_note, create_swap_note},
+    transaction::{TransactionEvent, TransactionKernel},
+    AuthScheme, StdLibrary,
+};
 use miden_objects::{
     accounts::{
         account_id::testing::{
             ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
-            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
             ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         },
-        AccountBuilder, AccountCode, AccountComponent, AccountStorage, AccountType, StorageSlot,
+        compute_storage_commitment, Account, AccountBuilder, AccountCode, AccountComponent,
+        AccountId, AccountStorage, AccountType, StorageSlot,
     },
-    assembly::DefaultSourceManager,
-    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    assembly::{DefaultSourceManager, Library},
+    assets::{compute_vault_root, Asset, AssetVault, FungibleAsset, NonFungibleAsset},
+    block::BlockNumber,
+    crypto::{merkle::Smt, rand::RpoRandomCoin},
     notes::{
-        Note, NoteAssets, NoteExecutionHint, NoteExecutionMode, NoteHeader, NoteId, NoteInputs,
-        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+        Note, NoteAssets, NoteConsumptionProof, NoteExecutionHint, NoteExecutionMode, NoteHeader,
+        NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteTarget, NoteType,
+        NullifierProof,
     },
     testing::{
+        account_code::DEFAULT_AUTH_SCRIPT,
         account_component::AccountMockComponent,
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
-        notes::DEFAULT_NOTE_CODE,
+        notes::{NoteBuilder, DEFAULT_NOTE_CODE},
         prepare_word,
-        storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
+        storage::{STORAGE_INDEX_0, STORAGE_INDEX_1, STORAGE_INDEX_2, STORAGE_LEAVES_2},
+    },
+    transaction::{
+        InputNote, InputNotes, OutputNote, ProvenTransaction, StorageAccessSet, TransactionArgs,
+        TransactionInputs, TransactionScript, TransactionSummary, TransactionWitness, WitnessDelta,
     },
-    transaction::{ProvenTransaction, TransactionArgs, TransactionScript},
-    Felt, Word, MIN_PROOF_SECURITY_LEVEL,
+    utils::format::JsonSerializable,
+    Felt, NoteError, ProvenTransactionError, TransactionInputError, Word, MIN_PROOF_SECURITY_LEVEL,
+    MIN_TX_EXECUTION_CYCLES, ZERO,
 };
 use miden_prover::ProvingOptions;
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    Digest, ExecutionError, MemAdviceProvider, RowIndex, ONE,
 };
+use winter_maybe_async::{maybe_async, maybe_async_trait};
 
 use super::{
-    LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
-    TransactionVerifier,
+    optimize_note_order, AccountStateOrigin, AccountStateTransition, AccountTypeAssetPolicy,
+    AuthPolicy, ExecutionContext, ExecutionOutput, FeePolicy, FullScanDiscovery, LinearFeePolicy,
+    LocalTransactionProver, NoteAssetPolicy, ScriptWhitelist, TagBasedDiscovery,
+    TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionProver,
+    TransactionVerifier, TransactionVerifierError,
+};
+use crate::{
+    auth::TransactionAuthenticator,
+    testing::{
+        dump_transaction_vector,
+        mock_chain::{Auth, MockChain, MockChainBuilder},
+        TransactionContextBuilder,
+    },
+    DataStore, DataStoreError, KvDataStore, KvStore, PublicInputsFormatter, TransactionMastStore,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
 
 mod kernel_tests;
 
@@ -120,6 +147,153 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_witness_delta_encoding() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let base: TransactionWitness = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap()
+        .into();
+    let mut tx_args = tx_context.tx_args().clone();
+    tx_args.extend_advice_map([(Digest::from([ONE; 4]), vec![Felt::new(7); 8])]);
+    let witness: TransactionWitness = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap()
+        .into();
+    assert_ne!(base, witness);
+
+    // applying the delta reproduces the original witness, in both directions
+    let delta = witness.delta_encode(&base);
+    assert!(!delta.is_empty());
+    assert!(delta.to_bytes().len() < witness.to_bytes().len());
+    assert_eq!(base.apply_delta(&delta), witness);
+    assert_eq!(witness.apply_delta(&base.delta_encode(&witness)), base);
+
+    // deltas survive serialization
+    let delta = WitnessDelta::read_from_bytes(&delta.to_bytes()).unwrap();
+    assert_eq!(TransactionWitness::apply_delta(&base, &delta), witness);
+
+    // a witness does not differ from itself
+    let delta = base.delta_encode(&base);
+    assert!(delta.is_empty());
+    assert_eq!(base.apply_delta(&delta), base);
+}
+
+#[test]
+fn transaction_witness_consumed_notes() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let witness: TransactionWitness = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap()
+        .into();
+    assert_eq!(witness.block_ref(), block_ref);
+
+    // the consumed notes are the input notes carried by the transaction inputs, and thus are
+    // available from a deserialized witness as well
+    assert!(witness.consumed_notes().map(Note::id).eq(note_ids.iter().copied()));
+    let witness = TransactionWitness::read_from_bytes(&witness.to_bytes()).unwrap();
+    assert!(witness.consumed_notes().map(Note::id).eq(note_ids.iter().copied()));
+}
+
+#[test]
+fn transaction_witness_advice_proof_size() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let witness: TransactionWitness = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap()
+        .into();
+
+    // the reported size matches the size of the serialized advice witness exactly
+    assert_eq!(witness.advice_proof_size_bytes(), witness.advice_witness.to_bytes().len());
+    assert!(witness.advice_proof_size_bytes() < witness.to_bytes().len());
+}
+
+#[test]
+fn execute_transaction_with_advice_snapshot() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    let tx_args = tx_context.tx_args().clone();
+
+    let (executed_transaction, final_recorder) = executor
+        .execute_transaction_retaining_recorder(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+
+    let (snapshot_transaction, snapshot) = executor
+        .execute_transaction_with_advice_snapshot(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_args.clone(),
+            1000,
+        )
+        .unwrap();
+    assert!(snapshot.clk() >= RowIndex::from(1000));
+    assert_ne!(snapshot.advice_provider().clone().finalize().0, final_recorder.finalize().0);
+
+    // taking the snapshot does not affect the witness of the transaction
+    assert_eq!(
+        TransactionWitness::from(snapshot_transaction),
+        TransactionWitness::from(executed_transaction)
+    );
+
+    let err = executor
+        .execute_transaction_with_advice_snapshot(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_args,
+            u32::MAX,
+        )
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::AdviceSnapshotNotReached(u32::MAX));
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -441,130 +615,2108 @@ fn test_empty_delta_nonce_update() {
 }
 
 #[test]
-fn test_send_note_proc() {
-    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
-        .with_mock_notes_preserved_with_account_vault_delta()
-        .build();
-
-    let executor =
-        TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_debug_mode(true);
-    let account_id = tx_context.tx_inputs().account().id();
-
-    // removed assets
-    let removed_asset_1 = FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT / 2);
-    let removed_asset_2 = Asset::Fungible(
-        FungibleAsset::new(
-            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().expect("id is valid"),
-            FUNGIBLE_ASSET_AMOUNT,
-        )
-        .expect("asset is valid"),
-    );
-    let removed_asset_3 =
-        NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &NON_FUNGIBLE_ASSET_DATA);
+fn executed_transaction_without_input_notes() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    assert!(tx_context.tx_inputs().input_notes().is_empty());
 
-    let tag = NoteTag::from_account_id(
-        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
-        NoteExecutionMode::Local,
+    let tx_script_src = "
+        use.test::account
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+    ";
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
     )
     .unwrap();
-    let aux = Felt::new(27);
-    let note_type = NoteType::Private;
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
 
-    assert_eq!(tag.validate(note_type), Ok(tag));
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
 
-    // prepare the asset vector to be removed for each test variant
-    let assets_matrix = vec![
-        vec![],
-        vec![removed_asset_1],
-        vec![removed_asset_1, removed_asset_2],
-        vec![removed_asset_1, removed_asset_2, removed_asset_3],
-    ];
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &[], tx_args).unwrap();
 
-    for removed_assets in assets_matrix {
-        // Prepare the string containing the procedures required for adding assets to the note.
-        // Depending on the number of the assets to remove, the resulting string will be extended
-        // with the corresponding number of procedure "blocks"
-        let mut assets_to_remove = String::new();
-        for asset in removed_assets.iter() {
-            assets_to_remove.push_str(&format!(
-                "\n
-            # prepare the stack for the next call
-            dropw
+    // the commitment to an empty set of input notes is the empty digest
+    assert!(executed_transaction.input_notes().is_empty());
+    assert_eq!(executed_transaction.input_notes().commitment(), Digest::default());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
 
-            # push the asset to be removed
-            push.{ASSET}
-            # => [ASSET, note_idx, GARBAGE(11)]
+    // only the nonce of the account changed
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert!(executed_transaction.account_delta().vault().is_empty());
+    assert!(executed_transaction.account_delta().storage().is_empty());
+    assert_eq!(executed_transaction.final_account().nonce(), Felt::new(2));
+}
 
-            call.wallet::move_asset_to_note
-            # => [ASSET, note_idx, GARBAGE(11)]\n",
-                ASSET = prepare_word(&asset.into())
-            ))
-        }
+#[test]
+fn kv_data_store() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let tx_inputs = tx_context.tx_inputs();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_inputs.block_header().block_num();
+    let note_ids = tx_inputs.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut data_store = KvDataStore::new(BTreeMap::<String, Vec<u8>>::new());
+    data_store.insert_account(tx_inputs.account(), tx_inputs.account_seed());
+    data_store.insert_block(tx_inputs.block_header(), tx_inputs.block_chain());
+    for note in tx_inputs.input_notes() {
+        data_store.insert_note(note);
+    }
+    assert_eq!(
+        data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap(),
+        *tx_inputs
+    );
+    assert_eq!(
+        data_store.get_unconsumed_notes(block_ref).unwrap(),
+        tx_inputs.input_notes().iter().cloned().collect::<Vec<_>>()
+    );
 
-        let tx_script_src = format!(
-            "\
-            use.miden::contracts::wallets::basic->wallet
-            use.miden::tx
-            use.test::account
+    let executor = TransactionExecutor::new(Arc::new(data_store), None);
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
 
-            ## TRANSACTION SCRIPT
-            ## ========================================================================================
-            begin
-                # prepare the values for note creation
-                push.1.2.3.4      # recipient
-                push.1            # note_execution_hint (NoteExecutionHint::Always)
-                push.{note_type}  # note_type
-                push.{aux}        # aux
-                push.{tag}        # tag
-                # => [tag, aux, note_type, RECIPIENT, ...]
+    // consumed and missing notes are reported
+    let mut data_store = KvDataStore::new(BTreeMap::<String, Vec<u8>>::new());
+    data_store.insert_account(tx_inputs.account(), tx_inputs.account_seed());
+    data_store.insert_block(tx_inputs.block_header(), tx_inputs.block_chain());
+    let consumed_note = tx_inputs.input_notes().get_note(0);
+    data_store.insert_note(consumed_note);
+    data_store.insert_nullifier(consumed_note.note().nullifier());
+    assert_eq!(
+        data_store.get_transaction_inputs(account_id, block_ref, &note_ids[..1]),
+        Err(DataStoreError::NoteAlreadyConsumed(note_ids[0]))
+    );
+    assert_eq!(
+        data_store.get_transaction_inputs(account_id, block_ref, &note_ids[1..2]),
+        Err(DataStoreError::NoteNotFound(note_ids[1]))
+    );
+    assert!(data_store.get_unconsumed_notes(block_ref).unwrap().is_empty());
+}
 
-                # pad the stack with zeros before calling the `create_note`.
-                padw padw swapdw
-                # => [tag, aux, execution_hint, note_type, RECIPIENT, PAD(8) ...]
+#[test]
+fn transaction_executor_note_discovery() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let tx_inputs = tx_context.tx_inputs();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_inputs.block_header().block_num();
 
-                call.wallet::create_note
-                # => [note_idx, GARBAGE(15)]
+    // a full scan discovers the notes which are likely consumable by the account
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let expected_notes = tx_inputs
+        .input_notes()
+        .iter()
+        .filter(|note| note.note().is_likely_consumable_by(account_id, tx_context.account().code()))
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    assert_eq!(executor.available_notes(account_id, block_ref).unwrap(), expected_notes);
+    let executor = executor.with_note_discovery(FullScanDiscovery);
+    assert_eq!(executor.available_notes(account_id, block_ref).unwrap(), expected_notes);
 
-                movdn.4
-                # => [GARBAGE(4), note_idx, GARBAGE(11)]
+    // tag-based discovery returns only the notes with subscribed tags
+    let tag = tx_inputs.input_notes().get_note(0).note().metadata().tag();
+    let expected_notes = tx_inputs
+        .input_notes()
+        .iter()
+        .filter(|note| note.note().metadata().tag() == tag)
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    let executor = executor.with_note_discovery(TagBasedDiscovery::new([tag]));
+    assert_eq!(executor.available_notes(account_id, block_ref).unwrap(), expected_notes);
 
-                {assets_to_remove}
+    // all discovered notes are consumed by a single transaction
+    let note_ids = tx_inputs.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+    let tags = tx_inputs.input_notes().iter().map(|note| note.note().metadata().tag());
+    let executor = executor.with_note_discovery(TagBasedDiscovery::new(tags));
+    let executed_transaction = executor
+        .consume_all(account_id, block_ref, tx_context.tx_args().clone())
+        .unwrap();
+    let expected_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    assert_eq!(executed_transaction.id(), expected_transaction.id());
 
-                dropw dropw dropw dropw
+    // data stores which cannot enumerate their notes do not support discovery
+    let executor = TransactionExecutor::new(Arc::new(StaticDataStore(tx_inputs.clone())), None);
+    assert_eq!(
+        executor.available_notes(account_id, block_ref),
+        Err(TransactionExecutorError::NoteDiscoveryFailed(
+            DataStoreError::NoteListingUnavailable
+        ))
+    );
+}
 
-                ## Update the account nonce
-                ## ------------------------------------------------------------------------------------
-                push.1 call.account::incr_nonce drop
-                # => []
-            end
-        ",
-            note_type = note_type as u8,
-        );
+#[test]
+fn transaction_executor_read_historical() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let tx_inputs = tx_context.tx_inputs();
+    let historical_account = tx_inputs.account().clone();
+    let account_id = historical_account.id();
+    let block_ref = tx_inputs.block_header().block_num();
 
-        let tx_script = TransactionScript::compile(
-            tx_script_src,
-            [],
-            TransactionKernel::testing_assembler_with_mock_account(),
-        )
-        .unwrap();
-        let tx_args = TransactionArgs::new(
-            Some(tx_script),
-            None,
-            tx_context.tx_args().advice_inputs().clone().map,
-        );
+    // the latest state of the account differs from its state as of the reference block
+    let latest_account = Account::from_parts(
+        account_id,
+        historical_account.vault().clone(),
+        historical_account.storage().clone(),
+        historical_account.code().clone(),
+        historical_account.nonce() + ONE,
+    );
 
-        let block_ref = tx_context.tx_inputs().block_header().block_num();
-        let note_ids = tx_context
-            .tx_inputs()
-            .input_notes()
-            .iter()
-            .map(|note| note.id())
-            .collect::<Vec<_>>();
+    let mut data_store = KvDataStore::new(BTreeMap::<String, Vec<u8>>::new());
+    data_store.insert_account(&latest_account, None);
+    data_store.insert_account_at(&historical_account, block_ref);
+    data_store.insert_block(tx_inputs.block_header(), tx_inputs.block_chain());
+
+    let executor = TransactionExecutor::new(Arc::new(data_store), None);
+    let executed_transaction = executor.read_historical(account_id, block_ref).unwrap();
+    assert_eq!(*executed_transaction.initial_account(), historical_account);
+    assert_eq!(executed_transaction.final_account().hash(), historical_account.hash());
+
+    // historical states are not available for other blocks, nor from stores which do not keep them
+    let parent_block = block_ref.parent().unwrap();
+    let expected_err =
+        DataStoreError::HistoricalStateUnavailable { account_id, block_num: parent_block };
+    assert!(matches!(
+        executor.read_historical(account_id, parent_block),
+        Err(TransactionExecutorError::FetchTransactionInputsFailed(err)) if err == expected_err
+    ));
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None);
+    assert!(matches!(
+        executor.read_historical(account_id, block_ref),
+        Err(TransactionExecutorError::FetchTransactionInputsFailed(
+            DataStoreError::HistoricalStateUnavailable { .. }
+        ))
+    ));
+}
 
-        // expected delta
-        // --------------------------------------------------------------------------------------------
-        // execute the transaction and get the witness
-        let executed_transaction =
+#[test]
+fn transaction_executor_balance_projection() {
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ONE,
+        TransactionKernel::testing_assembler(),
+    );
+    let held_asset = account.vault().assets().find(Asset::is_fungible).unwrap();
+    let sender = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let mut rng = RpoRandomCoin::new(Word::default());
+
+    let received = FungibleAsset::new(sender, 100).unwrap().into();
+    let p2id_note =
+        create_p2id_note(sender, account.id(), vec![received], NoteType::Public, ZERO, &mut rng)
+            .unwrap();
+    let offered = FungibleAsset::new(sender, 50).unwrap().into();
+    let requested = FungibleAsset::new(held_asset.faucet_id(), 1).unwrap().into();
+    let (swap_note, _) =
+        create_swap_note(sender, offered, requested, NoteType::Public, ZERO, &mut rng).unwrap();
+
+    // the effects of standard notes are determined statically
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(vec![p2id_note.clone(), swap_note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = [p2id_note.id(), swap_note.id()];
+
+    let projection = executor
+        .project_balance_change(account.id(), block_ref, &note_ids, None)
+        .unwrap();
+    let mut expected_vault = account.vault().clone();
+    expected_vault.add_asset(received).unwrap();
+    expected_vault.add_asset(offered).unwrap();
+    expected_vault.remove_asset(requested).unwrap();
+    assert!(projection.is_complete());
+    assert_eq!(*projection.vault(), expected_vault);
+
+    // transaction scripts and custom notes make the projection incomplete
+    let tx_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, [], TransactionKernel::testing_assembler())
+            .unwrap();
+    let projection = executor
+        .project_balance_change(account.id(), block_ref, &note_ids, Some(&tx_script))
+        .unwrap();
+    assert!(!projection.is_complete());
+    assert!(projection.has_dynamic_tx_script());
+    assert_eq!(*projection.vault(), expected_vault);
+
+    let custom_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([0_u8; 32]))
+        .add_assets([received])
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(vec![p2id_note.clone(), custom_note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None);
+    let projection = executor
+        .project_balance_change(account.id(), block_ref, &[p2id_note.id(), custom_note.id()], None)
+        .unwrap();
+    assert!(!projection.is_complete());
+    assert_eq!(projection.dynamic_notes(), &[custom_note.id()]);
+    assert_eq!(
+        projection.vault().get_balance(sender).unwrap(),
+        account.vault().get_balance(sender).unwrap() + 100
+    );
+
+    // a swap which the account cannot fill is reported
+    let requested =
+        FungibleAsset::new(held_asset.faucet_id(), held_asset.unwrap_fungible().amount() + 1)
+            .unwrap()
+            .into();
+    let (swap_note, _) =
+        create_swap_note(sender, offered, requested, NoteType::Public, ZERO, &mut rng).unwrap();
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(vec![swap_note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None);
+    assert!(matches!(
+        executor.project_balance_change(account.id(), block_ref, &[swap_note.id()], None),
+        Err(TransactionExecutorError::BalanceProjectionFailed(_))
+    ));
+}
+
+#[test]
+fn transaction_executor_net_position() {
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ONE,
+        TransactionKernel::testing_assembler(),
+    );
+    let held_asset = account.vault().assets().find(Asset::is_fungible).unwrap().unwrap_fungible();
+    let sender = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3).unwrap();
+    let mut rng = RpoRandomCoin::new(Word::default());
+
+    let received = FungibleAsset::new(sender, 100).unwrap();
+    let topped_up = FungibleAsset::new(held_asset.faucet_id(), 5).unwrap();
+    let notes = [received, topped_up].map(|asset| {
+        create_p2id_note(sender, account.id(), vec![asset.into()], NoteType::Public, ZERO, &mut rng)
+            .unwrap()
+    });
+    let tags = notes.iter().map(|note| note.metadata().tag());
+
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(notes.to_vec())
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_note_discovery(TagBasedDiscovery::new(tags));
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the vault is settled, while the assets of the discovered notes are pending
+    let position = executor.net_position(account.id(), block_ref).unwrap();
+    assert_eq!(position.settled(), account.vault());
+    assert_eq!(position.balance(sender).unwrap(), (0, received.amount()));
+    assert_eq!(
+        position.balance(held_asset.faucet_id()).unwrap(),
+        (held_asset.amount(), topped_up.amount())
+    );
+    assert_eq!(
+        position.total().get_balance(held_asset.faucet_id()).unwrap(),
+        held_asset.amount() + topped_up.amount()
+    );
+
+    // balances exceeding the maximum amount are rejected
+    let overflowing_asset =
+        FungibleAsset::new(held_asset.faucet_id(), FungibleAsset::MAX_AMOUNT).unwrap();
+    let overflowing_note = create_p2id_note(
+        sender,
+        account.id(),
+        vec![overflowing_asset.into()],
+        NoteType::Public,
+        ZERO,
+        &mut rng,
+    )
+    .unwrap();
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .input_notes(vec![overflowing_note.clone()])
+        .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None)
+        .with_note_discovery(TagBasedDiscovery::new([overflowing_note.metadata().tag()]));
+    assert!(matches!(
+        executor.net_position(account.id(), block_ref),
+        Err(TransactionExecutorError::NetPositionFailed(_))
+    ));
+}
+
+#[test]
+fn execute_storage_update() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let new_value = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let tx_script_src = format!(
+        "
+        use.test::account
+        begin
+            push.{new_value}
+            push.{STORAGE_INDEX_0}
+            call.account::set_item dropw dropw
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        new_value = prepare_word(&new_value),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let executed_transaction = executor
+        .execute_storage_update(account_id, block_ref, tx_script.clone(), true)
+        .unwrap();
+    assert!(executed_transaction.input_notes().is_empty());
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+
+    // only the storage and the nonce of the account changed
+    let account_delta = executed_transaction.account_delta();
+    assert_eq!(account_delta.nonce(), Some(Felt::new(2)));
+    assert!(account_delta.vault().is_empty());
+    assert_eq!(account_delta.storage().values().get(&STORAGE_INDEX_0), Some(&new_value));
+
+    // in strict mode, scripts creating notes are rejected
+    let tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+        use.test::account
+        begin
+            push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            call.wallet::create_note dropw dropw dropw dropw
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        recipient = prepare_word(&Word::default()),
+        execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = tag,
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let err = executor
+        .execute_storage_update(account_id, block_ref, tx_script.clone(), true)
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::StorageUpdateCreatedNotes(1));
+    executor
+        .execute_storage_update(account_id, block_ref, tx_script, false)
+        .unwrap();
+}
+
+#[test]
+fn transaction_executor_validates_created_notes() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+    let recipient = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let create_note = |recipient: &Word| {
+        format!(
+            "
+            push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            call.wallet::create_note dropw dropw dropw dropw
+            ",
+            recipient = prepare_word(recipient),
+            execution_hint = Felt::from(NoteExecutionHint::always()),
+            note_type = NoteType::Private as u8,
+        )
+    };
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+        use.test::account
+        begin
+            {valid_note}
+            {empty_note}
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        valid_note = create_note(&recipient),
+        empty_note = create_note(&Word::default()),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    // the check is opt-in
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    executor
+        .execute_storage_update(account_id, block_ref, tx_script.clone(), false)
+        .unwrap();
+
+    let executor = executor.with_created_note_validation();
+    let err = executor
+        .execute_storage_update(account_id, block_ref, tx_script, false)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::MalformedCreatedNote {
+            index: 1,
+            error: NoteError::EmptyNoteRecipient
+        }
+    );
+}
+
+#[test]
+fn transaction_executor_missing_auth_advice() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.seal_block(None);
+
+    let auth_scheme = AuthScheme::from_account(&account).unwrap();
+    let AuthScheme::RpoFalcon512 { pub_key } = &auth_scheme;
+    assert_eq!(Digest::from(Word::from(*pub_key)), account.storage().get_item(0).unwrap());
+
+    let tx_script =
+        TransactionScript::compile(DEFAULT_AUTH_SCRIPT, [], TransactionKernel::assembler())
+            .unwrap();
+    let tx_context = mock_chain.build_tx_context(account.id()).tx_script(tx_script.clone()).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the signature is provided by the authenticator of the account
+    tx_context.clone().execute().unwrap();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None);
+    let err = executor
+        .execute_transaction(
+            account.id(),
+            block_ref,
+            &[],
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::MissingAuthAdvice {
+            account_id: account.id(),
+            auth_procedure: Some(auth_scheme.procedure_root()),
+        }
+    );
+}
+
+#[test]
+fn transaction_executor_code_migration() {
+    let mut mock_chain = MockChain::new();
+    let account = mock_chain.add_from_account_builder(
+        Auth::BasicAuth,
+        AccountBuilder::new()
+            .init_seed([7; 32])
+            .nonce(ONE)
+            .account_type(AccountType::RegularAccountUpdatableCode)
+            .with_component(BasicWallet)
+            .with_component(BasicCodeUpgrade),
+    );
+    let wallet = mock_chain.add_existing_wallet(Auth::BasicAuth, vec![]);
+    mock_chain.seal_block(None);
+
+    // the new code no longer includes the code upgrade component
+    let new_code = wallet.code().clone();
+    assert!(BasicCodeUpgrade::is_included_in(account.code()));
+    assert!(!BasicCodeUpgrade::is_included_in(&new_code));
+
+    let tx_context = mock_chain.build_tx_context(account.id()).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let authenticator = tx_context
+        .authenticator()
+        .cloned()
+        .map(|auth| Arc::new(auth) as Arc<dyn TransactionAuthenticator>);
+    let executor = TransactionExecutor::new(Arc::new(tx_context), authenticator);
+
+    let executed_transaction = executor
+        .execute_code_migration(account.id(), block_ref, new_code.clone())
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().code_commitment(), new_code.commitment());
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(account.nonce() + ONE));
+
+    // migrating to the current code is rejected
+    assert_eq!(
+        executor
+            .execute_code_migration(account.id(), block_ref, account.code().clone())
+            .unwrap_err(),
+        TransactionExecutorError::UnchangedAccountCode(account.code().commitment())
+    );
+
+    // accounts without the code upgrade component cannot be migrated
+    let tx_context = mock_chain.build_tx_context(wallet.id()).build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None);
+    assert_eq!(
+        executor
+            .execute_code_migration(wallet.id(), block_ref, account.code().clone())
+            .unwrap_err(),
+        TransactionExecutorError::AccountCodeNotUpgradable(wallet.id())
+    );
+}
+
+#[test]
+fn transaction_executor_note_asset_policy() {
+    // regular accounts may consume notes carrying any assets
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_note_asset_policy(AccountTypeAssetPolicy);
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    // faucets may consume only notes carrying their own assets
+    let tx_context = TransactionContextBuilder::with_fungible_faucet(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ONE,
+        Felt::new(100),
+    )
+    .build();
+    let faucet = tx_context.account().clone();
+    let own_asset: Asset = FungibleAsset::new(faucet.id(), 10).unwrap().into();
+    let foreign_asset: Asset =
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().unwrap(), 10)
+            .unwrap()
+            .into();
+    assert!(AccountTypeAssetPolicy.is_compatible(&faucet, &own_asset));
+    assert!(!AccountTypeAssetPolicy.is_compatible(&faucet, &foreign_asset));
+
+    let note = NoteBuilder::new(account_id, ChaCha20Rng::from_seed([4; 32]))
+        .add_assets([foreign_asset])
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+    let tx_context = TransactionContextBuilder::with_fungible_faucet(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ONE,
+        Felt::new(100),
+    )
+    .input_notes(vec![note.clone()])
+    .build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None)
+        .with_note_asset_policy(AccountTypeAssetPolicy);
+    assert_eq!(
+        executor
+            .execute_transaction(faucet.id(), block_ref, &[note.id()], TransactionArgs::default())
+            .unwrap_err(),
+        TransactionExecutorError::IncompatibleNoteAssets {
+            note_id: note.id(),
+            asset: foreign_asset
+        }
+    );
+}
+
+#[test]
+fn transaction_executor_tracks_faucet_issuance() {
+    let initial_issuance = FungibleAsset::MAX_AMOUNT - 2 * FUNGIBLE_ASSET_AMOUNT;
+    let tx_context = TransactionContextBuilder::with_fungible_faucet(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ONE,
+        Felt::new(initial_issuance),
+    )
+    .build();
+    assert_eq!(tx_context.account().total_issued(), initial_issuance);
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the minted asset is moved into a newly created note
+    let tag = NoteTag::from_account_id(account_id, NoteExecutionMode::Local).unwrap();
+    let mint_script = |amount: u64| {
+        let code = format!(
+            "
+            use.miden::contracts::wallets::basic->wallet
+            use.test::account
+
+            begin
+                push.{recipient}
+                push.{execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                padw padw swapdw
+                call.wallet::create_note
+                # => [note_idx, PAD(15)]
+
+                swapw dropw
+                push.{amount}.0.0.{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN}
+                call.account::mint
+                call.account::add_asset_to_note
+                dropw dropw dropw dropw
+
+                push.1 call.account::incr_nonce drop
+            end
+            ",
+            recipient = prepare_word(&Word::default()),
+            execution_hint = Felt::from(NoteExecutionHint::always()),
+            note_type = NoteType::Private as u8,
+        );
+        let tx_script = TransactionScript::compile(
+            code,
+            [],
+            TransactionKernel::testing_assembler_with_mock_account(),
+        )
+        .unwrap();
+        TransactionArgs::with_tx_script(tx_script)
+    };
+
+    // the account delta reflects the updated issuance
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &[], mint_script(FUNGIBLE_ASSET_AMOUNT))
+        .unwrap();
+    let mut account = tx_context.account().clone();
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(account.total_issued(), initial_issuance + FUNGIBLE_ASSET_AMOUNT);
+
+    // minting beyond the maximum issuance is reported as a dedicated error
+    let err = executor
+        .execute_transaction(account_id, block_ref, &[], mint_script(3 * FUNGIBLE_ASSET_AMOUNT))
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::FaucetSupplyOverflow(account_id));
+}
+
+#[test]
+fn transaction_executor_injects_note_consumption_proofs() {
+    // the note can only be consumed if its consumption proof is equal to [1, 2]
+    let note_script_src = "
+        use.miden::note
+
+        begin
+            exec.note::get_serial_number hash adv.push_mapval dropw
+            adv_push.2
+            push.1 assert_eq
+            push.2 assert_eq
+        end
+        ";
+    let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let input_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([9_u8; 32]))
+        .code(note_script_src)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![input_note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let notes = [input_note.id()];
+
+    // without a proof, the missing proof is reported for the note
+    let err = executor
+        .execute_transaction(account_id, block_ref, &notes, TransactionArgs::default())
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::MissingNoteConsumptionProof(input_note.id()));
+
+    // a proof for a note which is not consumed is rejected before execution
+    let proof = NoteConsumptionProof::new(vec![Felt::new(2), Felt::new(1)]).unwrap();
+    let unknown_note_id = NoteId::from(Digest::default());
+    let mut tx_args = TransactionArgs::default();
+    tx_args.add_note_consumption_proof(unknown_note_id, proof.clone());
+    let err = executor
+        .execute_transaction(account_id, block_ref, &notes, tx_args)
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::UnknownNoteConsumptionProof(unknown_note_id));
+
+    // with the proof, the note is consumed
+    let mut tx_args = TransactionArgs::default();
+    tx_args.add_note_consumption_proof(input_note.id(), proof);
+    executor.execute_transaction(account_id, block_ref, &notes, tx_args).unwrap();
+}
+
+#[test]
+fn executed_transaction_establishes_new_account() {
+    let component = AccountMockComponent::new_with_slots(
+        TransactionKernel::testing_assembler(),
+        vec![StorageSlot::empty_value()],
+    )
+    .unwrap();
+    let (account, seed) = AccountBuilder::new()
+        .init_seed(ChaCha20Rng::from_seed([3; 32]).gen())
+        .with_component(component)
+        .build()
+        .unwrap();
+    assert!(account.is_new());
+
+    let tx_context = TransactionContextBuilder::new(account).account_seed(Some(seed)).build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the account is created and used in the same transaction
+    let tx_script = TransactionScript::compile(
+        "use.test::account begin push.1 call.account::incr_nonce drop end",
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &[], tx_args).unwrap();
+    assert_eq!(executed_transaction.initial_account().nonce(), Felt::new(0));
+    assert_eq!(executed_transaction.final_account().nonce(), ONE);
+
+    // a transaction which does not increment the nonce of the new account is rejected
+    let err = executor
+        .execute_transaction(account_id, block_ref, &[], TransactionArgs::default())
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::NewAccountNonceNotIncremented(account_id));
+}
+
+#[test]
+fn executed_transaction_multiple_output_notes_from_single_note() {
+    let sender = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap();
+    let recipients = [
+        Digest::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        Digest::from([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+        Digest::from([Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)]),
+    ];
+    let tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap();
+
+    // a note script which fans out into three output notes without assets
+    let create_notes = recipients
+        .iter()
+        .map(|recipient| {
+            format!(
+                "
+                push.{recipient}
+                push.{execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                call.wallet::create_note
+                dropw dropw
+                ",
+                recipient = prepare_word(recipient),
+                execution_hint = Felt::from(NoteExecutionHint::always()),
+                note_type = NoteType::Private as u8,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let note_script_src = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            {create_notes}
+        end
+        "
+    );
+    let input_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([3_u8; 32]))
+        .code(note_script_src)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![input_note.clone()])
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let executed_transaction = executor
+        .execute_transaction(
+            account_id,
+            block_ref,
+            &[input_note.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    // all created notes are collected in the order in which they were created
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 3);
+    let empty_assets = NoteAssets::new(vec![]).unwrap();
+    for (idx, recipient) in recipients.iter().enumerate() {
+        let expected_id = NoteId::new(*recipient, empty_assets.commitment());
+        assert_eq!(output_notes.get_note(idx).id(), expected_id);
+    }
+
+    // exceeding the configured cap results in an error rather than in truncation
+    let executor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_max_output_notes(2);
+    let err = executor
+        .execute_transaction(
+            account_id,
+            block_ref,
+            &[input_note.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::TooManyOutputNotes { max: 2, actual: 3 });
+
+    let executor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_max_output_notes(3);
+    executor
+        .execute_transaction(
+            account_id,
+            block_ref,
+            &[input_note.id()],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+}
+
+#[test]
+fn executed_transaction_against_genesis_block() {
+    let account = Account::mock(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ONE,
+        TransactionKernel::testing_assembler(),
+    );
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([5_u8; 32]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    // a chain consisting only of the genesis block, in which the note was created
+    let mut mock_chain = MockChainBuilder::default().notes(vec![note.clone()]).build();
+    let tx_inputs = mock_chain.get_transaction_inputs(account.clone(), None, &[note.id()]);
+    assert_eq!(tx_inputs.block_header().block_num(), BlockNumber::GENESIS);
+    assert_eq!(tx_inputs.block_chain().chain_length(), 0);
+
+    let executor = TransactionExecutor::new(Arc::new(StaticDataStore(tx_inputs)), None);
+    let executed_transaction = executor
+        .execute_transaction(
+            account.id(),
+            BlockNumber::GENESIS,
+            &[note.id()],
+            TransactionArgs::default(),
+        )
+        .unwrap();
+    assert_eq!(executed_transaction.block_header().block_num(), BlockNumber::GENESIS);
+    assert_eq!(executed_transaction.input_notes().num_notes(), 1);
+
+    // the chain MMR must cover exactly the blocks preceding the reference block
+    mock_chain.seal_block(None);
+    let block_chain = mock_chain
+        .get_transaction_inputs(account.clone(), None, &[])
+        .block_chain()
+        .clone();
+    let err = TransactionInputs::new(
+        account,
+        None,
+        mock_chain.block_header(0),
+        block_chain,
+        InputNotes::new(vec![]).unwrap(),
+    )
+    .unwrap_err();
+    assert_eq!(err, TransactionInputError::InconsistentChainLength { expected: 0, actual: 1 });
+}
+
+#[test]
+fn execute_prepared_transaction_from_bytes() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    // the transaction is prepared and serialized on one machine ...
+    let tx_inputs_bytes = tx_context.tx_inputs().to_bytes();
+    let tx_args_bytes = tx_context.tx_args().to_bytes();
+
+    // ... and deserialized and executed on another one
+    let tx_inputs = TransactionInputs::read_from_bytes(&tx_inputs_bytes).unwrap();
+    let tx_args = TransactionArgs::read_from_bytes(&tx_args_bytes).unwrap();
+    let executed_transaction = executor.execute_prepared_transaction(tx_inputs, tx_args).unwrap();
+
+    let expected_transaction = tx_context.execute().unwrap();
+    assert_eq!(executed_transaction.id(), expected_transaction.id());
+    assert_eq!(executed_transaction.final_account(), expected_transaction.final_account());
+    assert_eq!(executed_transaction.output_notes(), expected_transaction.output_notes());
+
+    // inconsistent inputs are rejected during deserialization
+    let mut corrupted_bytes = tx_inputs_bytes;
+    let last = corrupted_bytes.len() - 1;
+    corrupted_bytes[last] ^= 1;
+    assert!(TransactionInputs::read_from_bytes(&corrupted_bytes).is_err());
+}
+
+#[test]
+fn transaction_executor_trusted_auth_policy() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let authenticated_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    assert!(authenticated_tx.input_notes().iter().all(|note| note.proof().is_some()));
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_auth_policy(AuthPolicy::Trusted);
+    let trusted_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    // the notes are consumed without inclusion proofs, and the transaction records this by
+    // committing to the note hashes
+    assert!(trusted_tx.input_notes().iter().all(|note| note.proof().is_none()));
+    assert_ne!(
+        trusted_tx.input_notes().commitment(),
+        authenticated_tx.input_notes().commitment()
+    );
+    assert_eq!(trusted_tx.final_account(), authenticated_tx.final_account());
+    assert_eq!(trusted_tx.output_notes(), authenticated_tx.output_notes());
+
+    // the proven transaction records all input notes as unauthenticated, and thus requires them
+    // to be authenticated when the transaction is included in a block
+    let prover = LocalTransactionProver::new(ProvingOptions::default());
+    let proven_authenticated_tx = prover.prove(authenticated_tx.into()).unwrap();
+    let proven_trusted_tx = prover.prove(trusted_tx.into()).unwrap();
+    assert!(proven_authenticated_tx.input_notes().iter().all(|note| note.is_authenticated()));
+    assert!(proven_trusted_tx.input_notes().iter().all(|note| !note.is_authenticated()));
+    assert_ne!(proven_trusted_tx.id(), proven_authenticated_tx.id());
+    assert!(TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL)
+        .verify(proven_trusted_tx)
+        .is_ok());
+}
+
+#[test]
+fn transaction_executor_rejects_timelocked_notes() {
+    let sender = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap();
+    let build_context = |earliest_block: u32| {
+        let note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([7_u8; 32]))
+            .note_execution_hint(NoteExecutionHint::after_block(earliest_block))
+            .build(&TransactionKernel::testing_assembler())
+            .unwrap();
+        TransactionContextBuilder::with_standard_account(ONE)
+            .input_notes(vec![note])
+            .build()
+    };
+
+    // the note becomes spendable only after the reference block
+    let tx_context = build_context(100);
+    let block_ref = tx_context.tx_inputs().block_header().block_num().as_u32();
+    assert!(block_ref < 100);
+    let note_id = tx_context.tx_inputs().input_notes().get_note(0).id();
+    assert_eq!(
+        tx_context.execute().unwrap_err(),
+        TransactionExecutorError::NoteNotYetSpendable { note_id, earliest_block: 100, block_ref }
+    );
+
+    // the note is spendable starting from the reference block
+    let tx_context = build_context(block_ref);
+    tx_context.execute().unwrap();
+}
+
+#[test]
+fn linear_fee_policy() {
+    let faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    // transaction measurements are collected only when tracing is enabled
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_tracing();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let total_cycles = executed_transaction.measurements().total_cycles() as u64;
+    assert!(total_cycles > 0);
+
+    let fee_policy = LinearFeePolicy::new(faucet_id, 10, 2).unwrap();
+    let fee = fee_policy.compute_transaction_fee(&executed_transaction).unwrap();
+    assert_eq!(fee, FungibleAsset::new(faucet_id, 10 + 2 * total_cycles).unwrap());
+
+    // the fee is capped at the maximum fungible asset amount
+    let fee_policy = LinearFeePolicy::new(faucet_id, 0, u64::MAX).unwrap();
+    let fee = fee_policy.compute_transaction_fee(&executed_transaction).unwrap();
+    assert_eq!(fee.amount(), FungibleAsset::MAX_AMOUNT);
+
+    // free transactions have no fee
+    let fee_policy = LinearFeePolicy::new(faucet_id, 0, 0).unwrap();
+    assert_eq!(fee_policy.compute_transaction_fee(&executed_transaction), None);
+
+    // fees must be denominated in fungible assets
+    assert!(LinearFeePolicy::new(account_id, 1, 1).is_err());
+}
+
+#[test]
+fn fee_policy_check_fee_paid() {
+    let faucet_id = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let collector = ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap();
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    // pay the fee with a P2ID note to the collector created in the transaction script
+    let paid_fee = FUNGIBLE_ASSET_AMOUNT / 2;
+    let fee_note = create_p2id_note(
+        account_id,
+        collector,
+        vec![FungibleAsset::mock(paid_fee)],
+        NoteType::Private,
+        ZERO,
+        &mut RpoRandomCoin::new(Word::default()),
+    )
+    .unwrap();
+
+    let tx_script_src = format!(
+        "\
+        use.miden::contracts::wallets::basic->wallet
+        use.test::account
+
+        begin
+            push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.{aux}
+            push.{tag}
+            padw padw swapdw
+            call.wallet::create_note
+            # => [note_idx, GARBAGE(15)]
+
+            movdn.4 dropw
+            push.{asset}
+            call.wallet::move_asset_to_note
+            dropw dropw dropw dropw
+
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        recipient = prepare_word(&fee_note.recipient().digest()),
+        execution_hint = Felt::from(fee_note.metadata().execution_hint()),
+        note_type = fee_note.metadata().note_type() as u8,
+        aux = fee_note.metadata().aux(),
+        tag = fee_note.metadata().tag(),
+        asset = prepare_word(&FungibleAsset::mock(paid_fee).into()),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+    let mut tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+    tx_args.extend_expected_output_notes(vec![fee_note]);
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_tracing();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    // the paid fee covers the computed fee
+    let fee_policy = LinearFeePolicy::new(faucet_id, paid_fee, 0).unwrap();
+    assert_eq!(fee_policy.check_fee_paid(&executed_transaction, collector), Ok(()));
+
+    // free transactions don't need to pay a fee
+    let fee_policy = LinearFeePolicy::new(faucet_id, 0, 0).unwrap();
+    assert_eq!(fee_policy.check_fee_paid(&executed_transaction, account_id), Ok(()));
+
+    // the paid fee doesn't cover the computed fee
+    let fee_policy = LinearFeePolicy::new(faucet_id, paid_fee + 1, 0).unwrap();
+    assert_eq!(
+        fee_policy.check_fee_paid(&executed_transaction, collector),
+        Err(TransactionExecutorError::InsufficientFee {
+            required: FungibleAsset::new(faucet_id, paid_fee + 1).unwrap(),
+            paid: paid_fee,
+        })
+    );
+
+    // notes to other accounts don't pay the fee
+    let fee_policy = LinearFeePolicy::new(faucet_id, paid_fee, 0).unwrap();
+    assert_eq!(
+        fee_policy.check_fee_paid(&executed_transaction, account_id),
+        Err(TransactionExecutorError::InsufficientFee {
+            required: FungibleAsset::new(faucet_id, paid_fee).unwrap(),
+            paid: 0,
+        })
+    );
+}
+
+#[test]
+fn transaction_executor_nullifier_checker() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let input_notes = tx_context.tx_inputs().input_notes();
+    let note_ids = input_notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // mark the last input note as spent
+    let spent_note = input_notes.get_note(input_notes.num_notes() - 1).note().clone();
+    let spent_nullifiers = BTreeSet::from([spent_note.nullifier()]);
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_nullifier_checker(move |nullifier| spent_nullifiers.contains(nullifier));
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::NoteAlreadySpent {
+            note_id: spent_note.id(),
+            nullifier: spent_note.nullifier()
+        }
+    );
+
+    // the kernel verifies that the nullifiers it computes match the input notes commitment built
+    // from the nullifiers checked by the executor, so the transaction executes successfully
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_nullifier_checker(|_| false);
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+}
+
+#[test]
+fn transaction_inputs_consumed_assets() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let input_notes = tx_context.tx_inputs().input_notes();
+    let consumed_assets = tx_context.tx_inputs().consumed_assets().unwrap();
+
+    // fungible assets are summed up per faucet
+    let fungible_faucets =
+        [ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2]
+            .map(|faucet_id| AccountId::try_from(faucet_id).unwrap());
+    for faucet_id in fungible_faucets {
+        let expected: u64 = input_notes
+            .iter()
+            .map(|note| note.note().assets().fungible_amount(faucet_id))
+            .sum();
+        assert_eq!(consumed_assets.get_balance(faucet_id).unwrap(), expected);
+    }
+
+    // every input note asset is present in the consumed assets
+    for asset in input_notes.iter().flat_map(|note| note.note().assets().iter()) {
+        if let Asset::NonFungible(_) = asset {
+            assert!(consumed_assets.has_non_fungible_asset(*asset).unwrap());
+        }
+    }
+    assert_eq!(
+        consumed_assets.assets().count(),
+        input_notes
+            .iter()
+            .flat_map(|note| note.note().assets().iter())
+            .map(|asset| Digest::from(asset.vault_key()))
+            .collect::<BTreeSet<_>>()
+            .len()
+    );
+}
+
+#[test]
+fn transaction_executor_rejects_duplicate_notes() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_id = tx_context.tx_inputs().input_notes().get_note(0).id();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let err = executor
+        .execute_transaction(
+            account_id,
+            block_ref,
+            &[note_id, note_id],
+            tx_context.tx_args().clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::DuplicateInputNote(note_id));
+}
+
+#[test]
+fn transaction_executor_debug_sink() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script_src = "
+        use.test::account
+        begin
+            push.1.2.3 debug.stack.3 trace.1000 drop drop drop
+            push.1 call.account::incr_nonce drop
+        end
+    ";
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account().with_debug_mode(true),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    let execute = |debug_mode: bool| {
+        let records = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink_records = records.clone();
+        let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+            .with_debug_mode(debug_mode)
+            .with_tracing()
+            .with_debug_sink(move |record| sink_records.lock().unwrap().push(record));
+        executor
+            .execute_transaction(account_id, block_ref, &[], tx_args.clone())
+            .unwrap();
+        let records = records.lock().unwrap().clone();
+        records
+    };
+
+    let records = execute(true);
+    assert_eq!(records.len(), 2);
+    assert!(records[0].message().starts_with("stack: [3, 2, 1]"));
+    assert_eq!(records[1].message(), "trace.1000");
+
+    // debug instructions are not executed outside of debug mode
+    let records = execute(false);
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].message(), "trace.1000");
+}
+
+#[test]
+fn transaction_executor_with_context() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    // the event hook observes the events emitted by the kernel without affecting the execution
+    let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let hook_events = events.clone();
+    let context = ExecutionContext::new()
+        .with_event_hook(move |event| hook_events.lock().unwrap().push(event.clone()));
+    let executed_with_context = executor
+        .execute_transaction_with_context(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+            &context.clone(),
+        )
+        .unwrap();
+    assert_eq!(executed_with_context.id(), executed_transaction.id());
+    assert!(events.lock().unwrap().contains(&TransactionEvent::AccountPushProcedureIndex));
+
+    // the cycle limit of the context applies only to the transaction executed with it
+    let context = context.with_max_cycles(MIN_TX_EXECUTION_CYCLES);
+    assert_eq!(context.max_cycles(), MIN_TX_EXECUTION_CYCLES);
+    let err = executor
+        .execute_transaction_with_context(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+            &context,
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::CycleLimitExceeded(_)
+        )
+    ));
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+}
+
+#[test]
+fn transaction_executor_with_sink() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    let mut outputs = Vec::new();
+    let witness = executor
+        .execute_transaction_with_sink(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+            |output| outputs.push(output),
+        )
+        .unwrap();
+    assert_eq!(witness, TransactionWitness::from(executed_transaction.clone()));
+
+    // all events are reported before the created notes, which are reported in index order
+    let num_events = outputs
+        .iter()
+        .take_while(|output| matches!(output, ExecutionOutput::Event(_)))
+        .count();
+    assert!(num_events > 0);
+    let notes = outputs[num_events..]
+        .iter()
+        .map(|output| match output {
+            ExecutionOutput::Note(note) => *note.clone(),
+            ExecutionOutput::Event(event) => panic!("unexpected event {event} after notes"),
+        })
+        .collect::<Vec<_>>();
+    assert!(!notes.is_empty());
+    assert_eq!(notes, executed_transaction.output_notes().iter().cloned().collect::<Vec<_>>());
+}
+
+#[test]
+fn transaction_executor_full() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    let (witness, measurements, created_notes) = executor
+        .execute_transaction_full(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    assert_eq!(witness, TransactionWitness::from(executed_transaction.clone()));
+    assert_eq!(measurements.total_cycles(), executed_transaction.measurements().total_cycles());
+
+    let expected_notes = executed_transaction
+        .output_notes()
+        .iter()
+        .filter_map(|note| match note {
+            OutputNote::Full(note) => Some(note.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert!(!expected_notes.is_empty());
+    assert_eq!(created_notes, expected_notes);
+
+    // the failing phase is identified by the returned error
+    let tx_script = TransactionScript::compile(
+        "begin push.0 assert end",
+        [],
+        TransactionKernel::testing_assembler(),
+    )
+    .unwrap();
+    let err = executor
+        .execute_transaction_full(
+            account_id,
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap_err();
+    assert!(matches!(err, TransactionExecutorError::ExecuteTransactionProgramFailed(_)));
+}
+
+#[test]
+fn transaction_executor_verify_kernel_calls() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    assert_eq!(executor.kernel_procedure_roots(), TransactionKernel::PROCEDURES[0]);
+    executor.verify_kernel_calls(tx_context.account().code()).unwrap();
+
+    // code compiled against a different kernel calls procedures unknown to the executor
+    let kernel = ::assembly::Assembler::default()
+        .assemble_kernel("export.foo push.1 drop end")
+        .unwrap();
+    let assembler =
+        ::assembly::Assembler::with_kernel(Arc::new(DefaultSourceManager::default()), kernel);
+    let component = AccountComponent::compile("export.bar syscall.foo end", assembler, vec![])
+        .unwrap()
+        .with_supports_all_types();
+    let code = AccountCode::from_components(
+        &[component.clone()],
+        AccountType::RegularAccountUpdatableCode,
+    )
+    .unwrap();
+    // merging the component MAST turns the syscall into a regular call, so the unmerged MAST of
+    // the component is used instead
+    let code = AccountCode::from_parts(
+        Arc::new(component.mast_forest().clone()),
+        code.procedures().to_vec(),
+    );
+    let Err(TransactionExecutorError::UnknownKernelProcedure { code_commitment, procedure_root }) =
+        executor.verify_kernel_calls(&code)
+    else {
+        panic!("expected an unknown kernel procedure");
+    };
+    assert_eq!(code_commitment, code.commitment());
+    assert!(!executor.kernel_procedure_roots().contains(&procedure_root));
+}
+
+#[test]
+fn transaction_executor_pinned_stdlib() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let component = AccountComponent::compile(
+        "
+        use.std::math::u64
+        export.add_u64
+            exec.u64::wrapping_add
+        end
+        ",
+        TransactionKernel::assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let code = AccountCode::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+        .unwrap();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    assert_eq!(executor.stdlib_version(), *Library::from(StdLibrary::default()).digest());
+    executor.check_stdlib_compatibility(&code).unwrap();
+    executor.check_stdlib_compatibility(tx_context.account().code()).unwrap();
+
+    // code compiled against the bundled standard library is flagged when a different one is pinned
+    let stdlib = ::assembly::Assembler::default()
+        .assemble_library([Module::parser(ModuleKind::Library)
+            .parse_str(
+                LibraryPath::new("std::math::u64").unwrap(),
+                "export.wrapping_add drop end",
+                &DefaultSourceManager::default(),
+            )
+            .unwrap()])
+        .unwrap();
+    let executor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_stdlib(stdlib.clone());
+    assert_eq!(executor.stdlib_version(), *stdlib.digest());
+    assert!(matches!(
+        executor.check_stdlib_compatibility(&code),
+        Err(TransactionExecutorError::StdlibVersionMismatch { code_commitment, .. })
+            if code_commitment == code.commitment()
+    ));
+
+    // compiling against the pinned library resolves the mismatch
+    let component = AccountComponent::compile(
+        "
+        use.std::math::u64
+        export.add_u64
+            exec.u64::wrapping_add
+        end
+        ",
+        TransactionKernel::assembler_with_stdlib(stdlib),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let code = AccountCode::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+        .unwrap();
+    executor.check_stdlib_compatibility(&code).unwrap();
+
+    // procedures of other libraries which have not been loaded are not attributed to the stdlib
+    let library = ::assembly::Assembler::default()
+        .assemble_library([Module::parser(ModuleKind::Library)
+            .parse_str(
+                LibraryPath::new("custom::math").unwrap(),
+                "export.double dup add end",
+                &DefaultSourceManager::default(),
+            )
+            .unwrap()])
+        .unwrap();
+    let component = AccountComponent::compile(
+        "
+        use.custom::math
+        export.double
+            exec.math::double
+        end
+        ",
+        TransactionKernel::assembler().with_library(&library).unwrap(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let code = AccountCode::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+        .unwrap();
+    assert!(matches!(
+        executor.check_stdlib_compatibility(&code),
+        Err(TransactionExecutorError::MissingProcedure { code_commitment, .. })
+            if code_commitment == code.commitment()
+    ));
+}
+
+#[test]
+fn transaction_executor_intermediate_states() {
+    let account = TransactionContextBuilder::with_standard_account(ONE).build().account().clone();
+    let assembler = TransactionKernel::testing_assembler_with_mock_account();
+
+    // every note increments the nonce of the account
+    let notes = [1_u8, 2]
+        .into_iter()
+        .map(|seed| {
+            NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([seed; 32]))
+                .code("use.test::account begin push.1 call.account::incr_nonce drop end")
+                .build(&assembler)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let tx_context =
+        TransactionContextBuilder::with_standard_account(ONE).input_notes(notes).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let (executed_transaction, states) = executor
+        .execute_transaction_with_intermediate_states(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    // one state per consumed note, in execution order, followed by the final state
+    assert_eq!(states.len(), note_ids.len() + 1);
+    for (i, ((origin, hash), note_id)) in states.iter().zip(note_ids.iter()).enumerate() {
+        assert_eq!(*origin, AccountStateOrigin::Note(*note_id));
+
+        let expected = Account::from_parts(
+            account.id(),
+            account.vault().clone(),
+            account.storage().clone(),
+            account.code().clone(),
+            account.nonce() + Felt::from(i as u32 + 1),
+        );
+        assert_eq!(*hash, expected.hash());
+    }
+    assert_eq!(
+        *states.last().unwrap(),
+        (AccountStateOrigin::Transaction, executed_transaction.final_account().hash())
+    );
+
+    // capturing intermediate states does not affect the executed transaction
+    let expected = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    assert_eq!(executed_transaction.id(), expected.id());
+}
+
+#[test]
+fn note_builder_targeting_account_code() {
+    let account = TransactionContextBuilder::with_standard_account(ONE).build().account().clone();
+    let assembler = TransactionKernel::testing_assembler_with_mock_account();
+
+    // a note script calling a procedure of the account can be built and consumed by the account
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([1; 32]))
+        .code("use.test::account begin push.1 call.account::incr_nonce drop end")
+        .targeting(account.code())
+        .build(&assembler)
+        .unwrap();
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note])
+        .build();
+    tx_context.execute().unwrap();
+
+    // a note script calling a procedure the account does not export is rejected
+    let code = "
+        proc.foo
+            push.1 drop
+        end
+
+        begin
+            call.foo
+        end
+    ";
+    let err = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([2; 32]))
+        .code(code)
+        .targeting(account.code())
+        .build(&assembler)
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        NoteError::DisallowedProcedureCall(root) if !account.code().has_procedure(root)
+    ));
+}
+
+#[test]
+fn note_script_compiled_for_targets() {
+    let account = TransactionContextBuilder::with_standard_account(ONE).build().account().clone();
+    let assembler = TransactionKernel::testing_assembler_with_mock_account();
+    let library = AccountCode::mock_library(TransactionKernel::testing_assembler());
+    let procedure_root = |name: &str| {
+        library
+            .exports()
+            .find(|export| export.name.as_str() == name)
+            .map(|export| library.mast_forest()[library.get_export_node_id(export)].digest())
+            .unwrap()
+    };
+    let incr_nonce = procedure_root("incr_nonce");
+    let set_item = procedure_root("set_item");
+
+    let target = NoteTarget::new(account.code()).with_callable_procedure(incr_nonce).unwrap();
+
+    // a note script calling a callable procedure compiles and can be consumed by the account
+    let code = "use.test::account begin push.1 call.account::incr_nonce drop end";
+    let script =
+        NoteScript::compile_for_targets(code, assembler.clone(), &[target.clone()]).unwrap();
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([1; 32]))
+        .code(code)
+        .build(&assembler)
+        .unwrap();
+    assert_eq!(note.script().hash(), script.hash());
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note])
+        .build();
+    tx_context.execute().unwrap();
+
+    // calling a procedure of the account which is not marked callable is rejected
+    let code = "use.test::account begin call.account::set_item end";
+    assert_eq!(
+        NoteScript::compile_for_targets(code, assembler.clone(), &[target]),
+        Err(NoteError::ProcedureNotCallable(set_item))
+    );
+}
+
+#[test]
+fn note_is_likely_consumable_by() {
+    let account = TransactionContextBuilder::with_standard_account(ONE).build().account().clone();
+    let other_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let assembler = TransactionKernel::testing_assembler_with_mock_account();
+    let code = "use.test::account begin push.1 call.account::incr_nonce drop end";
+    let local_tag = NoteTag::from_account_id(account.id(), NoteExecutionMode::Local).unwrap();
+
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([1; 32]))
+        .code(code)
+        .tag(local_tag.into())
+        .build(&assembler)
+        .unwrap();
+    assert!(note.is_likely_consumable_by(account.id(), account.code()));
+
+    // notes calling procedures the account does not export are not consumable
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([2; 32]))
+        .code("proc.foo push.1 drop end begin call.foo end")
+        .tag(local_tag.into())
+        .build(&assembler)
+        .unwrap();
+    assert!(!note.is_likely_consumable_by(account.id(), account.code()));
+
+    // notes targeting another account are not consumable
+    let tag = NoteTag::from_account_id(other_account_id, NoteExecutionMode::Network).unwrap();
+    let note = NoteBuilder::new(account.id(), ChaCha20Rng::from_seed([3; 32]))
+        .code(code)
+        .tag(tag.into())
+        .build(&assembler)
+        .unwrap();
+    assert!(!note.is_likely_consumable_by(account.id(), account.code()));
+    assert!(note.is_likely_consumable_by(other_account_id, account.code()));
+}
+
+#[test]
+fn transaction_executor_note_script_whitelist() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let input_notes = tx_context.tx_inputs().input_notes();
+    let note_ids = input_notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // whitelist the scripts of all notes but the last one
+    let rejected_note = input_notes.get_note(input_notes.num_notes() - 1).note();
+    let whitelist: ScriptWhitelist = input_notes
+        .iter()
+        .map(|note| note.note().script().hash())
+        .filter(|root| *root != rejected_note.script().hash())
+        .collect();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_note_script_whitelist(whitelist.clone());
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TransactionExecutorError::NoteScriptNotWhitelisted { note_id, script_root }
+            if note_id == Some(rejected_note.id()) && script_root == rejected_note.script().hash()
+    ));
+
+    // once the missing script is whitelisted, the transaction executes
+    let mut whitelist = whitelist;
+    assert!(whitelist.insert(rejected_note.script().hash()));
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_note_script_whitelist(whitelist);
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+}
+
+#[test]
+fn transaction_executor_compile_note_script() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+
+    // by default, note scripts with any root can be compiled
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let note_script = executor.compile_note_script(DEFAULT_NOTE_CODE, &[]).unwrap();
+    let root = note_script.hash();
+    assert!(matches!(
+        executor.compile_note_script("begin call.foo end", &[]),
+        Err(TransactionExecutorError::NoteScriptCompilationFailed(_))
+    ));
+
+    // with a whitelist, only scripts with whitelisted roots can be compiled
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_note_script_whitelist(ScriptWhitelist::default());
+    assert_eq!(
+        executor.compile_note_script(DEFAULT_NOTE_CODE, &[]).unwrap_err(),
+        TransactionExecutorError::NoteScriptNotWhitelisted { note_id: None, script_root: root }
+    );
+    let executor = TransactionExecutor::new(Arc::new(tx_context), None)
+        .with_note_script_whitelist(ScriptWhitelist::new([root]));
+    assert_eq!(executor.compile_note_script(DEFAULT_NOTE_CODE, &[]).unwrap().hash(), root);
+}
+
+#[test]
+fn transaction_executor_retains_advice_recorder() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let (executed_transaction, advice_recorder) = executor
+        .execute_transaction_retaining_recorder(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    // the retained recorder reproduces the advice witness of the executed transaction
+    let (advice_witness, ..) = advice_recorder.finalize();
+    let (_, _, tx_witness, _) = executed_transaction.into_parts();
+    assert_eq!(advice_witness.stack(), tx_witness.advice_witness.stack());
+    assert_eq!(
+        advice_witness.merkle_store().inner_nodes().count(),
+        tx_witness.advice_witness.merkle_store().inner_nodes().count()
+    );
+}
+
+#[test]
+fn transaction_executor_final_stack() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let (tx_witness, final_stack) = executor
+        .execute_transaction_with_stack(
+            account_id,
+            block_ref,
+            &note_ids,
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+
+    // the labeled words match the outputs of the executed transaction
+    assert_eq!(
+        final_stack.output_notes_commitment(),
+        executed_transaction.output_notes().commitment()
+    );
+    assert_eq!(final_stack.final_account_hash(), executed_transaction.final_account().hash());
+    // the mock notes do not set an expiration delta
+    assert_eq!(final_stack.expiration_block_num(), u32::MAX);
+    assert_eq!(
+        TransactionKernel::parse_output_stack(final_stack.stack_outputs()).unwrap(),
+        (
+            final_stack.final_account_hash(),
+            final_stack.output_notes_commitment(),
+            final_stack.expiration_block_num()
+        )
+    );
+
+    // the witness is the one of the executed transaction
+    assert_eq!(tx_witness, TransactionWitness::from(executed_transaction));
+}
+
+#[test]
+fn final_account_roots_match_raw_components() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let mut final_account = tx_context.account().clone();
+    let executed_transaction = tx_context.execute().unwrap();
+
+    final_account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    // the roots recomputed from the raw final account contents match the ones output by the
+    // kernel
+    let assets = final_account.vault().assets().collect::<Vec<_>>();
+    assert_eq!(
+        compute_vault_root(&assets).unwrap(),
+        executed_transaction.final_account().vault_root()
+    );
+    assert_eq!(
+        compute_storage_commitment(final_account.storage().slots()),
+        executed_transaction.final_account().storage_commitment()
+    );
+    assert_eq!(final_account.hash(), executed_transaction.final_account().hash());
+}
+
+#[test]
+fn transaction_vector_is_deterministic() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executed_tx_1 = tx_context.clone().execute().unwrap();
+    let executed_tx_2 = tx_context.execute().unwrap();
+
+    let vector = dump_transaction_vector(&executed_tx_1);
+    assert_eq!(vector, dump_transaction_vector(&executed_tx_2));
+    assert_eq!(vector.to_json(), dump_transaction_vector(&executed_tx_2).to_json());
+
+    assert_eq!(vector.account_id, executed_tx_1.account_id());
+    assert_eq!(vector.input_notes_commitment, executed_tx_1.input_notes().commitment());
+    assert_eq!(vector.final_account_hash, executed_tx_1.final_account().hash());
+    assert_eq!(vector.program_root, TransactionKernel::main().hash());
+
+    let json = vector.to_json();
+    assert!(json.starts_with(&format!("{{\"account_id\":\"{}\"", vector.account_id.to_hex())));
+    assert!(json.contains(&format!("\"program_root\":\"{}\"", vector.program_root.to_hex())));
+    assert!(json.ends_with('}'));
+}
+
+#[test]
+fn test_send_note_proc() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved_with_account_vault_delta()
+        .build();
+
+    let executor =
+        TransactionExecutor::new(Arc::new(tx_context.clone()), None).with_debug_mode(true);
+    let account_id = tx_context.tx_inputs().account().id();
+
+    // removed assets
+    let removed_asset_1 = FungibleAsset::mock(FUNGIBLE_ASSET_AMOUNT / 2);
+    let removed_asset_2 = Asset::Fungible(
+        FungibleAsset::new(
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().expect("id is valid"),
+            FUNGIBLE_ASSET_AMOUNT,
+        )
+        .expect("asset is valid"),
+    );
+    let removed_asset_3 =
+        NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &NON_FUNGIBLE_ASSET_DATA);
+
+    let tag = NoteTag::from_account_id(
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
+        NoteExecutionMode::Local,
+    )
+    .unwrap();
+    let aux = Felt::new(27);
+    let note_type = NoteType::Private;
+
+    assert_eq!(tag.validate(note_type), Ok(tag));
+
+    // prepare the asset vector to be removed for each test variant
+    let assets_matrix = vec![
+        vec![],
+        vec![removed_asset_1],
+        vec![removed_asset_1, removed_asset_2],
+        vec![removed_asset_1, removed_asset_2, removed_asset_3],
+    ];
+
+    for removed_assets in assets_matrix {
+        // Prepare the string containing the procedures required for adding assets to the note.
+        // Depending on the number of the assets to remove, the resulting string will be extended
+        // with the corresponding number of procedure "blocks"
+        let mut assets_to_remove = String::new();
+        for asset in removed_assets.iter() {
+            assets_to_remove.push_str(&format!(
+                "\n
+            # prepare the stack for the next call
+            dropw
+
+            # push the asset to be removed
+            push.{ASSET}
+            # => [ASSET, note_idx, GARBAGE(11)]
+
+            call.wallet::move_asset_to_note
+            # => [ASSET, note_idx, GARBAGE(11)]\n",
+                ASSET = prepare_word(&asset.into())
+            ))
+        }
+
+        let tx_script_src = format!(
+            "\
+            use.miden::contracts::wallets::basic->wallet
+            use.miden::tx
+            use.test::account
+
+            ## TRANSACTION SCRIPT
+            ## ========================================================================================
+            begin
+                # prepare the values for note creation
+                push.1.2.3.4      # recipient
+                push.1            # note_execution_hint (NoteExecutionHint::Always)
+                push.{note_type}  # note_type
+                push.{aux}        # aux
+                push.{tag}        # tag
+                # => [tag, aux, note_type, RECIPIENT, ...]
+
+                # pad the stack with zeros before calling the `create_note`.
+                padw padw swapdw
+                # => [tag, aux, execution_hint, note_type, RECIPIENT, PAD(8) ...]
+
+                call.wallet::create_note
+                # => [note_idx, GARBAGE(15)]
+
+                movdn.4
+                # => [GARBAGE(4), note_idx, GARBAGE(11)]
+
+                {assets_to_remove}
+
+                dropw dropw dropw dropw
+
+                ## Update the account nonce
+                ## ------------------------------------------------------------------------------------
+                push.1 call.account::incr_nonce drop
+                # => []
+            end
+        ",
+            note_type = note_type as u8,
+        );
+
+        let tx_script = TransactionScript::compile(
+            tx_script_src,
+            [],
+            TransactionKernel::testing_assembler_with_mock_account(),
+        )
+        .unwrap();
+        let tx_args = TransactionArgs::new(
+            Some(tx_script),
+            None,
+            tx_context.tx_args().advice_inputs().clone().map,
+        );
+
+        let block_ref = tx_context.tx_inputs().block_header().block_num();
+        let note_ids = tx_context
+            .tx_inputs()
+            .input_notes()
+            .iter()
+            .map(|note| note.id())
+            .collect::<Vec<_>>();
+
+        // expected delta
+        // --------------------------------------------------------------------------------------------
+        // execute the transaction and get the witness
+        let executed_transaction =
             executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
 
         // nonce delta
@@ -832,17 +2984,94 @@ fn prove_witness_and_verify() {
         .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
         .unwrap();
     let executed_transaction_id = executed_transaction.id();
+    let prepared_id = executed_transaction.prepared_id();
+    let summary = executed_transaction.clone().into_summary();
+    assert_eq!(summary.id(), executed_transaction_id);
+    assert_eq!(tx_context.tx_inputs().prepared_id(), prepared_id);
 
     let proof_options = ProvingOptions::default();
     let prover = LocalTransactionProver::new(proof_options);
     let proven_transaction = prover.prove(executed_transaction.into()).unwrap();
 
-    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+    assert_eq!(proven_transaction.prepared_id(), prepared_id);
+    assert_eq!(proven_transaction.clone().into_summary(), summary);
+    assert_eq!(TransactionSummary::read_from_bytes(&summary.to_bytes()).unwrap(), summary);
+
+    let serialized_transaction = proven_transaction.to_bytes();
+    let proven_transaction = ProvenTransaction::read_from_bytes(&serialized_transaction).unwrap();
+
+    // the JSON encoding represents the same transaction
+    let json = proven_transaction.to_json();
+    assert_eq!(ProvenTransaction::from_json(&json).unwrap(), proven_transaction);
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction.clone()).is_ok());
+    assert_eq!(verifier.verify_batch(&[proven_transaction.clone()]), vec![Ok(())]);
+
+    // the proof can be bound to the expected account transition
+    let initial = tx_context.account().hash();
+    let final_ = proven_transaction.account_update().final_state_hash();
+    assert!(proven_transaction.matches_transition(initial, final_));
+    assert!(verifier
+        .verify_with_expected_transition(proven_transaction.clone(), Some((initial, final_)))
+        .is_ok());
+
+    let err = verifier
+        .verify_with_expected_transition(
+            proven_transaction.clone(),
+            Some((Digest::default(), final_)),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionVerifierError::UnexpectedAccountTransition {
+            actual_initial_hash: initial,
+            actual_final_hash: final_,
+        }
+    );
+
+    // the consumed notes can be checked to be unspent against a nullifier tree
+    let nullifiers = proven_transaction.get_nullifiers().collect::<Vec<_>>();
+    let mut nullifier_tree = Smt::default();
+    nullifier_tree.insert(Digest::from([ONE; 4]), [ONE, ZERO, ZERO, ZERO]);
+    let open = |tree: &Smt| {
+        nullifiers
+            .iter()
+            .map(|nullifier| NullifierProof::new(*nullifier, tree.open(&nullifier.inner())))
+            .collect::<Vec<_>>()
+    };
+    let root = nullifier_tree.root();
+    assert_eq!(
+        proven_transaction.verify_with_nullifier_root(root, open(&nullifier_tree)),
+        Ok(())
+    );
+    assert_eq!(
+        proven_transaction.verify_with_nullifier_root(root, open(&nullifier_tree)[1..].to_vec()),
+        Err(ProvenTransactionError::MissingNullifierProof(nullifiers[0]))
+    );
+    assert_eq!(
+        proven_transaction.verify_with_nullifier_root(Digest::default(), open(&nullifier_tree)),
+        Err(ProvenTransactionError::InvalidNullifierProof(nullifiers[0]))
+    );
+
+    nullifier_tree.insert(nullifiers[1].inner(), [ONE, ZERO, ZERO, ZERO]);
+    assert_eq!(
+        proven_transaction.verify_with_nullifier_root(nullifier_tree.root(), open(&nullifier_tree)),
+        Err(ProvenTransactionError::NullifierAlreadySpent(nullifiers[1]))
+    );
 
-    let serialized_transaction = proven_transaction.to_bytes();
-    let proven_transaction = ProvenTransaction::read_from_bytes(&serialized_transaction).unwrap();
-    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
-    assert!(verifier.verify(proven_transaction).is_ok());
+    // the transition can be transferred and verified without the state of the account
+    let transition = AccountStateTransition::new(initial, final_, proven_transaction.clone());
+    let transition = AccountStateTransition::read_from_bytes(&transition.to_bytes()).unwrap();
+    assert_eq!(transition.account_id(), account_id);
+    assert!(transition.verify(&verifier).is_ok());
+
+    let transition = AccountStateTransition::new(initial, Digest::default(), proven_transaction);
+    assert!(matches!(
+        transition.verify(&verifier),
+        Err(TransactionVerifierError::UnexpectedAccountTransition { .. })
+    ));
 }
 
 // TEST TRANSACTION SCRIPT
@@ -1003,3 +3232,567 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account nonce should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(4));
 }
+
+// HELPERS
+// ================================================================================================
+
+/// A [DataStore] which always returns the same transaction inputs.
+struct StaticDataStore(TransactionInputs);
+
+#[maybe_async_trait]
+impl DataStore for StaticDataStore {
+    #[maybe_async]
+    fn get_transaction_inputs(
+        &self,
+        _account_id: AccountId,
+        _block_ref: BlockNumber,
+        _notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        Ok(self.0.clone())
+    }
+}
+
+impl KvStore for BTreeMap<String, Vec<u8>> {
+    fn get(&self, key: &str) -> Option<Vec<u8>> {
+        BTreeMap::get(self, key).cloned()
+    }
+
+    fn put(&mut self, key: &str, value: Vec<u8>) {
+        self.insert(key.into(), value);
+    }
+}
+
+#[test]
+fn transaction_executor_script_transform() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let compile = |source: &str| {
+        TransactionScript::compile(
+            source,
+            [],
+            TransactionKernel::testing_assembler_with_mock_account(),
+        )
+        .unwrap()
+    };
+    let failing_script = compile("begin push.0 assert end");
+    let wrapped_script = compile(
+        "
+        use.test::account
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+    );
+    let tx_args = TransactionArgs::new(
+        Some(failing_script.clone()),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    assert!(executor
+        .execute_transaction(account_id, block_ref, &[], tx_args.clone())
+        .is_err());
+
+    // the transform replaces the script before the transaction is executed
+    let failing_root = failing_script.hash();
+    let transformed_script = wrapped_script.clone();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_script_transform(move |tx_script| {
+            assert_eq!(tx_script.hash(), failing_root);
+            transformed_script.clone()
+        });
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &[], tx_args).unwrap();
+
+    assert_eq!(executed_transaction.final_account().nonce(), tx_context.account().nonce() + ONE);
+    let witness = TransactionWitness::from(executed_transaction);
+    assert_eq!(witness.tx_args.tx_script().unwrap().hash(), wrapped_script.hash());
+}
+
+#[test]
+fn transaction_executor_expected_tx_script_root() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let tx_script = TransactionScript::compile(
+        "
+        use.test::account
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+    let tx_args = TransactionArgs::new(
+        Some(tx_script.clone()),
+        None,
+        tx_context.tx_args().advice_inputs().clone().map,
+    );
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    // a script with the expected root is executed
+    let context = ExecutionContext::new().with_expected_tx_script_root(tx_script.hash());
+    executor
+        .execute_transaction_with_context(account_id, block_ref, &[], tx_args.clone(), &context)
+        .unwrap();
+
+    // a script with a different root is rejected before execution
+    let expected = Digest::default();
+    let context = ExecutionContext::new().with_expected_tx_script_root(expected);
+    let err = executor
+        .execute_transaction_with_context(account_id, block_ref, &[], tx_args, &context)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::ScriptRootMismatch { expected, actual: tx_script.hash() }
+    );
+
+    // transactions without a script are not affected
+    executor
+        .execute_transaction_with_context(
+            account_id,
+            block_ref,
+            &[],
+            TransactionArgs::default(),
+            &context,
+        )
+        .unwrap();
+}
+
+#[test]
+fn transaction_executor_tx_script_cache() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let account_library = AccountCode::mock_library(TransactionKernel::testing_assembler());
+    let source = "
+        use.test::account
+        begin
+            push.1 call.account::incr_nonce drop
+        end
+    ";
+
+    // the script cannot be linked without the library of the account
+    let err = executor.compile_tx_script(source, [], &[]).unwrap_err();
+    assert!(matches!(err, TransactionExecutorError::TransactionScriptCompilationFailed(_)));
+
+    let script = executor.compile_tx_script(source, [], &[account_library.clone()]).unwrap();
+    let script_inputs = [([ONE; 4], vec![ONE])];
+    let script_with_inputs =
+        executor.compile_tx_script(source, script_inputs, &[account_library]).unwrap();
+    assert_eq!(script_with_inputs.hash(), script.hash());
+    assert_eq!(script_with_inputs.inputs().len(), 1);
+
+    let stats = executor.tx_script_cache_stats();
+    assert_eq!((stats.hits(), stats.misses()), (1, 2));
+
+    // the cached script is executed like a freshly compiled one
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &[], TransactionArgs::with_tx_script(script))
+        .unwrap();
+    assert_eq!(executed_transaction.final_account().nonce(), tx_context.account().nonce() + ONE);
+}
+
+#[test]
+fn transaction_executor_per_note_cycle_limit() {
+    let assembler = TransactionKernel::testing_assembler_with_mock_account();
+    let account_id = TransactionContextBuilder::with_standard_account(ONE).build().account().id();
+
+    // one of the notes takes considerably more cycles than the other one
+    let notes = [(1_u8, ""), (2, "repeat.2000 push.1 drop end")]
+        .into_iter()
+        .map(|(seed, prefix)| {
+            NoteBuilder::new(account_id, ChaCha20Rng::from_seed([seed; 32]))
+                .code(format!(
+                    "use.test::account begin {prefix} push.1 call.account::incr_nonce drop end"
+                ))
+                .build(&assembler)
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let tx_context =
+        TransactionContextBuilder::with_standard_account(ONE).input_notes(notes).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executed_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_tracing()
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let mut note_cycles = executed_transaction.measurements().note_execution.clone();
+    note_cycles.sort_by_key(|(_, cycles)| *cycles);
+    let [(_, cheap_cycles), (expensive_note, expensive_cycles)] = note_cycles[..] else {
+        panic!("expected cycle measurements for two notes");
+    };
+    let (cheap_cycles, expensive_cycles) = (cheap_cycles as u32, expensive_cycles as u32);
+    assert!(cheap_cycles < expensive_cycles);
+
+    // the budget is reset for every note, so a limit covering the most expensive note suffices
+    TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_per_note_cycle_limit(expensive_cycles)
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    let err = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_per_note_cycle_limit(cheap_cycles)
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TransactionExecutorError::NoteCycleLimitExceeded(note_id) if note_id == expensive_note
+    ));
+}
+
+#[test]
+fn executed_transaction_storage_accesses() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    // the script reads a value slot and a map slot, and writes another value slot
+    let tx_script_src = format!(
+        "
+        use.test::account
+        begin
+            push.{STORAGE_INDEX_1} call.account::get_item dropw
+            push.{MAP_KEY} push.{STORAGE_INDEX_2} call.account::get_map_item dropw
+            push.1.2.3.4 push.{STORAGE_INDEX_0} call.account::set_item dropw dropw
+            push.1 call.account::incr_nonce drop
+        end
+        ",
+        MAP_KEY = prepare_word(&STORAGE_LEAVES_2[0].0),
+    );
+    let tx_script = TransactionScript::compile(
+        tx_script_src,
+        [],
+        TransactionKernel::testing_assembler_with_mock_account(),
+    )
+    .unwrap();
+
+    let executed_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .execute_transaction(account_id, block_ref, &[], TransactionArgs::with_tx_script(tx_script))
+        .unwrap();
+    let accesses = executed_transaction.measurements().storage_accesses();
+    assert_eq!(
+        accesses.reads().iter().copied().collect::<Vec<_>>(),
+        [STORAGE_INDEX_1, STORAGE_INDEX_2]
+    );
+    assert_eq!(accesses.writes().iter().copied().collect::<Vec<_>>(), [STORAGE_INDEX_0]);
+
+    // a transaction only reading the written slot conflicts with this one
+    let mut other = StorageAccessSet::default();
+    other.record_read(STORAGE_INDEX_2);
+    assert!(!accesses.conflicts_with(&other));
+    other.record_read(STORAGE_INDEX_0);
+    assert!(accesses.conflicts_with(&other));
+    assert!(other.conflicts_with(accesses));
+}
+
+#[test]
+fn transaction_executor_unordered_notes() {
+    let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let note_a = NoteBuilder::new(sender, ChaCha20Rng::from_seed([1_u8; 32]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+    let note_b = NoteBuilder::new(sender, ChaCha20Rng::from_seed([2_u8; 32]))
+        .code("begin push.1 drop end")
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+    let note_c = NoteBuilder::new(sender, ChaCha20Rng::from_seed([3_u8; 32]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note_a.clone(), note_b.clone(), note_c.clone()])
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    // the mock data store returns the notes in the order of its inputs, which may differ from the
+    // order in which they were added
+    let input_notes = tx_context.tx_inputs().input_notes();
+    let note_ids = input_notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // notes sharing a script are grouped, and the order is stable
+    let notes = [&note_a, &note_b, &note_c].map(|note| InputNote::unauthenticated(note.clone()));
+    let optimized = optimize_note_order(InputNotes::new(notes.to_vec()).unwrap());
+    let optimized_ids = optimized.iter().map(|note| note.id()).collect::<Vec<_>>();
+    assert_eq!(optimized_ids, [note_a.id(), note_c.id(), note_b.id()]);
+    let reoptimized = optimize_note_order(optimized.clone());
+    assert_eq!(reoptimized, optimized);
+    let optimized_ids = optimize_note_order(input_notes.clone())
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    // by default, notes are consumed in the requested order
+    let executed_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let consumed_ids = executed_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    assert_eq!(consumed_ids, note_ids);
+
+    let unordered_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_unordered_notes()
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let consumed_ids = unordered_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    assert_eq!(consumed_ids, optimized_ids);
+    assert_eq!(
+        unordered_transaction.final_account().hash(),
+        executed_transaction.final_account().hash()
+    );
+}
+
+#[test]
+fn transaction_executor_block_timestamp_override() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let account_id = tx_context.account().id();
+    let block_header = *tx_context.tx_inputs().block_header();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let timestamp = block_header.timestamp() + 3600;
+    let executed_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .with_block_timestamp(timestamp)
+        .execute_transaction(
+            account_id,
+            block_header.block_num(),
+            &note_ids,
+            tx_context.tx_args().clone(),
+        )
+        .unwrap();
+    let executed_header = executed_transaction.block_header();
+    assert_eq!(executed_header.timestamp(), timestamp);
+    assert_eq!(executed_header.block_num(), block_header.block_num());
+    assert_eq!(executed_header.note_root(), block_header.note_root());
+    let block_ref = executed_header.hash();
+    assert_ne!(block_ref, block_header.hash());
+
+    // the overridden header is committed to by the transaction and can be proven and verified
+    let proven_transaction = LocalTransactionProver::new(ProvingOptions::default())
+        .prove(executed_transaction.into())
+        .unwrap();
+    assert_eq!(proven_transaction.block_ref(), block_ref);
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
+#[test]
+fn transaction_executor_error_word_formatting() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+    let tx_inputs = tx_context.tx_inputs();
+    let input_notes_commitment = tx_inputs.input_notes().commitment();
+    let err = TransactionExecutorError::ExecuteTransactionProgramFailed(
+        ExecutionError::AdviceMapKeyNotFound(input_notes_commitment.into()),
+    );
+
+    // the public inputs of the transaction are labeled
+    let formatter = PublicInputsFormatter::new(tx_inputs);
+    let message = err.format_with(&formatter);
+    assert!(message.contains(&format!("INPUT_NOTES_COMMITMENT({})", input_notes_commitment)));
+
+    // unknown words are left unchanged
+    let unknown = TransactionExecutorError::ExecuteTransactionProgramFailed(
+        ExecutionError::AdviceMapKeyNotFound([ONE; 4]),
+    );
+    assert_eq!(unknown.format_with(&formatter), unknown.to_string());
+
+    // domain-specific labels can be provided via closures or added to the default formatter
+    let message =
+        unknown.format_with(&|word: &Word| (*word == [ONE; 4]).then(|| "ONES".to_string()));
+    assert!(message.contains(&format!("ONES({})", Digest::from([ONE; 4]))));
+    let message = unknown.format_with(&formatter.with_label([ONE; 4], "ONES"));
+    assert!(message.contains(&format!("ONES({})", Digest::from([ONE; 4]))));
+}
+
+#[test]
+fn transaction_executor_consumes_note_without_assets() {
+    // the note writes its inputs into the first storage slot of the account
+    let note_script_src = format!(
+        "
+        use.miden::note
+        use.test::account
+
+        begin
+            push.0 exec.note::get_inputs drop drop
+            padw push.0 mem_loadw
+            push.{STORAGE_INDEX_0} call.account::set_item dropw dropw
+            push.1 call.account::incr_nonce drop
+        end
+        "
+    );
+    let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let message = [Felt::new(9), Felt::new(8), Felt::new(7), Felt::new(6)];
+    let input_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([5_u8; 32]))
+        .note_inputs(message)
+        .unwrap()
+        .code(note_script_src)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap();
+    assert!(input_note.assets().is_empty());
+    assert_eq!(input_note.assets().commitment(), Digest::default());
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![input_note.clone()])
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executed_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .execute_transaction(account_id, block_ref, &[input_note.id()], TransactionArgs::default())
+        .unwrap();
+    let account_delta = executed_transaction.account_delta();
+    assert!(account_delta.vault().is_empty());
+    assert_eq!(account_delta.storage().values().get(&STORAGE_INDEX_0), Some(&message));
+    assert_eq!(executed_transaction.input_notes().num_notes(), 1);
+}
+
+#[test]
+fn transaction_executor_consumes_internal_notes() {
+    // the internal note is created by the script of the input note
+    let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let internal_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([6_u8; 32]))
+        .build(&TransactionKernel::testing_assembler())
+        .unwrap();
+    let tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local).unwrap();
+    let note_script_src = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            padw padw swapdw
+            call.wallet::create_note
+            dropw dropw dropw dropw
+        end
+        ",
+        recipient = prepare_word(&internal_note.recipient().digest().into()),
+        execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+    );
+    let input_note = NoteBuilder::new(sender, ChaCha20Rng::from_seed([7_u8; 32]))
+        .code(note_script_src)
+        .build(&TransactionKernel::testing_assembler_with_mock_account())
+        .unwrap();
+    let context = ExecutionContext::new().with_internal_notes(vec![internal_note.clone()]);
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![input_note.clone()])
+        .build();
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let executed_transaction = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .execute_transaction_with_context(
+            account_id,
+            block_ref,
+            &[input_note.id()],
+            TransactionArgs::default(),
+            &context,
+        )
+        .unwrap();
+    let input_note_ids = executed_transaction
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+    assert_eq!(input_note_ids, [input_note.id(), internal_note.id()]);
+    assert!(executed_transaction.input_notes().get_note(1).proof().is_none());
+    assert!(executed_transaction
+        .output_notes()
+        .iter()
+        .any(|note| note.id() == internal_note.id()));
+
+    // internal notes are subject to the same checks as the other input notes
+    let whitelisted_context = context
+        .clone()
+        .with_note_script_whitelist(ScriptWhitelist::new([input_note.script().hash()]));
+    let err = TransactionExecutor::new(Arc::new(tx_context.clone()), None)
+        .execute_transaction_with_context(
+            account_id,
+            block_ref,
+            &[input_note.id()],
+            TransactionArgs::default(),
+            &whitelisted_context,
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        TransactionExecutorError::NoteScriptNotWhitelisted { note_id, .. }
+            if note_id == Some(internal_note.id())
+    ));
+
+    // without the note creating it, the internal note cannot be consumed
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let err = TransactionExecutor::new(Arc::new(tx_context), None)
+        .execute_transaction_with_context(
+            account_id,
+            block_ref,
+            &[],
+            TransactionArgs::default(),
+            &context,
+        )
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::UncreatedInternalNote(internal_note.id()));
+}
+
+#[test]
+fn transaction_executor_warmup() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let account = tx_context.account().clone();
+    let codes = [(account.id(), account.code().clone())];
+
+    let mut executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+    let report = executor.warmup(&codes);
+    let [warmup] = report.codes() else {
+        panic!("expected a single warmup result");
+    };
+    assert_eq!(warmup.account_id(), account.id());
+    assert_eq!(warmup.code_commitment(), account.code().commitment());
+    assert!(!warmup.already_loaded());
+    assert!(warmup.duration() <= report.duration());
+    assert_eq!(report.failures().count(), 0);
+
+    // loading the same code again is skipped
+    let report = executor.warmup(&codes);
+    assert!(report.codes()[0].already_loaded());
+
+    // transactions can be executed against the account as before
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    executor
+        .execute_transaction(account.id(), block_ref, &[], TransactionArgs::default())
+        .unwrap();
+}
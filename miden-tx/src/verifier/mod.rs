@@ -1,9 +1,14 @@
+use alloc::vec::Vec;
+
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
+use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo, Digest};
 use miden_verifier::verify;
 
 use super::TransactionVerifierError;
 
+mod state_transition;
+pub use state_transition::AccountStateTransition;
+
 // TRANSACTION VERIFIER
 // ================================================================================================
 
@@ -31,6 +36,66 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        self.verify_with_expected_transition(transaction, None)
+    }
+
+    /// Verifies the provided [ProvenTransaction]s against the transaction kernel.
+    ///
+    /// The transactions are verified independently of each other, and the result of verifying a
+    /// transaction is returned at the same position as the transaction in the provided slice.
+    /// When the `concurrent` feature is enabled, the transactions are verified in parallel.
+    pub fn verify_batch(
+        &self,
+        transactions: &[ProvenTransaction],
+    ) -> Vec<Result<(), TransactionVerifierError>> {
+        #[cfg(feature = "concurrent")]
+        {
+            let thread_count = std::thread::available_parallelism().map_or(1, |v| v.get());
+            let chunk_size = transactions.len().div_ceil(thread_count).max(1);
+
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = transactions
+                    .chunks(chunk_size)
+                    .map(|chunk| scope.spawn(move || self.verify_all(chunk)))
+                    .collect();
+
+                // joining the threads in the order of the chunks preserves the input ordering
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().expect("verification thread panicked"))
+                    .collect()
+            })
+        }
+
+        #[cfg(not(feature = "concurrent"))]
+        self.verify_all(transactions)
+    }
+
+    /// Verifies the provided [ProvenTransaction] against the transaction kernel, optionally
+    /// checking that it transitions its account between the specified initial and final states.
+    ///
+    /// The expected transition is provided as a tuple of the initial and final account state
+    /// hashes. For transactions executed against new accounts, the initial hash is [ZERO; 4].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - An expected transition is provided and the transaction does not match it.
+    /// - Transaction verification fails.
+    /// - The security level of the verified proof is insufficient.
+    pub fn verify_with_expected_transition(
+        &self,
+        transaction: ProvenTransaction,
+        expected_transition: Option<(Digest, Digest)>,
+    ) -> Result<(), TransactionVerifierError> {
+        if let Some((initial, final_)) = expected_transition {
+            if !transaction.matches_transition(initial, final_) {
+                return Err(TransactionVerifierError::UnexpectedAccountTransition {
+                    actual_initial_hash: transaction.account_update().init_state_hash(),
+                    actual_final_hash: transaction.account_update().final_state_hash(),
+                });
+            }
+        }
+
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
             transaction.account_id(),
@@ -63,4 +128,18 @@ impl TransactionVerifier {
 
         Ok(())
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sequentially verifies the provided transactions.
+    fn verify_all(
+        &self,
+        transactions: &[ProvenTransaction],
+    ) -> Vec<Result<(), TransactionVerifierError>> {
+        transactions
+            .iter()
+            .map(|transaction| self.verify(transaction.clone()))
+            .collect()
+    }
 }
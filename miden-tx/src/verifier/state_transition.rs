@@ -0,0 +1,90 @@
+use miden_objects::{
+    accounts::AccountId,
+    transaction::ProvenTransaction,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Digest,
+};
+
+use super::TransactionVerifier;
+use crate::TransactionVerifierError;
+
+// ACCOUNT STATE TRANSITION
+// ================================================================================================
+
+/// A claim that an account moved from one state to another, backed by a [ProvenTransaction].
+///
+/// A state transition can be verified without access to the state of the account: verification
+/// checks only that the proof of the transaction is valid and that its public inputs match the
+/// claimed initial and final account state hashes. Thus, the cost of verification does not depend
+/// on the size of the account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountStateTransition {
+    initial_hash: Digest,
+    final_hash: Digest,
+    transaction: ProvenTransaction,
+}
+
+impl AccountStateTransition {
+    /// Returns a new [AccountStateTransition] claiming that the specified transaction moved its
+    /// account from the state with the initial hash to the state with the final hash.
+    ///
+    /// For transactions executed against new accounts, the initial hash is [ZERO; 4].
+    pub fn new(initial_hash: Digest, final_hash: Digest, transaction: ProvenTransaction) -> Self {
+        Self { initial_hash, final_hash, transaction }
+    }
+
+    /// Returns the ID of the account whose state transition is claimed.
+    pub fn account_id(&self) -> AccountId {
+        self.transaction.account_id()
+    }
+
+    /// Returns the claimed hash of the account state before the transition.
+    pub fn initial_hash(&self) -> Digest {
+        self.initial_hash
+    }
+
+    /// Returns the claimed hash of the account state after the transition.
+    pub fn final_hash(&self) -> Digest {
+        self.final_hash
+    }
+
+    /// Returns the transaction backing this state transition.
+    pub fn transaction(&self) -> &ProvenTransaction {
+        &self.transaction
+    }
+
+    /// Verifies this state transition using the provided [TransactionVerifier].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The transaction does not transition its account between the claimed states.
+    /// - The proof of the transaction is invalid.
+    /// - The security level of the proof is lower than required by the verifier.
+    pub fn verify(&self, verifier: &TransactionVerifier) -> Result<(), TransactionVerifierError> {
+        verifier.verify_with_expected_transition(
+            self.transaction.clone(),
+            Some((self.initial_hash, self.final_hash)),
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountStateTransition {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.initial_hash.write_into(target);
+        self.final_hash.write_into(target);
+        self.transaction.write_into(target);
+    }
+}
+
+impl Deserializable for AccountStateTransition {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let initial_hash = Digest::read_from(source)?;
+        let final_hash = Digest::read_from(source)?;
+        let transaction = ProvenTransaction::read_from(source)?;
+
+        Ok(Self::new(initial_hash, final_hash, transaction))
+    }
+}
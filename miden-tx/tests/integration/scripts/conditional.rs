@@ -0,0 +1,135 @@
+use alloc::sync::Arc;
+
+use miden_lib::notes::ConditionalNoteTemplate;
+use miden_objects::{
+    accounts::{
+        account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
+        },
+        Account, AccountId,
+    },
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{NoteExecutionMode, NoteTag, NoteType},
+    transaction::TransactionArgs,
+    Felt,
+};
+use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor, TransactionExecutorError};
+
+use crate::{
+    build_default_auth_script, get_account_with_basic_authenticated_wallet,
+    get_new_pk_and_authenticator,
+};
+
+// CONDITIONAL TESTS
+// ===============================================================================================
+// We want to test the conditional consumption script, which allows the creator of the note to
+// restrict the account consuming the note and the earliest block at which the note can be
+// consumed.
+#[test]
+fn conditional_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    // Create the consumer and the malicious account
+    let consumer_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (consumer_pub_key, consumer_falcon_auth) = get_new_pk_and_authenticator();
+    let consumer_account =
+        get_account_with_basic_authenticated_wallet(consumer_account_id, consumer_pub_key, None);
+
+    let malicious_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2).unwrap();
+    let (malicious_pub_key, malicious_falcon_auth) = get_new_pk_and_authenticator();
+    let malicious_account =
+        get_account_with_basic_authenticated_wallet(malicious_account_id, malicious_pub_key, None);
+
+    // --------------------------------------------------------------------------------------------
+    // Create notes (Note: Current block height is 4, the notes are created in block 3)
+    let tag = NoteTag::from_account_id(consumer_account_id, NoteExecutionMode::Local).unwrap();
+    let template =
+        ConditionalNoteTemplate::new(vec![fungible_asset]).with_consumer(consumer_account_id);
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    let note_unlocked = template
+        .clone()
+        .with_earliest_block(4)
+        .build_note(sender_account_id, 3, NoteType::Public, tag, Felt::new(0), &mut rng)
+        .unwrap();
+    let note_locked = template
+        .with_earliest_block(5)
+        .build_note(sender_account_id, 3, NoteType::Public, tag, Felt::new(0), &mut rng)
+        .unwrap();
+
+    let tx_args = TransactionArgs::with_tx_script(build_default_auth_script());
+
+    // CONSTRUCT AND EXECUTE TX (Unlocked note - Consumer Account Success)
+    // --------------------------------------------------------------------------------------------
+    let tx_context_1 = TransactionContextBuilder::new(consumer_account.clone())
+        .input_notes(vec![note_unlocked.clone()])
+        .build();
+    let executor_1 = TransactionExecutor::new(
+        Arc::new(tx_context_1.clone()),
+        Some(consumer_falcon_auth.clone()),
+    );
+
+    let block_ref_1 = tx_context_1.tx_inputs().block_header().block_num();
+    let note_ids_1 = tx_context_1.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction_1 = executor_1
+        .execute_transaction(consumer_account_id, block_ref_1, &note_ids_1, tx_args.clone())
+        .unwrap();
+
+    // Assert that the consumer account received the funds and the nonce increased by 1
+    let consumer_account_after: Account = Account::from_parts(
+        consumer_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        consumer_account.storage().clone(),
+        consumer_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction_1.final_account().hash(), consumer_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Unlocked note - Malicious Account Failure)
+    // --------------------------------------------------------------------------------------------
+    let tx_context_2 = TransactionContextBuilder::new(malicious_account)
+        .input_notes(vec![note_unlocked])
+        .build();
+    let executor_2 =
+        TransactionExecutor::new(Arc::new(tx_context_2.clone()), Some(malicious_falcon_auth));
+
+    let block_ref_2 = tx_context_2.tx_inputs().block_header().block_num();
+    let note_ids_2 = tx_context_2.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction_2 = executor_2.execute_transaction(
+        malicious_account_id,
+        block_ref_2,
+        &note_ids_2,
+        tx_args.clone(),
+    );
+    assert!(executed_transaction_2.is_err());
+
+    // CONSTRUCT AND EXECUTE TX (Locked note - Consumer Account Failure)
+    // --------------------------------------------------------------------------------------------
+    let tx_context_3 = TransactionContextBuilder::new(consumer_account)
+        .input_notes(vec![note_locked.clone()])
+        .build();
+    let executor_3 =
+        TransactionExecutor::new(Arc::new(tx_context_3.clone()), Some(consumer_falcon_auth));
+
+    let block_ref_3 = tx_context_3.tx_inputs().block_header().block_num();
+    let note_ids_3 = tx_context_3.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // The note is rejected based on its execution hint before its script is executed
+    let executed_transaction_3 =
+        executor_3.execute_transaction(consumer_account_id, block_ref_3, &note_ids_3, tx_args);
+    assert!(matches!(
+        executed_transaction_3,
+        Err(TransactionExecutorError::NoteNotYetSpendable { earliest_block: 5, .. })
+    ));
+}
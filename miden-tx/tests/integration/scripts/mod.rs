@@ -1,3 +1,4 @@
+mod conditional;
 mod faucet;
 mod p2id;
 mod p2idr;
@@ -333,6 +333,30 @@ impl AccountId {
         format!("0x{:016x}", self.0.as_int())
     }
 
+    /// Returns a bech32 encoding of this account ID with the specified human-readable prefix.
+    ///
+    /// The account ID is encoded as its 8 big-endian bytes, and the encoding includes a checksum
+    /// which allows detecting mistyped IDs. The prefix is expected to be a non-empty lowercase
+    /// ASCII string (e.g., "mid").
+    pub fn to_bech32(&self, hrp: &str) -> String {
+        super::bech32::encode(hrp, &self.0.as_int().to_be_bytes())
+    }
+
+    /// Decodes an account ID from a bech32 string with the specified human-readable prefix.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not a well-formed bech32 string.
+    /// - The prefix of the string is not `hrp`.
+    /// - The checksum of the string is invalid.
+    /// - The decoded data is not a valid account ID.
+    pub fn from_bech32(value: &str, hrp: &str) -> Result<AccountId, AccountError> {
+        let bytes: [u8; 8] = super::bech32::decode(hrp, value)?.try_into().map_err(|_| {
+            AccountError::Bech32InvalidString("data part must encode exactly 8 bytes".into())
+        })?;
+        AccountId::try_from(u64::from_be_bytes(bytes))
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -620,11 +644,13 @@ pub mod testing {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::String;
+
     use miden_crypto::utils::{Deserializable, Serializable};
 
     use super::{
-        testing::*, AccountId, AccountStorageMode, AccountType, ACCOUNT_ISFAUCET_MASK,
-        ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET, NON_FUNGIBLE_FAUCET,
+        testing::*, AccountError, AccountId, AccountStorageMode, AccountType,
+        ACCOUNT_ISFAUCET_MASK, ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET, NON_FUNGIBLE_FAUCET,
         REGULAR_ACCOUNT_IMMUTABLE_CODE, REGULAR_ACCOUNT_UPDATABLE_CODE,
     };
 
@@ -659,6 +685,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_id_bech32_roundtrip() {
+        for account_id in [
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ] {
+            let acc = AccountId::try_from(account_id).expect("Valid account ID");
+            let encoded = acc.to_bech32("mid");
+            assert!(encoded.starts_with("mid1"));
+            assert_eq!(acc, AccountId::from_bech32(&encoded, "mid").unwrap());
+            assert_eq!(acc, AccountId::from_bech32(&encoded.to_uppercase(), "mid").unwrap());
+        }
+    }
+
+    #[test]
+    fn test_account_id_bech32_errors() {
+        let acc = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let encoded = acc.to_bech32("mid");
+
+        // wrong prefix
+        assert_eq!(
+            AccountId::from_bech32(&encoded, "mtst").unwrap_err(),
+            AccountError::Bech32InvalidHrp {
+                expected: "mtst".into(),
+                actual: "mid".into()
+            }
+        );
+
+        // mistyped character
+        let mut mistyped = encoded.clone().into_bytes();
+        let last = mistyped.len() - 1;
+        mistyped[last] = if mistyped[last] == b'q' { b'p' } else { b'q' };
+        let mistyped = String::from_utf8(mistyped).unwrap();
+        assert_eq!(
+            AccountId::from_bech32(&mistyped, "mid").unwrap_err(),
+            AccountError::Bech32InvalidChecksum
+        );
+
+        // malformed strings
+        assert!(matches!(
+            AccountId::from_bech32("midqqqqqq", "mid"),
+            Err(AccountError::Bech32InvalidString(_))
+        ));
+        assert!(matches!(
+            AccountId::from_bech32(&encoded.replacen('m', "M", 1), "mid"),
+            Err(AccountError::Bech32InvalidString(_))
+        ));
+        assert!(matches!(
+            AccountId::from_bech32("mid1bbbbbbbbbbbbbbbbbbb", "mid"),
+            Err(AccountError::Bech32InvalidString(_))
+        ));
+    }
+
     #[test]
     fn test_account_id_serde() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -0,0 +1,149 @@
+//! A minimal implementation of the bech32 encoding as specified in
+//! [BIP-173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki).
+
+use alloc::{string::String, vec::Vec};
+
+use crate::AccountError;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The alphabet used to encode 5-bit groups of data.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The character separating the human-readable part from the data part.
+const SEPARATOR: char = '1';
+
+/// The number of characters used to encode the checksum.
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Generator coefficients of the BCH code used to compute the checksum.
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+// ENCODING / DECODING
+// ================================================================================================
+
+/// Encodes the provided bytes as a bech32 string with the specified human-readable part.
+///
+/// The human-readable part is expected to be a non-empty lowercase ASCII string.
+pub(super) fn encode(hrp: &str, data: &[u8]) -> String {
+    let data = convert_bits(data, 8, 5, true).expect("padding is allowed");
+    let checksum = create_checksum(hrp, &data);
+
+    let mut result = String::with_capacity(hrp.len() + 1 + data.len() + CHECKSUM_LENGTH);
+    result.push_str(hrp);
+    result.push(SEPARATOR);
+    result.extend(data.iter().chain(checksum.iter()).map(|&d| CHARSET[d as usize] as char));
+    result
+}
+
+/// Decodes the provided bech32 string and returns its data bytes.
+///
+/// # Errors
+/// Returns an error if:
+/// - The string is not a well-formed bech32 string (e.g., it contains invalid or mixed-case
+///   characters, or misses the separator).
+/// - The human-readable part of the string is not `expected_hrp`.
+/// - The checksum of the string is invalid.
+pub(super) fn decode(expected_hrp: &str, value: &str) -> Result<Vec<u8>, AccountError> {
+    let invalid = |msg: &str| AccountError::Bech32InvalidString(msg.into());
+
+    if value.chars().any(|c| c.is_ascii_lowercase())
+        && value.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err(invalid("string contains mixed-case characters"));
+    }
+    let value = value.to_ascii_lowercase();
+
+    let (hrp, data) = value.rsplit_once(SEPARATOR).ok_or_else(|| invalid("separator not found"))?;
+    if hrp != expected_hrp {
+        return Err(AccountError::Bech32InvalidHrp {
+            expected: expected_hrp.into(),
+            actual: hrp.into(),
+        });
+    }
+    if data.len() < CHECKSUM_LENGTH {
+        return Err(invalid("data part is too short"));
+    }
+
+    let data = data
+        .bytes()
+        .map(|c| CHARSET.iter().position(|&d| d == c).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| invalid("data part contains invalid characters"))?;
+
+    if polymod(&[hrp_expand(hrp), data.clone()].concat()) != 1 {
+        return Err(AccountError::Bech32InvalidChecksum);
+    }
+
+    let data = &data[..data.len() - CHECKSUM_LENGTH];
+    convert_bits(data, 5, 8, false).ok_or_else(|| invalid("data part has invalid padding"))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn polymod(values: &[u8]) -> u32 {
+    let mut checksum = 1_u32;
+    for &value in values {
+        let top = checksum >> 25;
+        checksum = ((checksum & 0x1ffffff) << 5) ^ u32::from(value);
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn hrp_expand(hrp: &str) -> Vec<u8> {
+    let mut result = Vec::with_capacity(hrp.len() * 2 + 1);
+    result.extend(hrp.bytes().map(|c| c >> 5));
+    result.push(0);
+    result.extend(hrp.bytes().map(|c| c & 0x1f));
+    result
+}
+
+fn create_checksum(hrp: &str, data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let mut values = hrp_expand(hrp);
+    values.extend_from_slice(data);
+    values.extend_from_slice(&[0; CHECKSUM_LENGTH]);
+    let polymod = polymod(&values) ^ 1;
+
+    let mut checksum = [0; CHECKSUM_LENGTH];
+    for (i, value) in checksum.iter_mut().enumerate() {
+        *value = ((polymod >> (5 * (5 - i))) & 0x1f) as u8;
+    }
+    checksum
+}
+
+/// Regroups the provided data from `from`-bit groups into `to`-bit groups.
+///
+/// Returns `None` if the padding is not allowed and the data contains leftover non-zero bits or a
+/// leftover group of `from` bits or more.
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+    let mut acc = 0_u32;
+    let mut bits = 0_u32;
+    let max_value = (1_u32 << to) - 1;
+    let mut result = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+    for &value in data {
+        acc = (acc << from) | u32::from(value);
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            result.push(((acc >> bits) & max_value) as u8);
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            result.push(((acc << (to - bits)) & max_value) as u8);
+        }
+    } else if bits >= from || ((acc << (to - bits)) & max_value) != 0 {
+        return None;
+    }
+
+    Some(result)
+}
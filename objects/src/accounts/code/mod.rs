@@ -1,6 +1,11 @@
-use alloc::{collections::BTreeSet, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    sync::Arc,
+    vec::Vec,
+};
 
-use vm_core::mast::MastForest;
+use vm_core::mast::{MastForest, MastNode};
 
 use super::{
     AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
@@ -196,6 +201,16 @@ impl AccountCode {
         self.procedures.iter().any(|procedure| procedure.mast_root() == &mast_root)
     }
 
+    /// Returns true if the interface of this account code is a superset of the interface of the
+    /// provided code, i.e., if every procedure of `old` is also defined in this account code.
+    ///
+    /// Procedures are compared by their MAST roots only; procedures defined in this account code
+    /// but not in `old` are ignored. Thus, replacing `old` with this code does not break callers
+    /// relying on any of the procedures of `old`.
+    pub fn is_compatible_superset_of(&self, old: &AccountCode) -> bool {
+        old.procedure_roots().all(|root| self.has_procedure(root))
+    }
+
     /// Returns information about the procedure at the specified index.
     ///
     /// # Panics
@@ -213,6 +228,95 @@ impl AccountCode {
             .position(|r| r == &root)
     }
 
+    // CALL GRAPH ANALYSIS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the static call graph of the procedures of this account code.
+    ///
+    /// The returned map contains an entry for every procedure of this account code, mapping its
+    /// MAST root to the MAST roots of the procedures it invokes via `call` instructions or via
+    /// references to external procedures, in the order in which they are first encountered.
+    /// Callees are not limited to the procedures of this account code; syscalls and dynamic calls
+    /// are omitted since their targets are the transaction kernel and unknown, respectively.
+    pub fn call_graph(&self) -> BTreeMap<Digest, Vec<Digest>> {
+        self.procedure_roots()
+            .map(|root| {
+                let callees = self.procedure_callees(root).0;
+                (root, callees)
+            })
+            .collect()
+    }
+
+    /// Returns true if any procedure of this account code may, directly or indirectly, invoke
+    /// itself.
+    ///
+    /// This is the case if the [call graph](Self::call_graph()) of this account code contains a
+    /// cycle, or if any procedure of this account code performs a dynamic call. Since the
+    /// target of a dynamic call is determined at runtime, it may be any procedure of the account,
+    /// including the caller.
+    pub fn has_recursive_calls(&self) -> bool {
+        let mut graph = BTreeMap::new();
+        for root in self.procedure_roots() {
+            let (callees, has_dynamic_calls) = self.procedure_callees(root);
+            if has_dynamic_calls {
+                return true;
+            }
+            graph.insert(root, callees);
+        }
+        has_cycle(&graph)
+    }
+
+    /// Returns the MAST roots of the procedures invoked by the procedure with the specified MAST
+    /// root, together with a flag indicating whether the procedure performs dynamic calls.
+    fn procedure_callees(&self, root: Digest) -> (Vec<Digest>, bool) {
+        let mut callees = Vec::new();
+        // the body of a procedure which is not in the MAST forest is unknown, and thus it is
+        // treated as performing dynamic calls
+        let Some(entrypoint) = self.mast.find_procedure_root(root) else {
+            return (callees, true);
+        };
+
+        let mut has_dynamic_calls = false;
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![entrypoint];
+        while let Some(node_id) = stack.pop() {
+            if !visited.insert(node_id) {
+                continue;
+            }
+
+            let callee = match &self.mast[node_id] {
+                MastNode::Join(node) => {
+                    stack.push(node.second());
+                    stack.push(node.first());
+                    None
+                },
+                MastNode::Split(node) => {
+                    stack.push(node.on_false());
+                    stack.push(node.on_true());
+                    None
+                },
+                MastNode::Loop(node) => {
+                    stack.push(node.body());
+                    None
+                },
+                MastNode::Call(node) if !node.is_syscall() => {
+                    Some(self.mast[node.callee()].digest())
+                },
+                MastNode::External(node) => Some(node.digest()),
+                MastNode::Dyn(_) => {
+                    has_dynamic_calls = true;
+                    None
+                },
+                MastNode::Block(_) | MastNode::Call(_) => None,
+            };
+            if let Some(callee) = callee.filter(|callee| !callees.contains(callee)) {
+                callees.push(callee);
+            }
+        }
+
+        (callees, has_dynamic_calls)
+    }
+
     /// Converts procedure information in this [AccountCode] into a vector of field elements.
     ///
     /// This is done by first converting each procedure into 8 field elements as follows:
@@ -299,6 +403,45 @@ fn procedures_as_elements(procedures: &[AccountProcedureInfo]) -> Vec<Felt> {
         .collect()
 }
 
+/// Returns true if the provided call graph contains a cycle.
+///
+/// Procedures without an entry in the graph are treated as leaves.
+fn has_cycle(graph: &BTreeMap<Digest, Vec<Digest>>) -> bool {
+    // procedures whose callees have all been explored, and procedures on the current call path
+    let mut explored = BTreeSet::new();
+    let mut on_path = BTreeSet::new();
+
+    for &root in graph.keys() {
+        // each stack entry is a procedure together with the index of its next callee to explore
+        let mut stack = vec![(root, 0)];
+        while let Some((procedure, next_callee)) = stack.pop() {
+            if next_callee == 0 {
+                if explored.contains(&procedure) {
+                    continue;
+                }
+                on_path.insert(procedure);
+            }
+
+            let callees = graph.get(&procedure).map(Vec::as_slice).unwrap_or_default();
+            match callees.get(next_callee) {
+                Some(&callee) => {
+                    if on_path.contains(&callee) {
+                        return true;
+                    }
+                    stack.push((procedure, next_callee + 1));
+                    stack.push((callee, 0));
+                },
+                None => {
+                    on_path.remove(&procedure);
+                    explored.insert(procedure);
+                },
+            }
+        }
+    }
+
+    false
+}
+
 /// Computes the commitment to the given procedures
 fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
     let elements = procedures_as_elements(procedures);
@@ -311,13 +454,15 @@ fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
 #[cfg(test)]
 mod tests {
 
+    use alloc::collections::BTreeMap;
+
     use assembly::Assembler;
     use vm_core::Word;
 
-    use super::{AccountCode, Deserializable, Serializable};
+    use super::{has_cycle, AccountCode, Deserializable, Serializable};
     use crate::{
         accounts::{code::build_procedure_commitment, AccountComponent, AccountType, StorageSlot},
-        AccountError,
+        AccountError, Digest, Felt,
     };
 
     #[test]
@@ -335,6 +480,26 @@ mod tests {
         assert_eq!(procedure_commitment, code.commitment())
     }
 
+    #[test]
+    fn test_account_code_compatible_superset() {
+        let library = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let extended_library = Assembler::default()
+            .assemble_library(["export.foo add end export.bar sub end"])
+            .unwrap();
+        let code = |library| {
+            let component =
+                AccountComponent::new(library, vec![]).unwrap().with_supports_all_types();
+            AccountCode::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+                .unwrap()
+        };
+        let old = code(library);
+        let new = code(extended_library);
+
+        assert!(new.is_compatible_superset_of(&old));
+        assert!(old.is_compatible_superset_of(&old));
+        assert!(!old.is_compatible_superset_of(&new));
+    }
+
     #[test]
     fn test_account_code_procedure_offset_out_of_bounds() {
         let code1 = "export.foo add end";
@@ -369,4 +534,47 @@ mod tests {
 
         assert!(matches!(err, AccountError::StorageOffsetOutOfBounds { actual: 256, .. }))
     }
+
+    #[test]
+    fn test_account_code_call_graph() {
+        let code = |source| {
+            let library = Assembler::default().assemble_library([source]).unwrap();
+            let component =
+                AccountComponent::new(library, vec![]).unwrap().with_supports_all_types();
+            AccountCode::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+                .unwrap()
+        };
+
+        assert!(!AccountCode::mock().has_recursive_calls());
+
+        let code_with_calls = code("export.bar add end export.foo call.bar push.1 drop end");
+        let graph = code_with_calls.call_graph();
+        let bar = code_with_calls.procedure_roots().find(|root| graph[root].is_empty()).unwrap();
+        let foo = code_with_calls.procedure_roots().find(|root| *root != bar).unwrap();
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph[&foo], vec![bar]);
+        assert!(!code_with_calls.has_recursive_calls());
+
+        // the target of a dynamic call may be any procedure, including the caller
+        let code_with_dyncall = code("export.foo add end export.bar dynexec end");
+        assert!(code_with_dyncall.has_recursive_calls());
+    }
+
+    #[test]
+    fn test_call_graph_cycles() {
+        let [a, b, c, d] = [1, 2, 3, 4].map(|i| Digest::from([Felt::new(i); 4]));
+
+        // a -> b -> c, a -> c, c -> d
+        let mut graph = BTreeMap::from([(a, vec![b, c]), (b, vec![c]), (c, vec![d]), (d, vec![])]);
+        assert!(!has_cycle(&graph));
+
+        // a -> b -> c -> a
+        graph.insert(c, vec![d, a]);
+        assert!(has_cycle(&graph));
+
+        // d -> d
+        graph.insert(c, vec![d]);
+        graph.insert(d, vec![d]);
+        assert!(has_cycle(&graph));
+    }
 }
@@ -10,7 +10,10 @@ use super::{
     AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     Word,
 };
-use crate::Digest;
+use crate::{
+    accounts::{AccountStorage, StorageMap, StorageSlot},
+    Digest,
+};
 
 // ACCOUNT STORAGE DELTA
 // ================================================================================================
@@ -40,6 +43,46 @@ impl AccountStorageDelta {
         Ok(result)
     }
 
+    /// Returns a storage delta describing the changes needed to transition `initial` storage into
+    /// `final_storage`.
+    ///
+    /// Value slots are compared directly. For map slots, only maps whose roots differ are
+    /// inspected, and the resulting [StorageMapDelta] contains only the entries which differ
+    /// between the two maps, with removed entries set to [EMPTY_WORD].
+    ///
+    /// # Errors
+    /// Returns an error if the two storages have different numbers or types of slots.
+    pub fn from_storages(
+        initial: &AccountStorage,
+        final_storage: &AccountStorage,
+    ) -> Result<Self, AccountDeltaError> {
+        if initial.slots().len() != final_storage.slots().len() {
+            return Err(AccountDeltaError::IncompatibleStorageLayouts);
+        }
+
+        let mut delta = Self::default();
+        for (idx, (initial_slot, final_slot)) in
+            initial.slots().iter().zip(final_storage.slots()).enumerate()
+        {
+            let idx = idx as u8;
+            match (initial_slot, final_slot) {
+                (StorageSlot::Value(initial_value), StorageSlot::Value(final_value)) => {
+                    if initial_value != final_value {
+                        delta.set_item(idx, *final_value);
+                    }
+                },
+                (StorageSlot::Map(initial_map), StorageSlot::Map(final_map)) => {
+                    if initial_map.root() != final_map.root() {
+                        delta.maps.insert(idx, StorageMapDelta::from_maps(initial_map, final_map));
+                    }
+                },
+                _ => return Err(AccountDeltaError::IncompatibleStorageLayouts),
+            }
+        }
+
+        Ok(delta)
+    }
+
     /// Returns a reference to the updated values in this storage delta.
     pub fn values(&self) -> &BTreeMap<u8, Word> {
         &self.values
@@ -203,6 +246,28 @@ impl StorageMapDelta {
         Self(map)
     }
 
+    /// Returns a storage map delta containing the entries which differ between the `initial` and
+    /// the `final_map` storage maps.
+    ///
+    /// Entries present only in `initial` are recorded as cleared (i.e., set to [EMPTY_WORD]).
+    pub fn from_maps(initial: &StorageMap, final_map: &StorageMap) -> Self {
+        let initial_entries: BTreeMap<Digest, Word> = initial.entries().copied().collect();
+
+        let mut delta = BTreeMap::new();
+        for (key, value) in final_map.entries() {
+            if initial_entries.get(key) != Some(value) {
+                delta.insert(*key, *value);
+            }
+        }
+        for key in initial_entries.keys() {
+            if final_map.get_value(key) == EMPTY_WORD {
+                delta.insert(*key, EMPTY_WORD);
+            }
+        }
+
+        Self(delta)
+    }
+
     /// Returns a reference to the updated leaves in this storage map delta.
     pub fn leaves(&self) -> &BTreeMap<Digest, Word> {
         &self.0
@@ -306,7 +371,9 @@ impl Deserializable for StorageMapDelta {
 mod tests {
     use super::{AccountStorageDelta, Deserializable, Serializable};
     use crate::{
-        accounts::StorageMapDelta, testing::storage::AccountStorageDeltaBuilder, ONE, ZERO,
+        accounts::{AccountStorage, StorageMap, StorageMapDelta, StorageSlot},
+        testing::storage::AccountStorageDeltaBuilder,
+        AccountDeltaError, Digest, ONE, ZERO,
     };
 
     #[test]
@@ -344,6 +411,61 @@ mod tests {
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn storage_delta_from_storages() {
+        let key_1 = Digest::from([ONE, ZERO, ZERO, ZERO]);
+        let key_2 = Digest::from([ZERO, ONE, ZERO, ZERO]);
+        let key_3 = Digest::from([ZERO, ZERO, ONE, ZERO]);
+        let value = [ONE, ONE, ONE, ONE];
+
+        let initial_map = StorageMap::with_entries([(key_1, value), (key_2, value)]).unwrap();
+        let initial = AccountStorage::new(vec![
+            StorageSlot::Value(value),
+            StorageSlot::Map(initial_map.clone()),
+            StorageSlot::Value(value),
+        ])
+        .unwrap();
+
+        // unchanged storage results in an empty delta
+        let delta = AccountStorageDelta::from_storages(&initial, &initial).unwrap();
+        assert!(delta.is_empty());
+
+        // update a value slot, and update, remove and add map entries
+        let final_map =
+            StorageMap::with_entries([(key_1, [ONE, ONE, ONE, ZERO]), (key_3, value)]).unwrap();
+        let final_storage = AccountStorage::new(vec![
+            StorageSlot::Value([ZERO, ZERO, ZERO, ONE]),
+            StorageSlot::Map(final_map.clone()),
+            StorageSlot::Value(value),
+        ])
+        .unwrap();
+
+        let delta = AccountStorageDelta::from_storages(&initial, &final_storage).unwrap();
+        let expected_map_delta = StorageMapDelta::from_iters(
+            [key_2.into()],
+            [(key_1.into(), [ONE, ONE, ONE, ZERO]), (key_3.into(), value)],
+        );
+        assert_eq!(
+            delta,
+            AccountStorageDelta::from_iters(
+                [],
+                [(0, [ZERO, ZERO, ZERO, ONE])],
+                [(1, expected_map_delta.clone())]
+            )
+        );
+
+        // applying the map delta to the initial map yields the final map
+        let mut map = initial_map;
+        assert_eq!(map.apply_delta(&expected_map_delta), final_map.root());
+
+        // storages with different layouts cannot be compared
+        let other = AccountStorage::new(vec![StorageSlot::Value(value)]).unwrap();
+        assert_eq!(
+            AccountStorageDelta::from_storages(&initial, &other),
+            Err(AccountDeltaError::IncompatibleStorageLayouts)
+        );
+    }
+
     #[test]
     fn test_is_empty() {
         let storage_delta = AccountStorageDelta::default();
@@ -12,6 +12,8 @@ pub use account_id::{
 
 pub mod auth;
 
+mod bech32;
+
 pub use auth::AuthSecretKey;
 
 mod builder;
@@ -33,7 +35,10 @@ mod seed;
 pub use seed::{get_account_seed, get_account_seed_single};
 
 mod storage;
-pub use storage::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    compute_storage_commitment, AccountStorage, AccountStorageHeader, StorageMap, StorageSlot,
+    StorageSlotType,
+};
 
 mod header;
 pub use header::AccountHeader;
@@ -299,6 +304,18 @@ impl Account {
     pub fn vault_mut(&mut self) -> &mut AssetVault {
         &mut self.vault
     }
+
+    #[cfg(any(feature = "testing", test))]
+    /// Sets the nonce of this account to the specified value without checking that the nonce
+    /// increases.
+    ///
+    /// This allows constructing accounts in arbitrary states offline (e.g., resetting an account
+    /// to the new state); during transaction execution the nonce is managed by the transaction
+    /// kernel and updated via [Self::apply_delta()]. The hash of the account reflects the new
+    /// nonce.
+    pub fn set_nonce_unchecked(&mut self, nonce: Felt) {
+        self.nonce = nonce;
+    }
 }
 
 // SERIALIZATION
@@ -344,6 +361,9 @@ impl Deserializable for Account {
 ///
 /// Hash of an account is computed as hash(id, nonce, vault_root, storage_commitment,
 /// code_commitment). Computing the account hash requires 2 permutations of the hash function.
+///
+/// This is the same hash as returned by [Account::hash()], and can be used to compute it from the
+/// commitments to the account's components without constructing the full [Account].
 pub fn hash_account(
     id: AccountId,
     nonce: Felt,
@@ -389,11 +409,16 @@ mod tests {
     };
     use vm_processor::Digest;
 
-    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+    use super::{
+        compute_storage_commitment, hash_account, AccountDelta, AccountStorageDelta,
+        AccountVaultDelta,
+    };
     use crate::{
         accounts::{
-            Account, AccountComponent, AccountType, StorageMap, StorageMapDelta, StorageSlot,
+            Account, AccountComponent, AccountHeader, AccountType, StorageMap, StorageMapDelta,
+            StorageSlot,
         },
+        assets::compute_vault_root,
         testing::storage::{
             build_account, build_account_delta, build_assets, AccountStorageDeltaBuilder,
         },
@@ -413,6 +438,71 @@ mod tests {
         assert_eq!(deserialized, account);
     }
 
+    #[test]
+    fn hash_account_matches_account_hash() {
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let account =
+            build_account(vec![asset_0, asset_1], Felt::new(3), vec![StorageSlot::Value(word)]);
+
+        let hash = hash_account(
+            account.id(),
+            account.nonce(),
+            account.vault().commitment(),
+            account.storage().commitment(),
+            account.code().commitment(),
+        );
+        assert_eq!(hash, account.hash());
+    }
+
+    #[test]
+    fn account_hash_from_raw_components() {
+        let (asset_0, asset_1) = build_assets();
+        let storage_map = StorageMap::with_entries([(
+            Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]),
+            [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        )])
+        .unwrap();
+        let slots = vec![
+            StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+            StorageSlot::Map(storage_map),
+        ];
+        let account = build_account(vec![asset_0, asset_1], Felt::new(3), slots.clone());
+
+        // the roots computed from the raw components match the ones of the account
+        let vault_root = compute_vault_root(&[asset_1, asset_0]).unwrap();
+        let storage_commitment = compute_storage_commitment(&slots);
+        assert_eq!(vault_root, account.vault().commitment());
+        assert_eq!(storage_commitment, account.storage().commitment());
+        assert_eq!(
+            hash_account(
+                account.id(),
+                account.nonce(),
+                vault_root,
+                storage_commitment,
+                account.code().commitment()
+            ),
+            account.hash()
+        );
+
+        // duplicate assets are rejected
+        assert!(compute_vault_root(&[asset_0, asset_0]).is_err());
+    }
+
+    #[test]
+    fn test_serde_account_header() {
+        let (asset_0, _) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let account = build_account(vec![asset_0], Felt::new(1), vec![StorageSlot::Value(word)]);
+        let header = AccountHeader::from(&account);
+        assert_eq!(header.hash(), account.hash());
+
+        let serialized = header.to_bytes();
+        let deserialized = AccountHeader::read_from_bytes(&serialized).unwrap();
+        assert_eq!(deserialized, header);
+        assert_eq!(deserialized.hash(), account.hash());
+    }
+
     #[test]
     fn test_serde_account_delta() {
         let final_nonce = Felt::new(2);
@@ -562,6 +652,21 @@ mod tests {
         account.apply_delta(&account_delta).unwrap()
     }
 
+    #[test]
+    fn set_nonce_unchecked_updates_account_hash() {
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut account = build_account(vec![], Felt::new(2), vec![StorageSlot::Value(word)]);
+        let initial_hash = account.hash();
+
+        // the nonce can be decreased, and the hash reflects the new nonce
+        account.set_nonce_unchecked(Felt::new(1));
+        assert_eq!(account.nonce(), Felt::new(1));
+        assert_ne!(account.hash(), initial_hash);
+
+        account.set_nonce_unchecked(Felt::new(2));
+        assert_eq!(account.hash(), initial_hash);
+    }
+
     /// Tests that initializing code and storage from a component which does not support the given
     /// account type returns an error.
     #[test]
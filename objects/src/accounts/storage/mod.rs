@@ -27,9 +27,13 @@ pub use header::AccountStorageHeader;
 /// - [StorageSlot::Map]: contains a [StorageMap] which is a key-value map where both keys and
 ///   values are [Word]s. The value of a storage slot containing a map is the commitment to the
 ///   underlying map.
+///
+/// The commitment to the storage is maintained as the slots are updated, so reading it via
+/// [AccountStorage::commitment()] does not require re-hashing the slots.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorage {
     slots: Vec<StorageSlot>,
+    commitment: Digest,
 }
 
 impl AccountStorage {
@@ -52,7 +56,8 @@ impl AccountStorage {
             return Err(AccountError::StorageTooManySlots(num_slots as u64));
         }
 
-        Ok(Self { slots })
+        let commitment = compute_storage_commitment(&slots);
+        Ok(Self { slots, commitment })
     }
 
     /// Creates an [`AccountStorage`] from the provided components' storage slots.
@@ -94,7 +99,7 @@ impl AccountStorage {
 
     /// Returns a commitment to this storage.
     pub fn commitment(&self) -> Digest {
-        build_slots_commitment(&self.slots)
+        self.commitment
     }
 
     /// Converts storage slots of this account storage into a vector of field elements.
@@ -151,7 +156,21 @@ impl AccountStorage {
     ///
     /// # Errors:
     /// - If the updates violate storage constraints.
-    pub(super) fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+    pub fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+        // the delta may have been applied partially if it is invalid, so the commitment is
+        // updated in either case
+        let result = self.apply_delta_to_slots(delta);
+        self.commitment = compute_storage_commitment(&self.slots);
+
+        result
+    }
+
+    /// Applies the provided delta to the slots of this account storage without updating the
+    /// storage commitment.
+    ///
+    /// # Errors:
+    /// - If the updates violate storage constraints.
+    fn apply_delta_to_slots(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
         let len = self.slots.len() as u8;
 
         // update storage maps
@@ -171,7 +190,7 @@ impl AccountStorage {
 
         // update storage values
         for (&idx, &value) in delta.values().iter() {
-            self.set_item(idx, value)?;
+            self.update_value_slot(idx, value)?;
         }
 
         Ok(())
@@ -186,6 +205,19 @@ impl AccountStorage {
     /// - If the index is out of bounds
     /// - If the [StorageSlot] is not [StorageSlotType::Value]
     pub fn set_item(&mut self, index: u8, value: Word) -> Result<Word, AccountError> {
+        let old_value = self.update_value_slot(index, value)?;
+        self.commitment = compute_storage_commitment(&self.slots);
+
+        Ok(old_value)
+    }
+
+    /// Updates the value of the storage slot at the specified index without updating the storage
+    /// commitment.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Value]
+    fn update_value_slot(&mut self, index: u8, value: Word) -> Result<Word, AccountError> {
         // check if index is in bounds
         let num_slots = self.slots.len();
 
@@ -242,6 +274,7 @@ impl AccountStorage {
 
         // update the key-value pair in the map
         let old_value = storage_map.insert(key.into(), value);
+        self.commitment = compute_storage_commitment(&self.slots);
 
         Ok((old_root.into(), old_value))
     }
@@ -255,8 +288,13 @@ fn slots_as_elements(slots: &[StorageSlot]) -> Vec<Felt> {
     slots.iter().flat_map(|slot| slot.as_elements()).collect()
 }
 
-/// Computes the commitment to the given slots
-fn build_slots_commitment(slots: &[StorageSlot]) -> Digest {
+/// Returns the commitment to an account storage consisting of the provided slots.
+///
+/// This is the same commitment as returned by [AccountStorage::commitment()] for a storage with
+/// these slots, and can be used to recompute the storage commitment of an account (e.g., to verify
+/// its hash via [hash_account()](crate::accounts::hash_account)) without constructing the storage.
+/// The commitment to a map slot is computed from the root of the map.
+pub fn compute_storage_commitment(slots: &[StorageSlot]) -> Digest {
     let elements = slots_as_elements(slots);
     Hasher::hash_elements(&elements)
 }
@@ -298,9 +336,12 @@ impl Deserializable for AccountStorage {
 #[cfg(test)]
 mod tests {
     use super::{
-        build_slots_commitment, AccountStorage, Deserializable, Serializable, StorageMap, Word,
+        compute_storage_commitment, AccountStorage, Deserializable, Serializable, StorageMap, Word,
+    };
+    use crate::{
+        accounts::{AccountStorageDelta, StorageMapDelta, StorageSlot},
+        ONE, ZERO,
     };
-    use crate::accounts::StorageSlot;
 
     #[test]
     fn test_serde_account_storage() {
@@ -322,7 +363,36 @@ mod tests {
     #[test]
     fn test_account_storage_slots_commitment() {
         let storage = AccountStorage::mock();
-        let storage_slots_commitment = build_slots_commitment(storage.slots());
+        let storage_slots_commitment = compute_storage_commitment(storage.slots());
         assert_eq!(storage_slots_commitment, storage.commitment())
     }
+
+    #[test]
+    fn test_account_storage_commitment_is_maintained() {
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::default()),
+            StorageSlot::Map(StorageMap::default()),
+            StorageSlot::Value(Word::default()),
+        ])
+        .unwrap();
+
+        storage.set_item(0, [ONE, ZERO, ZERO, ZERO]).unwrap();
+        assert_eq!(storage.commitment(), compute_storage_commitment(storage.slots()));
+
+        storage.set_map_item(1, [ONE; 4], [ONE, ONE, ZERO, ZERO]).unwrap();
+        assert_eq!(storage.commitment(), compute_storage_commitment(storage.slots()));
+
+        // failed updates do not change the commitment
+        let commitment = storage.commitment();
+        assert!(storage.set_item(1, [ONE; 4]).is_err());
+        assert_eq!(storage.commitment(), commitment);
+
+        let delta = AccountStorageDelta::new(
+            [(2, [ONE; 4])].into(),
+            [(1, StorageMapDelta::new([([ONE; 4].into(), [ZERO; 4])].into()))].into(),
+        )
+        .unwrap();
+        storage.apply_delta(&delta).unwrap();
+        assert_eq!(storage.commitment(), compute_storage_commitment(storage.slots()));
+    }
 }
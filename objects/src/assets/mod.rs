@@ -14,7 +14,7 @@ mod token_symbol;
 pub use token_symbol::TokenSymbol;
 
 mod vault;
-pub use vault::AssetVault;
+pub use vault::{compute_vault_root, AssetVault, VaultUpdateProof};
 
 // ASSET
 // ================================================================================================
@@ -6,8 +6,8 @@ use super::{
 };
 use crate::{
     accounts::{AccountVaultDelta, NonFungibleDeltaAction},
-    crypto::merkle::Smt,
-    AssetVaultError, Digest,
+    crypto::merkle::{Smt, SmtProof},
+    AssetVaultError, Digest, Word,
 };
 // ASSET VAULT
 // ================================================================================================
@@ -182,6 +182,22 @@ impl AssetVault {
         Ok(asset)
     }
 
+    /// Adds the specified asset to the vault and returns a proof of the resulting vault update.
+    ///
+    /// # Errors
+    /// Returns the same errors as [Self::add_asset()].
+    pub fn add_asset_with_proof(
+        &mut self,
+        asset: Asset,
+    ) -> Result<VaultUpdateProof, AssetVaultError> {
+        let key: Digest = asset.vault_key().into();
+        let old = self.asset_tree.open(&key);
+        self.add_asset(asset)?;
+        let new = self.asset_tree.open(&key);
+
+        Ok(VaultUpdateProof { key, old, new })
+    }
+
     // REMOVE ASSET
     // --------------------------------------------------------------------------------------------
     /// Remove the specified asset from the vault.
@@ -203,6 +219,26 @@ impl AssetVault {
         }
     }
 
+    /// Removes the specified asset from the vault and returns a proof of the resulting vault
+    /// update.
+    ///
+    /// If the last of a fungible asset is removed, the asset is deleted from the vault and the
+    /// proof attests to the absence of the asset in the updated vault.
+    ///
+    /// # Errors
+    /// Returns the same errors as [Self::remove_asset()].
+    pub fn remove_asset_with_proof(
+        &mut self,
+        asset: Asset,
+    ) -> Result<VaultUpdateProof, AssetVaultError> {
+        let key: Digest = asset.vault_key().into();
+        let old = self.asset_tree.open(&key);
+        self.remove_asset(asset)?;
+        let new = self.asset_tree.open(&key);
+
+        Ok(VaultUpdateProof { key, old, new })
+    }
+
     /// Remove the specified fungible asset from the vault.
     ///
     /// # Errors
@@ -257,6 +293,82 @@ impl AssetVault {
     }
 }
 
+/// Returns the root of an asset vault containing the provided assets.
+///
+/// This is the same root as returned by [AssetVault::commitment()] for a vault with these assets,
+/// and can be used to recompute the vault root of an account (e.g., to verify its hash via
+/// [hash_account()](crate::accounts::hash_account)). The assets must be provided as they are
+/// stored in the vault, i.e., with a single fungible asset per faucet; the order of the assets
+/// does not affect the root.
+///
+/// # Errors
+/// Returns an error if the provided assets contain duplicate vault keys, i.e., the same
+/// non-fungible asset twice or two fungible assets issued by the same faucet.
+pub fn compute_vault_root(assets: &[Asset]) -> Result<Digest, AssetVaultError> {
+    AssetVault::new(assets).map(|vault| vault.commitment())
+}
+
+// VAULT UPDATE PROOF
+// ================================================================================================
+
+/// A proof of an update of a single asset in an [AssetVault].
+///
+/// The proof consists of openings of the vault key of the updated asset against the vault before
+/// and after the update. Since the update changes only the value stored under this key, both
+/// openings share the same Merkle path, and the proof can be verified against both the old and the
+/// new vault roots. Absent assets are represented by [Smt::EMPTY_VALUE].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VaultUpdateProof {
+    key: Digest,
+    old: SmtProof,
+    new: SmtProof,
+}
+
+impl VaultUpdateProof {
+    /// Returns the vault key of the updated asset.
+    pub fn key(&self) -> Digest {
+        self.key
+    }
+
+    /// Returns the value stored under the key before the update.
+    pub fn old_value(&self) -> Word {
+        self.old.get(&self.key).expect("opening is for the proven key")
+    }
+
+    /// Returns the value stored under the key after the update.
+    pub fn new_value(&self) -> Word {
+        self.new.get(&self.key).expect("opening is for the proven key")
+    }
+
+    /// Returns the opening of the key against the vault before the update.
+    pub fn old_opening(&self) -> &SmtProof {
+        &self.old
+    }
+
+    /// Returns the opening of the key against the vault after the update.
+    pub fn new_opening(&self) -> &SmtProof {
+        &self.new
+    }
+
+    /// Returns the root of the vault before the update.
+    pub fn old_root(&self) -> Digest {
+        self.old.compute_root()
+    }
+
+    /// Returns the root of the vault after the update.
+    pub fn new_root(&self) -> Digest {
+        self.new.compute_root()
+    }
+
+    /// Returns true if this proof attests to the transition of a vault with `old_root` into a
+    /// vault with `new_root` by updating only the value stored under the proven key.
+    pub fn verify(&self, old_root: Digest, new_root: Digest) -> bool {
+        self.old.path() == self.new.path()
+            && self.old.verify_membership(&self.key, &self.old_value(), &old_root)
+            && self.new.verify_membership(&self.key, &self.new_value(), &new_root)
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -291,3 +403,61 @@ impl Deserializable for AssetVault {
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AssetVault, Smt};
+    use crate::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset},
+        Digest, Word,
+    };
+
+    #[test]
+    fn vault_update_proofs() {
+        let fungible: Asset = FungibleAsset::mock(100);
+        let non_fungible = NonFungibleAsset::mock(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, &[1, 2]);
+        let mut vault = AssetVault::new(&[non_fungible]).unwrap();
+
+        // insertion of a new asset
+        let old_root = vault.commitment();
+        let proof = vault.add_asset_with_proof(fungible).unwrap();
+        assert_eq!(proof.old_value(), Smt::EMPTY_VALUE);
+        assert_eq!(proof.new_value(), Word::from(fungible));
+        assert!(proof.verify(old_root, vault.commitment()));
+        assert_eq!(proof.old_root(), old_root);
+        assert_eq!(proof.new_root(), vault.commitment());
+
+        // partial removal of a fungible asset
+        let old_root = vault.commitment();
+        let proof = vault.remove_asset_with_proof(FungibleAsset::mock(40)).unwrap();
+        assert_eq!(proof.new_value(), Word::from(FungibleAsset::mock(60)));
+        assert!(proof.verify(old_root, vault.commitment()));
+        assert!(!proof.verify(vault.commitment(), old_root));
+
+        // removal of the last of a fungible asset results in a deletion proof
+        let old_root = vault.commitment();
+        let proof = vault.remove_asset_with_proof(FungibleAsset::mock(60)).unwrap();
+        assert_eq!(proof.new_value(), Smt::EMPTY_VALUE);
+        assert!(proof.verify(old_root, vault.commitment()));
+        assert_eq!(
+            vault.get_balance(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap()),
+            Ok(0)
+        );
+
+        // removal of a non-fungible asset
+        let old_root = vault.commitment();
+        let proof = vault.remove_asset_with_proof(non_fungible).unwrap();
+        assert!(proof.verify(old_root, vault.commitment()));
+        assert_eq!(vault.commitment(), AssetVault::default().commitment());
+        assert!(!proof.verify(old_root, Digest::default()));
+
+        // failed updates do not produce proofs
+        assert!(vault.remove_asset_with_proof(non_fungible).is_err());
+    }
+}
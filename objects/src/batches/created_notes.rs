@@ -0,0 +1,125 @@
+use alloc::vec::Vec;
+
+use crate::{accounts::AccountId, transaction::OutputNotes, Digest, Felt, Hasher, Word, ZERO};
+
+// CREATED NOTES COMMITMENT
+// ================================================================================================
+
+/// Computes a commitment to all notes created by the provided transactions of a batch.
+///
+/// Each transaction is described by the ID of the account it was executed against and the notes
+/// it created. The notes are committed to in a canonical order: transactions are ordered by their
+/// account ID, transactions against the same account retain their relative order, and the notes
+/// of each transaction are ordered by their index. Thus, the resulting commitment does not depend
+/// on the order in which transactions against different accounts are provided.
+///
+/// The commitment is computed as a sequential hash of `(note_id, note_metadata)` tuples of all
+/// created notes. If no notes were created, [ZERO; 4] is returned.
+pub fn compute_created_notes_commitment<'a>(
+    transactions: impl IntoIterator<Item = (AccountId, &'a OutputNotes)>,
+) -> Digest {
+    let mut transactions: Vec<_> = transactions.into_iter().collect();
+    // sorting is stable, so the order of transactions against the same account is preserved
+    transactions.sort_by_key(|(account_id, _)| *account_id);
+
+    let mut elements: Vec<Felt> = Vec::new();
+    for (_, output_notes) in transactions {
+        for note in output_notes.iter() {
+            elements.extend_from_slice(note.id().as_elements());
+            elements.extend_from_slice(&Word::from(note.metadata()));
+        }
+    }
+
+    if elements.is_empty() {
+        return Digest::new([ZERO; 4]);
+    }
+
+    Hasher::hash_elements(&elements)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::compute_created_notes_commitment;
+    use crate::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+            },
+            AccountId,
+        },
+        notes::{NoteExecutionHint, NoteHeader, NoteId, NoteMetadata, NoteTag, NoteType},
+        transaction::{OutputNote, OutputNotes},
+        Digest, Felt, ZERO,
+    };
+
+    fn output_notes(sender: AccountId, seeds: &[u64]) -> OutputNotes {
+        let notes = seeds
+            .iter()
+            .map(|seed| {
+                let note_id = NoteId::from(Digest::new([Felt::new(*seed), ZERO, ZERO, ZERO]));
+                let metadata = NoteMetadata::new(
+                    sender,
+                    NoteType::Public,
+                    NoteTag::from(0),
+                    NoteExecutionHint::always(),
+                    ZERO,
+                )
+                .unwrap();
+                OutputNote::Header(NoteHeader::new(note_id, metadata))
+            })
+            .collect();
+        OutputNotes::new(notes).unwrap()
+    }
+
+    #[test]
+    fn created_notes_commitment_is_canonical() {
+        let account_1 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let account_2 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let (first, second) = if account_1 < account_2 {
+            (account_1, account_2)
+        } else {
+            (account_2, account_1)
+        };
+
+        let first_notes = output_notes(first, &[1, 2]);
+        let second_notes = output_notes(second, &[3]);
+        let later_notes = output_notes(first, &[4]);
+
+        let commitment = compute_created_notes_commitment([
+            (first, &first_notes),
+            (first, &later_notes),
+            (second, &second_notes),
+        ]);
+
+        // transactions against different accounts can be provided in any order
+        assert_eq!(
+            compute_created_notes_commitment([
+                (second, &second_notes),
+                (first, &first_notes),
+                (first, &later_notes),
+            ]),
+            commitment
+        );
+
+        // the order of transactions against the same account is significant
+        assert_ne!(
+            compute_created_notes_commitment([
+                (first, &later_notes),
+                (first, &first_notes),
+                (second, &second_notes),
+            ]),
+            commitment
+        );
+
+        let empty = OutputNotes::new(vec![]).unwrap();
+        assert_eq!(compute_created_notes_commitment([(first, &empty)]), Digest::new([ZERO; 4]));
+    }
+}
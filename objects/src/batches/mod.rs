@@ -1,2 +1,5 @@
+mod created_notes;
+pub use created_notes::compute_created_notes_commitment;
+
 mod note_tree;
 pub use note_tree::BatchNoteTree;
@@ -0,0 +1,132 @@
+use core::{fmt, ops::Range};
+
+use crate::{
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt,
+};
+
+// BLOCK NUMBER
+// ================================================================================================
+
+/// The number of a block in the Miden chain.
+///
+/// Block numbers are sequential, with the genesis block having number 0. The wrapper has the same
+/// representation as the underlying `u32` and exists to prevent block numbers from being mixed up
+/// with other integers in function signatures (e.g., the reference block of a transaction).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BlockNumber(u32);
+
+impl BlockNumber {
+    /// The number of the genesis block.
+    pub const GENESIS: Self = Self(0);
+
+    /// Returns a new [BlockNumber] from the provided value.
+    pub const fn new(block_num: u32) -> Self {
+        Self(block_num)
+    }
+
+    /// Returns the block number as a `u32`.
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the number of the block preceding this block, or None if this is the genesis
+    /// block.
+    pub fn parent(&self) -> Option<Self> {
+        self.0.checked_sub(1).map(Self)
+    }
+
+    /// Returns the number of the block following this block, or None if the block number would
+    /// overflow.
+    pub fn child(&self) -> Option<Self> {
+        self.0.checked_add(1).map(Self)
+    }
+
+    /// Returns an iterator over the block numbers in the provided range.
+    ///
+    /// [core::iter::Step] is not stable yet, and thus `start..end` cannot be iterated directly.
+    /// This function provides the equivalent iteration over the block numbers in `start..end`.
+    pub fn range(
+        range: Range<BlockNumber>,
+    ) -> impl DoubleEndedIterator<Item = BlockNumber> + ExactSizeIterator {
+        (range.start.0..range.end.0).map(Self)
+    }
+}
+
+impl From<u32> for BlockNumber {
+    fn from(block_num: u32) -> Self {
+        Self(block_num)
+    }
+}
+
+impl From<BlockNumber> for u32 {
+    fn from(block_num: BlockNumber) -> Self {
+        block_num.0
+    }
+}
+
+impl From<BlockNumber> for Felt {
+    fn from(block_num: BlockNumber) -> Self {
+        block_num.0.into()
+    }
+}
+
+impl fmt::Display for BlockNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BlockNumber {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.0.write_into(target);
+    }
+}
+
+impl Deserializable for BlockNumber {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source.read_u32().map(Self)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::BlockNumber;
+    use crate::utils::{Deserializable, Serializable};
+
+    #[test]
+    fn block_number_parent_and_child() {
+        assert_eq!(BlockNumber::GENESIS.parent(), None);
+        assert_eq!(BlockNumber::new(5).parent(), Some(BlockNumber::new(4)));
+        assert_eq!(BlockNumber::new(5).child(), Some(BlockNumber::new(6)));
+        assert_eq!(BlockNumber::new(u32::MAX).child(), None);
+    }
+
+    #[test]
+    fn block_number_range() {
+        let range = BlockNumber::range(BlockNumber::new(3)..BlockNumber::new(6));
+        assert_eq!(range.len(), 3);
+        assert_eq!(
+            range.rev().collect::<Vec<_>>(),
+            [BlockNumber::new(5), BlockNumber::new(4), BlockNumber::new(3)]
+        );
+        assert_eq!(BlockNumber::range(BlockNumber::new(6)..BlockNumber::new(3)).len(), 0);
+    }
+
+    #[test]
+    fn block_number_serialization() {
+        let block_num = BlockNumber::new(42);
+        let bytes = block_num.to_bytes();
+        assert_eq!(bytes, 42_u32.to_bytes());
+        assert_eq!(BlockNumber::read_from_bytes(&bytes).unwrap(), block_num);
+    }
+}
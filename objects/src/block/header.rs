@@ -1,6 +1,6 @@
 use alloc::vec::Vec;
 
-use super::{Digest, Felt, Hasher, ZERO};
+use super::{BlockNumber, Digest, Felt, Hasher, ZERO};
 use crate::utils::serde::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
 };
@@ -122,8 +122,8 @@ impl BlockHeader {
     }
 
     /// Returns the block number.
-    pub fn block_num(&self) -> u32 {
-        self.block_num
+    pub fn block_num(&self) -> BlockNumber {
+        BlockNumber::new(self.block_num)
     }
 
     /// Returns the chain root.
@@ -5,6 +5,8 @@ use super::{
     MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK, ZERO,
 };
 
+mod block_number;
+pub use block_number::BlockNumber;
 mod header;
 pub use header::BlockHeader;
 mod note_tree;
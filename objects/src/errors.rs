@@ -1,13 +1,21 @@
-use alloc::{boxed::Box, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::fmt;
 
+use assembly::{
+    diagnostics::{Diagnostic, FileLineCol, Report, SourceCode},
+    AssemblyError,
+};
 use vm_processor::DeserializationError;
 
 use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
+    notes::{NoteId, Nullifier},
     Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
     MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
 };
@@ -36,6 +44,12 @@ pub enum AccountError {
     AccountIdInvalidFieldElement(String),
     AccountIdTooFewOnes(u32, u32),
     AssetVaultUpdateError(AssetVaultError),
+    Bech32InvalidChecksum,
+    Bech32InvalidHrp {
+        expected: String,
+        actual: String,
+    },
+    Bech32InvalidString(String),
     BuildError(String, Option<Box<AccountError>>),
     DuplicateStorageItems(MerkleError),
     FungibleFaucetIdInvalidFirstBit,
@@ -102,6 +116,7 @@ pub enum AccountDeltaError {
     },
     IncompatibleAccountUpdates(AccountUpdateDetails, AccountUpdateDetails),
     InconsistentNonceUpdate(String),
+    IncompatibleStorageLayouts,
     NotAFungibleFaucetId(AccountId),
 }
 
@@ -119,8 +134,10 @@ impl fmt::Display for AccountDeltaError {
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AssetError {
+    AmountExceedsMaxSupply { amount: u64, max_supply: u64 },
     AmountTooBig(u64),
     AssetAmountNotSufficient(u64, u64),
+    FaucetMetadataNotFound(AccountId),
     FungibleAssetInvalidTag(u32),
     FungibleAssetInvalidWord(Word),
     InconsistentFaucetIds(AccountId, AccountId),
@@ -171,26 +188,40 @@ impl std::error::Error for AssetVaultError {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NoteError {
+    AssetNotFound(Asset),
+    DisallowedProcedureCall(Digest),
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    DynamicProcedureCall,
+    EmptyNoteConsumptionProof,
+    EmptyNoteRecipient,
+    IdenticalSwapAssets(Asset),
     InconsistentNoteTag(NoteType, u64),
     InvalidAssetData(AssetError),
     InvalidNoteSender(AccountError),
     InvalidNoteTagUseCase(u16),
+    InvalidNoteElements { field: &'static str, reason: String },
     InvalidNoteExecutionHintTag(u8),
     InvalidNoteExecutionHintPayload(u8, u32),
     InvalidNoteType(NoteType),
     InvalidNoteTypeValue(u64),
     InvalidLocationIndex(String),
+    InvalidNoteInputs(String),
     InvalidStubDataLen(usize),
+    MalformedNoteInclusionProof(String),
     NetworkExecutionRequiresOnChainAccount,
     NetworkExecutionRequiresPublicNote(NoteType),
+    NoteConsumptionProofTooLarge(usize),
     NoteDeserializationError(DeserializationError),
-    NoteScriptAssemblyError(String), // TODO: use Report
+    NoteInclusionRootMismatch { expected: Digest, actual: Digest },
+    NoteScriptAssemblyError(CompilationError),
     NoteScriptDeserializationError(DeserializationError),
+    NoteScriptMismatch { expected: Digest, actual: Digest },
+    ProcedureNotCallable(Digest),
     PublicUseCaseRequiresPublicNote(NoteType),
     TooManyAssets(usize),
     TooManyInputs(usize),
+    UnknownTargetProcedure(Digest),
 }
 
 impl NoteError {
@@ -257,12 +288,179 @@ impl fmt::Display for ChainMmrError {
 #[cfg(feature = "std")]
 impl std::error::Error for ChainMmrError {}
 
+// COMPILATION ERROR
+// ================================================================================================
+
+/// Describes why Miden assembly source code of a script could not be compiled.
+///
+/// Locations refer to the source file passed to the assembler, with one-indexed lines and columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompilationError {
+    /// A procedure or an import was defined more than once.
+    DuplicateDefinition {
+        name: String,
+        location: Option<FileLineCol>,
+    },
+    /// A syscall targets a procedure which is not exported by the kernel.
+    KernelProcedureMismatch {
+        name: String,
+        location: Option<FileLineCol>,
+    },
+    /// The source code could not be parsed.
+    ParseError {
+        message: String,
+        location: Option<FileLineCol>,
+    },
+    /// A procedure or module referenced by the source code could not be resolved.
+    UnresolvedImport {
+        name: String,
+        location: Option<FileLineCol>,
+    },
+    /// Any other error reported by the assembler.
+    Other(String),
+}
+
+impl fmt::Display for CompilationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CompilationError {}
+
+impl From<Report> for CompilationError {
+    fn from(report: Report) -> Self {
+        let diagnostic: &dyn Diagnostic = report.as_ref();
+        let source = diagnostic.source_code();
+
+        if let Some(error) = report.downcast_ref::<AssemblyError>() {
+            let location = label_locations(diagnostic, source).into_iter().next();
+            return match error {
+                AssemblyError::UndefinedModule { path, .. } => {
+                    Self::UnresolvedImport { name: path.to_string(), location }
+                },
+                AssemblyError::InvalidSysCallTarget { callee, .. } => {
+                    Self::KernelProcedureMismatch { name: callee.to_string(), location }
+                },
+                AssemblyError::ConflictingDefinitions { second, .. } => {
+                    Self::DuplicateDefinition { name: second.to_string(), location }
+                },
+                // the linker reports procedures which cannot be resolved as a generic failure, the
+                // first related label of which points to the unresolved invocation target
+                AssemblyError::Failed { labels } => match labels.first() {
+                    Some(related) => {
+                        let source = related.source_code().or(source);
+                        let name = label_snippets(related, source).pop().unwrap_or_default();
+                        let location = label_locations(related, source).into_iter().next();
+                        if is_syscall(related, source) {
+                            Self::KernelProcedureMismatch { name, location }
+                        } else {
+                            Self::UnresolvedImport { name, location }
+                        }
+                    },
+                    None => Self::Other(report.to_string()),
+                },
+                _ => Self::Other(report.to_string()),
+            };
+        }
+
+        // any other report is produced by the parser, which reports the errors found during
+        // semantic analysis as related diagnostics; miden-assembly does not export the types of
+        // these errors, so they are identified by the names of their variants
+        for related in diagnostic.related().into_iter().flatten() {
+            let source = related.source_code().or(source);
+            let name = || label_snippets(related, source).pop().unwrap_or_default();
+            let location = label_locations(related, source).into_iter().next();
+            match variant_name(related) {
+                "SymbolConflict" | "ImportConflict" => {
+                    return Self::DuplicateDefinition { name: name(), location };
+                },
+                "SymbolUndefined" | "MissingImport" => {
+                    return Self::UnresolvedImport { name: name(), location };
+                },
+                "InvalidSyscallTarget" | "SyscallInKernel" => {
+                    return Self::KernelProcedureMismatch { name: name(), location };
+                },
+                _ => (),
+            }
+        }
+
+        let location = label_locations(diagnostic, source).into_iter().next();
+        Self::ParseError { message: report.to_string(), location }
+    }
+}
+
+/// Returns the name of the enum variant of the provided diagnostic, as rendered by its derived
+/// [Debug](fmt::Debug) implementation.
+fn variant_name(diagnostic: &dyn Diagnostic) -> &'static str {
+    const VARIANTS: [&str; 6] = [
+        "SymbolConflict",
+        "ImportConflict",
+        "SymbolUndefined",
+        "MissingImport",
+        "InvalidSyscallTarget",
+        "SyscallInKernel",
+    ];
+
+    let debug = alloc::format!("{diagnostic:?}");
+    let name = debug.split(|c: char| !c.is_alphanumeric()).next().unwrap_or_default();
+    VARIANTS.into_iter().find(|variant| *variant == name).unwrap_or_default()
+}
+
+/// Returns the locations of the labels of the provided diagnostic within the provided source.
+fn label_locations(
+    diagnostic: &dyn Diagnostic,
+    source: Option<&dyn SourceCode>,
+) -> Vec<FileLineCol> {
+    let (Some(labels), Some(source)) = (diagnostic.labels(), source) else {
+        return Vec::new();
+    };
+    labels
+        .filter_map(|label| source.read_span(label.inner(), 0, 0).ok())
+        .map(|contents| {
+            FileLineCol::new(
+                contents.name().unwrap_or_default().into(),
+                contents.line() as u32 + 1,
+                contents.column() as u32 + 1,
+            )
+        })
+        .collect()
+}
+
+/// Returns true if the first label of the provided diagnostic points to the target of a syscall.
+fn is_syscall(diagnostic: &dyn Diagnostic, source: Option<&dyn SourceCode>) -> bool {
+    const SYSCALL: &str = "syscall.";
+
+    let (Some(mut labels), Some(source)) = (diagnostic.labels(), source) else {
+        return false;
+    };
+    labels
+        .next()
+        .and_then(|label| label.offset().checked_sub(SYSCALL.len()))
+        .and_then(|offset| source.read_span(&(offset, SYSCALL.len()).into(), 0, 0).ok())
+        .is_some_and(|contents| contents.data() == SYSCALL.as_bytes())
+}
+
+/// Returns the source code snippets labeled by the provided diagnostic.
+fn label_snippets(diagnostic: &dyn Diagnostic, source: Option<&dyn SourceCode>) -> Vec<String> {
+    let (Some(labels), Some(source)) = (diagnostic.labels(), source) else {
+        return Vec::new();
+    };
+    labels
+        .filter_map(|label| source.read_span(label.inner(), 0, 0).ok())
+        .map(|contents| String::from_utf8_lossy(contents.data()).into_owned())
+        .collect()
+}
+
 // TRANSACTION SCRIPT ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionScriptError {
-    AssemblyError(String), // TODO: change to Report
+    AssemblyError(CompilationError),
+    DisallowedProcedureCall(Digest),
+    DynamicProcedureCall,
 }
 
 impl fmt::Display for TransactionScriptError {
@@ -339,6 +537,9 @@ pub enum ProvenTransactionError {
     ExistingOnChainAccountRequiresDeltaDetails(AccountId),
     OutputNotesError(TransactionOutputError),
     AccountUpdateSizeLimitExceeded(AccountId, usize),
+    MissingNullifierProof(Nullifier),
+    InvalidNullifierProof(Nullifier),
+    NullifierAlreadySpent(Nullifier),
 }
 
 impl fmt::Display for ProvenTransactionError {
@@ -377,6 +578,15 @@ impl fmt::Display for ProvenTransactionError {
             ProvenTransactionError::AccountUpdateSizeLimitExceeded(account_id, size) => {
                 write!(f, "Update on account {account_id} of size {size} exceeds the allowed limit of {ACCOUNT_UPDATE_MAX_SIZE}")
             },
+            ProvenTransactionError::MissingNullifierProof(nullifier) => {
+                write!(f, "No proof provided for nullifier {nullifier}")
+            },
+            ProvenTransactionError::InvalidNullifierProof(nullifier) => {
+                write!(f, "Proof for nullifier {nullifier} does not open the nullifier against the nullifier root")
+            },
+            ProvenTransactionError::NullifierAlreadySpent(nullifier) => {
+                write!(f, "Nullifier {nullifier} is already spent as of the nullifier root")
+            },
         }
     }
 }
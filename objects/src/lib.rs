@@ -22,20 +22,22 @@ mod errors;
 // RE-EXPORTS
 // ================================================================================================
 
-pub use block::BlockHeader;
+pub use block::{BlockHeader, BlockNumber};
 pub use constants::*;
 pub use errors::{
     AccountDeltaError, AccountError, AssetError, AssetVaultError, BlockError, ChainMmrError,
-    NoteError, ProvenTransactionError, TransactionInputError, TransactionOutputError,
-    TransactionScriptError,
+    CompilationError, NoteError, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
 pub use vm_core::{Felt, FieldElement, StarkField, Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
 
 pub mod assembly {
     pub use assembly::{
-        mast, Assembler, AssemblyError, DefaultSourceManager, KernelLibrary, Library,
-        LibraryNamespace, LibraryPath, SourceManager, Version,
+        ast::{Module, ModuleKind},
+        diagnostics::FileLineCol,
+        mast, Assembler, AssemblyError, Compile, CompileOptions, DefaultSourceManager,
+        KernelLibrary, Library, LibraryNamespace, LibraryPath, SourceManager, Version,
     };
 }
 
@@ -47,6 +49,11 @@ pub mod utils {
     pub use miden_crypto::utils::{bytes_to_hex_string, collections, hex_to_bytes, HexParseError};
     pub use vm_core::utils::*;
 
+    pub mod format;
+
+    mod word_bytes;
+    pub use word_bytes::WordBytes;
+
     pub mod serde {
         pub use miden_crypto::utils::{
             ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
@@ -64,6 +71,6 @@ pub mod utils {
 
 pub mod vm {
     pub use miden_verifier::ExecutionProof;
-    pub use vm_core::{Program, ProgramInfo};
+    pub use vm_core::{DebugOptions, Program, ProgramInfo};
     pub use vm_processor::{AdviceInputs, AdviceMap, RowIndex, StackInputs, StackOutputs};
 }
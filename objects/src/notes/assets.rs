@@ -1,21 +1,24 @@
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use super::{
     Asset, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
     NoteError, Serializable, Word, WORD_SIZE, ZERO,
 };
-use crate::MAX_ASSETS_PER_NOTE;
+use crate::{accounts::AccountId, MAX_ASSETS_PER_NOTE};
 
 // NOTE ASSETS
 // ================================================================================================
 /// An asset container for a note.
 ///
-/// A note must contain at least 1 asset and can contain up to 256 assets. No duplicates are
-/// allowed, but the order of assets is unspecified.
+/// A note can contain up to 256 assets. No duplicates are allowed, but the order of assets is
+/// unspecified. A note without assets carries only data (its inputs), and can be consumed like any
+/// other note.
 ///
 /// All the assets in a note can be reduced to a single commitment which is computed by
 /// sequentially hashing the assets. Note that the same list of assets can result in two different
-/// commitments if the asset ordering is different.
+/// commitments if the asset ordering is different. The commitment to an empty list of assets is
+/// the empty digest, which is also the commitment computed by the transaction kernel for notes
+/// without assets.
 #[derive(Debug, Default, Clone)]
 pub struct NoteAssets {
     assets: Vec<Asset>,
@@ -59,6 +62,47 @@ impl NoteAssets {
         Ok(Self { assets, hash })
     }
 
+    /// Returns [NoteAssets] decoded from the provided field elements.
+    ///
+    /// This is the inverse of [Self::to_padded_assets()], and thus the elements are expected to
+    /// consist of one word per asset, padded with an empty word if the number of assets is odd.
+    ///
+    /// # Errors
+    /// Returns [NoteError::InvalidNoteElements] naming the field which failed validation if:
+    /// - The number of elements is not a multiple of 8 (`length`).
+    /// - Any of the words does not encode a valid asset (`assets`).
+    /// - The number of assets is greater than [Self::MAX_NUM_ASSETS], or the assets contain
+    ///   duplicates (`assets`).
+    pub fn from_elements(elements: &[Felt]) -> Result<Self, NoteError> {
+        if elements.len() % (2 * WORD_SIZE) != 0 {
+            return Err(invalid_element(
+                "length",
+                format!("{} elements are not a multiple of {}", elements.len(), 2 * WORD_SIZE),
+            ));
+        }
+
+        let mut words: Vec<Word> = elements
+            .chunks_exact(WORD_SIZE)
+            .map(|word| word.try_into().expect("chunk must be a word"))
+            .collect();
+        // an empty word does not encode a valid asset, and thus can only be padding; since the
+        // number of words is even, padding is present exactly if the number of assets is odd
+        if words.last().is_some_and(|word| *word == Word::default()) {
+            words.pop();
+        }
+
+        let assets = words
+            .into_iter()
+            .enumerate()
+            .map(|(index, word)| {
+                Asset::try_from(word)
+                    .map_err(|err| invalid_element("assets", format!("asset {index}: {err}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new(assets).map_err(|err| invalid_element("assets", format!("{err}")))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -82,6 +126,18 @@ impl NoteAssets {
         self.assets.iter()
     }
 
+    /// Returns the amount of the fungible asset issued by the specified faucet, or 0 if these
+    /// note assets don't contain any assets issued by the faucet.
+    pub fn fungible_amount(&self, faucet_id: AccountId) -> u64 {
+        self.assets
+            .iter()
+            .find_map(|asset| match asset {
+                Asset::Fungible(asset) if asset.faucet_id() == faucet_id => Some(asset.amount()),
+                _ => None,
+            })
+            .unwrap_or(0)
+    }
+
     /// Returns all assets represented as a vector of field elements.
     ///
     /// The vector is padded with ZEROs so that its length is a multiple of 8. This is useful
@@ -146,6 +202,72 @@ impl NoteAssets {
 
         Ok(())
     }
+
+    // SPLITTING AND MERGING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the union of these note assets and the provided note assets.
+    ///
+    /// Fungible assets issued by the same faucet are combined into a single asset holding the sum
+    /// of their amounts, and non-fungible assets are included as they are. The assets of `self`
+    /// come first, followed by the assets of `other` which are not issued by a faucet already
+    /// present in `self`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same non-fungible asset is present in both lists.
+    /// - The combined amount of fungible assets issued by the same faucet exceeds the maximum
+    ///   amount of a fungible asset.
+    /// - The merged list contains more than [Self::MAX_NUM_ASSETS] assets.
+    pub fn merge(&self, other: &NoteAssets) -> Result<NoteAssets, NoteError> {
+        let mut merged = self.clone();
+        for asset in other.iter() {
+            merged.add_asset(*asset)?;
+        }
+
+        Ok(merged)
+    }
+
+    /// Splits these note assets into the subset specified by `assets` and the remainder.
+    ///
+    /// Fungible assets are split by amount: the requested amount is moved into the subset and
+    /// the rest stays in the remainder (a faucet whose full balance is requested is dropped from
+    /// the remainder). Non-fungible assets are moved into the subset wholesale.
+    ///
+    /// The relative order of the assets in the remainder is preserved.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The requested assets contain duplicates.
+    /// - Any of the requested assets is not present in this list of note assets.
+    /// - The amount of a requested fungible asset is greater than the amount issued by the same
+    ///   faucet in this list of note assets.
+    pub fn split(&self, assets: &[Asset]) -> Result<(NoteAssets, NoteAssets), NoteError> {
+        let subset = NoteAssets::new(assets.to_vec())?;
+
+        let mut remainder = self.assets.clone();
+        for asset in assets {
+            let idx = remainder
+                .iter()
+                .position(|a| a.is_same(asset))
+                .ok_or(NoteError::AssetNotFound(*asset))?;
+
+            match (&mut remainder[idx], asset) {
+                (Asset::Fungible(own_asset), Asset::Fungible(requested)) => {
+                    own_asset.sub(requested.amount()).map_err(NoteError::InvalidAssetData)?;
+                    if own_asset.amount() == 0 {
+                        remainder.remove(idx);
+                    }
+                },
+                _ => {
+                    remainder.remove(idx);
+                },
+            }
+        }
+
+        let hash = compute_asset_commitment(&remainder);
+        Ok((subset, NoteAssets { assets: remainder, hash }))
+    }
 }
 
 impl PartialEq for NoteAssets {
@@ -194,6 +316,11 @@ fn compute_asset_commitment(assets: &[Asset]) -> Digest {
     Hasher::hash_elements(&asset_elements)
 }
 
+/// Returns a [NoteError::InvalidNoteElements] error for the specified field of the assets.
+fn invalid_element(field: &'static str, reason: String) -> NoteError {
+    NoteError::InvalidNoteElements { field, reason }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -221,9 +348,15 @@ impl Deserializable for NoteAssets {
 mod tests {
     use super::{compute_asset_commitment, NoteAssets};
     use crate::{
-        accounts::account_id::{testing::ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, AccountId},
-        assets::{Asset, FungibleAsset},
-        Digest, Felt,
+        accounts::account_id::{
+            testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+            },
+            AccountId,
+        },
+        assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        AssetError, Digest, Felt, NoteError, ZERO,
     };
 
     #[test]
@@ -248,4 +381,126 @@ mod tests {
         assert_eq!(assets.assets, vec![expected_asset]);
         assert_eq!(assets.hash, compute_asset_commitment(&[expected_asset]));
     }
+
+    #[test]
+    fn split_assets() {
+        let faucet_id_0 = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN));
+        let faucet_id_1 = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN));
+        let nf_faucet_id =
+            AccountId::new_unchecked(Felt::new(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN));
+
+        let fungible_0 = Asset::Fungible(FungibleAsset::new(faucet_id_0, 100).unwrap());
+        let fungible_1 = Asset::Fungible(FungibleAsset::new(faucet_id_1, 20).unwrap());
+        let details = NonFungibleAssetDetails::new(nf_faucet_id, vec![1, 2, 3]).unwrap();
+        let non_fungible = Asset::NonFungible(NonFungibleAsset::new(&details).unwrap());
+
+        let assets = NoteAssets::new(vec![fungible_0, non_fungible, fungible_1]).unwrap();
+
+        // split off part of the first fungible asset, the whole second one and the non-fungible
+        let requested = [
+            Asset::Fungible(FungibleAsset::new(faucet_id_0, 30).unwrap()),
+            non_fungible,
+            fungible_1,
+        ];
+        let (subset, remainder) = assets.split(&requested).unwrap();
+        assert_eq!(subset, NoteAssets::new(requested.to_vec()).unwrap());
+        assert_eq!(
+            remainder,
+            NoteAssets::new(vec![Asset::Fungible(FungibleAsset::new(faucet_id_0, 70).unwrap())])
+                .unwrap()
+        );
+        assert_eq!(remainder.commitment(), compute_asset_commitment(&remainder.assets));
+        assert_eq!(remainder.fungible_amount(faucet_id_0), 70);
+        assert_eq!(remainder.fungible_amount(faucet_id_1), 0);
+        assert_eq!(subset.fungible_amount(faucet_id_1), 20);
+
+        // splitting off nothing returns the assets unchanged
+        let (subset, remainder) = assets.split(&[]).unwrap();
+        assert!(subset.is_empty());
+        assert_eq!(remainder, assets);
+        assert_eq!(remainder.commitment(), assets.commitment());
+
+        // requesting more than available fails
+        let too_much = Asset::Fungible(FungibleAsset::new(faucet_id_1, 21).unwrap());
+        assert_eq!(
+            assets.split(&[too_much]).unwrap_err(),
+            NoteError::InvalidAssetData(AssetError::AssetAmountNotSufficient(20, 21))
+        );
+
+        // requesting an asset which isn't present fails
+        let missing_assets = NoteAssets::new(vec![fungible_0]).unwrap();
+        assert_eq!(
+            missing_assets.split(&[non_fungible]).unwrap_err(),
+            NoteError::AssetNotFound(non_fungible)
+        );
+    }
+
+    #[test]
+    fn merge_assets() {
+        let faucet_id_0 = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN));
+        let faucet_id_1 = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN));
+        let nf_faucet_id =
+            AccountId::new_unchecked(Felt::new(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN));
+
+        let fungible_0 = Asset::Fungible(FungibleAsset::new(faucet_id_0, 100).unwrap());
+        let fungible_1 = Asset::Fungible(FungibleAsset::new(faucet_id_1, 20).unwrap());
+        let details = NonFungibleAssetDetails::new(nf_faucet_id, vec![1, 2, 3]).unwrap();
+        let nf_asset = NonFungibleAsset::new(&details).unwrap();
+        let non_fungible = Asset::NonFungible(nf_asset);
+
+        let assets_0 = NoteAssets::new(vec![fungible_0, non_fungible]).unwrap();
+        let assets_1 = NoteAssets::new(vec![fungible_1, fungible_0]).unwrap();
+
+        // fungible assets are summed per faucet and other assets are appended
+        let merged = assets_0.merge(&assets_1).unwrap();
+        let combined = Asset::Fungible(FungibleAsset::new(faucet_id_0, 200).unwrap());
+        assert_eq!(merged, NoteAssets::new(vec![combined, non_fungible, fungible_1]).unwrap());
+        assert_eq!(merged.commitment(), compute_asset_commitment(&merged.assets));
+
+        // merging with empty assets returns the assets unchanged
+        assert_eq!(assets_0.merge(&NoteAssets::default()).unwrap(), assets_0);
+        assert_eq!(NoteAssets::default().merge(&assets_0).unwrap(), assets_0);
+
+        // merging the same non-fungible asset fails
+        assert_eq!(
+            assets_0.merge(&assets_0).unwrap_err(),
+            NoteError::duplicate_non_fungible_asset(nf_asset)
+        );
+
+        // merging fungible assets whose combined amount is too big fails
+        let max_amount = FungibleAsset::MAX_AMOUNT;
+        let large = NoteAssets::new(vec![Asset::Fungible(
+            FungibleAsset::new(faucet_id_0, max_amount).unwrap(),
+        )])
+        .unwrap();
+        assert_eq!(
+            large.merge(&assets_1).unwrap_err(),
+            NoteError::InvalidAssetData(AssetError::AmountTooBig(max_amount + 100))
+        );
+    }
+
+    #[test]
+    fn note_assets_from_elements() {
+        let faucet_id_0 = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN));
+        let faucet_id_1 = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN));
+        let fungible_0 = Asset::Fungible(FungibleAsset::new(faucet_id_0, 100).unwrap());
+        let fungible_1 = Asset::Fungible(FungibleAsset::new(faucet_id_1, 20).unwrap());
+
+        for assets in [vec![], vec![fungible_0], vec![fungible_0, fungible_1]] {
+            let assets = NoteAssets::new(assets).unwrap();
+            assert_eq!(NoteAssets::from_elements(&assets.to_padded_assets()).unwrap(), assets);
+        }
+
+        let field = |elements: &[Felt]| match NoteAssets::from_elements(elements) {
+            Err(NoteError::InvalidNoteElements { field, .. }) => field,
+            result => panic!("unexpected result {result:?}"),
+        };
+        let padded = NoteAssets::new(vec![fungible_0]).unwrap().to_padded_assets();
+        assert_eq!(field(&padded[..4]), "length");
+        assert_eq!(field(&[ZERO; 8]), "assets");
+        assert_eq!(field(&[padded[..4].to_vec(), padded[..4].to_vec()].concat()), "assets");
+        let mut invalid = padded.clone();
+        invalid[3] = Felt::new(1);
+        assert_eq!(field(&invalid), "assets");
+    }
 }
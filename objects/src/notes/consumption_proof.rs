@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
+    Serializable, Word,
+};
+
+// NOTE CONSUMPTION PROOF
+// ================================================================================================
+
+/// Data supplied by the consumer of a note which the note's script requires for the note to be
+/// consumed, e.g., a membership proof for a privacy-preserving note.
+///
+/// Before the transaction is executed, the proof elements are put into the advice map under a key
+/// derived from the serial number of the note (see [NoteConsumptionProof::advice_key()]). Thus,
+/// a note script can load the proof as follows:
+///
+/// ```masm
+/// exec.note::get_serial_number hash adv.push_mapval dropw
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NoteConsumptionProof {
+    elements: Vec<Felt>,
+}
+
+impl NoteConsumptionProof {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of elements a consumption proof can consist of.
+    pub const MAX_ELEMENTS: usize = 1 << 16;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [NoteConsumptionProof] instantiated from the provided elements.
+    ///
+    /// # Errors
+    /// Returns an error if no elements are provided or if the number of provided elements is
+    /// greater than [NoteConsumptionProof::MAX_ELEMENTS].
+    pub fn new(elements: Vec<Felt>) -> Result<Self, NoteError> {
+        if elements.is_empty() {
+            return Err(NoteError::EmptyNoteConsumptionProof);
+        }
+        if elements.len() > Self::MAX_ELEMENTS {
+            return Err(NoteError::NoteConsumptionProofTooLarge(elements.len()));
+        }
+
+        Ok(Self { elements })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the elements of this proof.
+    pub fn elements(&self) -> &[Felt] {
+        &self.elements
+    }
+
+    /// Returns the advice map key under which the consumption proof for the note with the
+    /// specified serial number is provided.
+    ///
+    /// The key is computed as the hash of the serial number, i.e., it matches the result of the
+    /// `hash` instruction applied to the serial number.
+    pub fn advice_key(serial_num: Word) -> Digest {
+        Hasher::hash_elements(&serial_num)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteConsumptionProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.elements.write_into(target);
+    }
+}
+
+impl Deserializable for NoteConsumptionProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let elements = Vec::<Felt>::read_from(source)?;
+        Self::new(elements).map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::{Deserializable, Felt, NoteConsumptionProof, NoteError, Serializable};
+
+    #[test]
+    fn note_consumption_proof_validation() {
+        assert_eq!(NoteConsumptionProof::new(vec![]), Err(NoteError::EmptyNoteConsumptionProof));
+
+        let too_large = vec![Felt::new(1); NoteConsumptionProof::MAX_ELEMENTS + 1];
+        assert_eq!(
+            NoteConsumptionProof::new(too_large),
+            Err(NoteError::NoteConsumptionProofTooLarge(NoteConsumptionProof::MAX_ELEMENTS + 1))
+        );
+
+        let proof = NoteConsumptionProof::new(vec![Felt::new(1), Felt::new(2)]).unwrap();
+        let bytes = proof.to_bytes();
+        assert_eq!(NoteConsumptionProof::read_from_bytes(&bytes).unwrap(), proof);
+    }
+}
@@ -1,4 +1,7 @@
-use alloc::vec::Vec;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use super::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
@@ -39,6 +42,80 @@ impl NoteInputs {
         Ok(pad_and_build(values))
     }
 
+    /// Returns [NoteInputs] with the specified number of values, where the values at the provided
+    /// indices are set to the provided values and all other values are ZERO.
+    ///
+    /// The resulting inputs, and thus their commitment, are identical to the inputs obtained by
+    /// passing the fully specified values to [Self::new()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of values is greater than 128.
+    /// - Any of the indices is not smaller than the number of values, or is specified more than
+    ///   once.
+    pub fn sparse(pairs: &[(usize, Felt)], len: usize) -> Result<Self, NoteError> {
+        if len > MAX_INPUTS_PER_NOTE {
+            return Err(NoteError::too_many_inputs(len));
+        }
+
+        let mut values = vec![ZERO; len];
+        let mut specified = vec![false; len];
+        for &(index, value) in pairs {
+            if index >= len {
+                return Err(NoteError::InvalidNoteInputs(format!(
+                    "input index {index} is out of range for {len} inputs"
+                )));
+            }
+            if core::mem::replace(&mut specified[index], true) {
+                return Err(NoteError::InvalidNoteInputs(format!(
+                    "input index {index} is specified more than once"
+                )));
+            }
+            values[index] = value;
+        }
+
+        Ok(pad_and_build(values))
+    }
+
+    /// Returns [NoteInputs] decoded from the provided field elements.
+    ///
+    /// This is the inverse of [Self::format_for_advice()], and thus the elements are expected to
+    /// be laid out as `input_len || INPUTS || PADDING`.
+    ///
+    /// # Errors
+    /// Returns [NoteError::InvalidNoteElements] naming the field which failed validation if:
+    /// - No elements are provided, or the number of inputs is greater than 128 (`input_len`).
+    /// - The number of elements is inconsistent with the number of inputs (`length`).
+    /// - Any of the padding elements is not ZERO (`padding`).
+    pub fn from_elements(elements: &[Felt]) -> Result<Self, NoteError> {
+        let (num_values, elements) = elements.split_first().ok_or_else(|| {
+            invalid_element("input_len", "the number of inputs is missing".to_string())
+        })?;
+        let num_values = num_values.as_int();
+        if num_values > MAX_INPUTS_PER_NOTE as u64 {
+            return Err(invalid_element(
+                "input_len",
+                format!("{num_values} inputs exceed the maximum of {MAX_INPUTS_PER_NOTE}"),
+            ));
+        }
+
+        let num_values = num_values as usize;
+        let padded_len = num_values.next_multiple_of(WORD_SIZE * 2);
+        if elements.len() != padded_len {
+            return Err(invalid_element(
+                "length",
+                format!("expected {padded_len} input elements, got {}", elements.len()),
+            ));
+        }
+
+        let (values, padding) = elements.split_at(num_values);
+        if padding.iter().any(|element| *element != ZERO) {
+            return Err(invalid_element("padding", "padding elements must be ZERO".to_string()));
+        }
+
+        Self::new(values.to_vec())
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -53,7 +130,7 @@ impl NoteInputs {
     pub fn num_values(&self) -> u8 {
         const _: () = assert!(MAX_INPUTS_PER_NOTE <= u8::MAX as usize);
         debug_assert!(
-            self.values.len() < MAX_INPUTS_PER_NOTE,
+            self.values.len() <= MAX_INPUTS_PER_NOTE,
             "The constructor should have checked the number of inputs"
         );
         self.values.len() as u8
@@ -114,6 +191,11 @@ impl TryFrom<Vec<Felt>> for NoteInputs {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a [NoteError::InvalidNoteElements] error for the specified field of the inputs.
+fn invalid_element(field: &'static str, reason: String) -> NoteError {
+    NoteError::InvalidNoteElements { field, reason }
+}
+
 /// Returns a vector with built from the provided inputs and padded to the next multiple of 8.
 fn pad_inputs(inputs: &[Felt]) -> Vec<Felt> {
     const BLOCK_SIZE: usize = WORD_SIZE * 2;
@@ -162,7 +244,7 @@ impl Deserializable for NoteInputs {
 mod tests {
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{Felt, NoteError, NoteInputs, Serializable, MAX_INPUTS_PER_NOTE, ZERO};
 
     #[test]
     fn test_input_ordering() {
@@ -184,4 +266,49 @@ mod tests {
         let parsed_note_inputs = NoteInputs::read_from_bytes(&bytes).unwrap();
         assert_eq!(note_inputs, parsed_note_inputs);
     }
+
+    #[test]
+    fn test_inputs_from_elements() {
+        for num_values in [0, 3, 8, MAX_INPUTS_PER_NOTE] {
+            let inputs = NoteInputs::new(vec![Felt::new(7); num_values]).unwrap();
+            assert_eq!(NoteInputs::from_elements(&inputs.format_for_advice()).unwrap(), inputs);
+        }
+
+        let field = |elements: &[Felt]| match NoteInputs::from_elements(elements) {
+            Err(NoteError::InvalidNoteElements { field, .. }) => field,
+            result => panic!("unexpected result {result:?}"),
+        };
+        let elements = NoteInputs::new(vec![Felt::new(7); 3]).unwrap().format_for_advice();
+        assert_eq!(field(&[]), "input_len");
+        assert_eq!(field(&[Felt::new(MAX_INPUTS_PER_NOTE as u64 + 1)]), "input_len");
+        assert_eq!(field(&elements[..elements.len() - 1]), "length");
+        let mut invalid = elements.clone();
+        invalid[8] = Felt::new(1);
+        assert_eq!(field(&invalid), "padding");
+    }
+
+    #[test]
+    fn test_sparse_inputs() {
+        let sparse = NoteInputs::sparse(&[(1, Felt::new(5)), (10, Felt::new(9))], 12).unwrap();
+        let mut values = vec![ZERO; 12];
+        values[1] = Felt::new(5);
+        values[10] = Felt::new(9);
+        let dense = NoteInputs::new(values).unwrap();
+        assert_eq!(sparse, dense);
+        assert_eq!(sparse.commitment(), dense.commitment());
+        assert_eq!(NoteInputs::sparse(&[], 0).unwrap(), NoteInputs::new(vec![]).unwrap());
+
+        assert!(matches!(
+            NoteInputs::sparse(&[(12, Felt::new(5))], 12),
+            Err(NoteError::InvalidNoteInputs(_))
+        ));
+        assert!(matches!(
+            NoteInputs::sparse(&[(1, Felt::new(5)), (1, Felt::new(6))], 12),
+            Err(NoteError::InvalidNoteInputs(_))
+        ));
+        assert_eq!(
+            NoteInputs::sparse(&[], MAX_INPUTS_PER_NOTE + 1),
+            Err(NoteError::TooManyInputs(MAX_INPUTS_PER_NOTE + 1))
+        );
+    }
 }
@@ -1,7 +1,13 @@
+use alloc::{string::ToString, vec::Vec};
+
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError, Serializable,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Note, NoteError, NoteId,
+    Serializable,
+};
+use crate::{
+    crypto::merkle::MerklePath, transaction::InputNote, BlockHeader, BLOCK_NOTE_TREE_DEPTH,
+    MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH,
 };
-use crate::{crypto::merkle::MerklePath, MAX_BATCHES_PER_BLOCK, MAX_OUTPUT_NOTES_PER_BATCH};
 
 /// Contains information about the location of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -28,6 +34,55 @@ impl NoteLocation {
     pub fn node_index_in_block(&self) -> u16 {
         self.node_index_in_block
     }
+
+    /// Verifies that the provided note was created at this location, i.e., that the provided
+    /// proof authenticates the note against the note root of the provided block header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The proof is malformed, i.e., the location of the proof or the number of the block header
+    ///   differ from this location, or the Merkle path of the proof does not have the depth of the
+    ///   block note tree.
+    /// - The root computed from the note and the Merkle path of the proof differs from the note
+    ///   root of the block header.
+    pub fn verify_inclusion(
+        &self,
+        note: &Note,
+        block_header: &BlockHeader,
+        proof: &NoteInclusionProof,
+    ) -> Result<(), NoteError> {
+        if proof.location() != self {
+            return Err(NoteError::MalformedNoteInclusionProof(
+                "proof location differs from the note location".to_string(),
+            ));
+        }
+        if block_header.block_num().as_u32() != self.block_num {
+            return Err(NoteError::MalformedNoteInclusionProof(format!(
+                "proof is for block {} but the block header is for block {}",
+                self.block_num,
+                block_header.block_num()
+            )));
+        }
+        if proof.note_path().depth() != BLOCK_NOTE_TREE_DEPTH {
+            return Err(NoteError::MalformedNoteInclusionProof(format!(
+                "note path depth is {} but must be {BLOCK_NOTE_TREE_DEPTH}",
+                proof.note_path().depth()
+            )));
+        }
+
+        let root = proof
+            .note_path()
+            .compute_root(self.node_index_in_block.into(), note.hash())
+            .map_err(|err| NoteError::MalformedNoteInclusionProof(err.to_string()))?;
+        if root != block_header.note_root() {
+            return Err(NoteError::NoteInclusionRootMismatch {
+                expected: block_header.note_root(),
+                actual: root,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// Contains the data required to prove inclusion of a note in the canonical chain.
@@ -73,6 +128,52 @@ impl NoteInclusionProof {
     }
 }
 
+// MIN CONSUMABLE BLOCK
+// ================================================================================================
+
+/// The earliest block a transaction consuming a set of notes may reference, as computed by
+/// [min_consumable_block()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinConsumableBlock {
+    block_num: Option<u32>,
+    unauthenticated_notes: Vec<NoteId>,
+}
+
+impl MinConsumableBlock {
+    /// Returns the number of the earliest block a transaction consuming the authenticated notes
+    /// may reference, or None if none of the notes is authenticated.
+    pub fn block_num(&self) -> Option<u32> {
+        self.block_num
+    }
+
+    /// Returns the IDs of the notes whose creation block is not known, and which are thus not
+    /// reflected in [Self::block_num()].
+    pub fn unauthenticated_notes(&self) -> &[NoteId] {
+        &self.unauthenticated_notes
+    }
+}
+
+/// Returns the earliest block a transaction consuming the provided notes may reference.
+///
+/// A note can be consumed only by transactions referencing the block the note was created in or
+/// a later block, and thus the earliest valid reference block is the highest creation block
+/// among the notes. The creation block of a note is known only if the note is authenticated;
+/// unauthenticated notes are excluded from the computation and reported separately.
+pub fn min_consumable_block(notes: &[InputNote]) -> MinConsumableBlock {
+    let mut block_num = None;
+    let mut unauthenticated_notes = Vec::new();
+    for note in notes {
+        match note.proof() {
+            Some(proof) => {
+                block_num = block_num.max(Some(proof.location().block_num()));
+            },
+            None => unauthenticated_notes.push(note.id()),
+        }
+    }
+
+    MinConsumableBlock { block_num, unauthenticated_notes }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -107,3 +208,96 @@ impl Deserializable for NoteInclusionProof {
         Ok(Self { location, note_path })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assembly::Assembler;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::{min_consumable_block, NoteInclusionProof};
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_SENDER, AccountId},
+        block::{BlockNoteIndex, BlockNoteTree},
+        crypto::merkle::MerklePath,
+        testing::notes::NoteBuilder,
+        transaction::InputNote,
+        BlockHeader, Digest, NoteError,
+    };
+
+    #[test]
+    fn verify_note_inclusion() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let note = NoteBuilder::new(sender, StdRng::seed_from_u64(1))
+            .build(&Assembler::default())
+            .unwrap();
+        let other_note = NoteBuilder::new(sender, StdRng::seed_from_u64(2))
+            .build(&Assembler::default())
+            .unwrap();
+
+        let index = BlockNoteIndex::new(0, 1).unwrap();
+        let tree = BlockNoteTree::with_entries([(index, note.id(), *note.metadata())]).unwrap();
+        let block_header = BlockHeader::mock(7, None, Some(tree.root()), &[], Digest::default());
+        let proof = NoteInclusionProof::new(7, index.leaf_index_value(), tree.get_note_path(index))
+            .unwrap();
+        let location = proof.location();
+
+        location.verify_inclusion(&note, &block_header, &proof).unwrap();
+
+        // a note which is not in the tree does not hash to the note root
+        assert!(matches!(
+            location.verify_inclusion(&other_note, &block_header, &proof),
+            Err(NoteError::NoteInclusionRootMismatch { expected, .. }) if expected == tree.root()
+        ));
+
+        // proofs for other blocks and truncated paths are malformed
+        let other_header = BlockHeader::mock(8, None, Some(tree.root()), &[], Digest::default());
+        assert!(matches!(
+            location.verify_inclusion(&note, &other_header, &proof),
+            Err(NoteError::MalformedNoteInclusionProof(_))
+        ));
+
+        let mut nodes: Vec<_> = tree.get_note_path(index).into();
+        nodes.pop();
+        let truncated_proof =
+            NoteInclusionProof::new(7, index.leaf_index_value(), MerklePath::new(nodes)).unwrap();
+        assert!(matches!(
+            location.verify_inclusion(&note, &block_header, &truncated_proof),
+            Err(NoteError::MalformedNoteInclusionProof(_))
+        ));
+    }
+
+    #[test]
+    fn min_consumable_block_of_notes() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let [note_a, note_b, note_c] = [1, 2, 3].map(|seed| {
+            NoteBuilder::new(sender, StdRng::seed_from_u64(seed))
+                .build(&Assembler::default())
+                .unwrap()
+        });
+        let authenticated = |note, block_num| {
+            let proof = NoteInclusionProof::new(block_num, 0, MerklePath::new(Vec::new())).unwrap();
+            InputNote::authenticated(note, proof)
+        };
+
+        let result = min_consumable_block(&[]);
+        assert_eq!(result.block_num(), None);
+        assert!(result.unauthenticated_notes().is_empty());
+
+        let result = min_consumable_block(&[
+            authenticated(note_a, 12),
+            InputNote::unauthenticated(note_b.clone()),
+            authenticated(note_c, 5),
+        ]);
+        assert_eq!(result.block_num(), Some(12));
+        assert_eq!(result.unauthenticated_notes(), &[note_b.id()]);
+
+        let result = min_consumable_block(&[InputNote::unauthenticated(note_b.clone())]);
+        assert_eq!(result.block_num(), None);
+        assert_eq!(result.unauthenticated_notes(), &[note_b.id()]);
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 
 use super::{
     execution_hint::NoteExecutionHint, AccountId, ByteReader, ByteWriter, Deserializable,
@@ -103,6 +103,38 @@ impl From<&NoteMetadata> for Word {
     }
 }
 
+impl NoteMetadata {
+    /// Returns [NoteMetadata] decoded from the provided field elements.
+    ///
+    /// This is the inverse of converting the metadata into a [Word], and thus exactly four
+    /// elements laid out as `[tag, sender, note_type_and_execution_hint, aux]` are expected.
+    ///
+    /// # Errors
+    /// Returns [NoteError::InvalidNoteElements] naming the field which failed validation if:
+    /// - The number of elements is not four (`length`).
+    /// - The tag does not fit into a u32 or is inconsistent with the note type (`tag`).
+    /// - The sender is not a valid account ID (`sender`).
+    /// - The note type or execution hint is invalid (`note_type_and_execution_hint`).
+    pub fn from_elements(elements: &[Felt]) -> Result<Self, NoteError> {
+        let elements: Word = elements.try_into().map_err(|_| {
+            invalid_element("length", format!("expected 4 elements, got {}", elements.len()))
+        })?;
+
+        let tag: u64 = elements[0].into();
+        let tag: u32 = tag
+            .try_into()
+            .map_err(|_| invalid_element("tag", format!("tag {tag} does not fit into a u32")))?;
+        let sender: AccountId = elements[1]
+            .try_into()
+            .map_err(|err| invalid_element("sender", format!("{err}")))?;
+        let (note_type, execution_hint) = unmerge_type_and_hint(elements[2].into())
+            .map_err(|err| invalid_element("note_type_and_execution_hint", format!("{err}")))?;
+
+        Self::new(sender, note_type, tag.into(), execution_hint, elements[3])
+            .map_err(|err| invalid_element("tag", format!("{err}")))
+    }
+}
+
 impl TryFrom<Word> for NoteMetadata {
     type Error = NoteError;
 
@@ -145,6 +177,11 @@ impl Deserializable for NoteMetadata {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a [NoteError::InvalidNoteElements] error for the specified field of the metadata.
+fn invalid_element(field: &'static str, reason: String) -> NoteError {
+    NoteError::InvalidNoteElements { field, reason }
+}
+
 /// Encodes `note_type` and `note_execution_hint` into a [u64] such that the resulting number has
 /// the following structure (from most significant bit to the least significant bit):
 ///
@@ -215,4 +252,37 @@ mod tests {
         assert_eq!(note_type, extracted_note_type);
         assert_eq!(note_execution_hint, extracted_note_execution_hint);
     }
+
+    #[test]
+    fn test_metadata_from_elements() {
+        let sender = AccountId::try_from(
+            crate::accounts::account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        )
+        .unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::after_block(10),
+            Felt::new(5),
+        )
+        .unwrap();
+        let elements = Word::from(metadata);
+        assert_eq!(NoteMetadata::from_elements(&elements).unwrap(), metadata);
+
+        let field = |elements: &[Felt]| match NoteMetadata::from_elements(elements) {
+            Err(NoteError::InvalidNoteElements { field, .. }) => field,
+            result => panic!("unexpected result {result:?}"),
+        };
+        assert_eq!(field(&elements[..3]), "length");
+        let mut invalid = elements;
+        invalid[0] = Felt::new(u64::from(u32::MAX) + 1);
+        assert_eq!(field(&invalid), "tag");
+        let mut invalid = elements;
+        invalid[1] = Felt::new(0);
+        assert_eq!(field(&invalid), "sender");
+        let mut invalid = elements;
+        invalid[2] = Felt::new(u64::MAX >> 8);
+        assert_eq!(field(&invalid), "note_type_and_execution_hint");
+    }
 }
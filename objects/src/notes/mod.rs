@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use core::ops::Deref;
 
 use miden_crypto::{
@@ -6,11 +7,18 @@ use miden_crypto::{
 };
 use vm_processor::DeserializationError;
 
-use crate::{accounts::AccountId, assets::Asset, Digest, Felt, Hasher, NoteError, WORD_SIZE, ZERO};
+use crate::{
+    accounts::{AccountCode, AccountId},
+    assets::Asset,
+    Digest, Felt, Hasher, NoteError, WORD_SIZE, ZERO,
+};
 
 mod assets;
 pub use assets::NoteAssets;
 
+mod consumption_proof;
+pub use consumption_proof::NoteConsumptionProof;
+
 mod details;
 pub use details::NoteDetails;
 
@@ -36,10 +44,10 @@ mod note_type;
 pub use note_type::NoteType;
 
 mod nullifier;
-pub use nullifier::Nullifier;
+pub use nullifier::{nullifiers, Nullifier, NullifierProof};
 
 mod location;
-pub use location::{NoteInclusionProof, NoteLocation};
+pub use location::{min_consumable_block, MinConsumableBlock, NoteInclusionProof, NoteLocation};
 
 mod partial;
 pub use partial::PartialNote;
@@ -50,6 +58,9 @@ pub use recipient::NoteRecipient;
 mod script;
 pub use script::NoteScript;
 
+mod target;
+pub use target::NoteTarget;
+
 mod file;
 pub use file::NoteFile;
 
@@ -159,6 +170,41 @@ impl Note {
     pub fn hash(&self) -> Digest {
         self.header.hash()
     }
+
+    // UTILITY METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this note is likely to be consumable by the specified account.
+    ///
+    /// This is a cheap heuristic which does not execute the note script, and thus a note for which
+    /// true is returned may still fail to be consumed. False is returned only if:
+    /// - The note tag targets a specific account for network execution (see
+    ///   [NoteTag::is_single_target()]) and this account is not the specified one.
+    /// - The note script calls a procedure which is not exposed by the provided account code (see
+    ///   [NoteScript::validate_calls()]). Note scripts making dynamic calls are assumed to be
+    ///   compatible with any account.
+    pub fn is_likely_consumable_by(
+        &self,
+        account_id: AccountId,
+        account_code: &AccountCode,
+    ) -> bool {
+        let tag = self.metadata().tag();
+        if tag.is_single_target()
+            && NoteTag::from_account_id(account_id, NoteExecutionMode::Network) != Ok(tag)
+        {
+            return false;
+        }
+
+        let procedures: Vec<Digest> = account_code
+            .procedures()
+            .iter()
+            .map(|procedure| *procedure.mast_root())
+            .collect();
+        !matches!(
+            self.script().validate_calls(&procedures),
+            Err(NoteError::DisallowedProcedureCall(_))
+        )
+    }
 }
 
 // DEREFERENCING
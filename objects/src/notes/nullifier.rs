@@ -1,11 +1,17 @@
-use alloc::string::String;
-use core::fmt::{Debug, Display, Formatter};
+use alloc::{string::String, vec::Vec};
+use core::{
+    fmt::{Debug, Display, Formatter},
+    hash::Hash,
+};
 
 use super::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
     NoteDetails, Serializable, Word, WORD_SIZE, ZERO,
 };
-use crate::utils::{hex_to_bytes, HexParseError};
+use crate::{
+    crypto::merkle::{Smt, SmtProof},
+    utils::{hex_to_bytes, HexParseError},
+};
 
 // NULLIFIER
 // ================================================================================================
@@ -55,6 +61,11 @@ impl Nullifier {
         self.0
     }
 
+    /// Returns a reference to the digest defining this nullifier.
+    pub fn as_digest(&self) -> &Digest {
+        &self.0
+    }
+
     /// Creates a Nullifier from a hex string. Assumes that the string starts with "0x" and
     /// that the hexadecimal characters are big-endian encoded.
     pub fn from_hex(hex_value: &str) -> Result<Self, HexParseError> {
@@ -82,6 +93,99 @@ impl Debug for Nullifier {
     }
 }
 
+impl Hash for Nullifier {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write(&<[u8; 32]>::from(self));
+    }
+}
+
+// NULLIFIER PROOF
+// ================================================================================================
+
+/// An opening of a nullifier in the nullifier tree.
+///
+/// The nullifier tree is a sparse Merkle tree keyed by nullifiers, in which the nullifiers of
+/// consumed notes are mapped to non-empty values. An opening of a nullifier to
+/// [Smt::EMPTY_VALUE] thus proves that the note with this nullifier had not been consumed as of
+/// the root of the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierProof {
+    nullifier: Nullifier,
+    opening: SmtProof,
+}
+
+impl NullifierProof {
+    /// Returns a new [NullifierProof] for the specified nullifier.
+    pub fn new(nullifier: Nullifier, opening: SmtProof) -> Self {
+        Self { nullifier, opening }
+    }
+
+    /// Returns the nullifier this proof is for.
+    pub fn nullifier(&self) -> Nullifier {
+        self.nullifier
+    }
+
+    /// Returns the opening of the nullifier in the nullifier tree.
+    pub fn opening(&self) -> &SmtProof {
+        &self.opening
+    }
+
+    /// Returns the value of the nullifier proven by the opening, or None if the opening is for a
+    /// different key.
+    pub fn value(&self) -> Option<Word> {
+        self.opening.get(&self.nullifier.inner())
+    }
+
+    /// Returns the root of the nullifier tree the opening is computed against.
+    pub fn root(&self) -> Digest {
+        self.opening.compute_root()
+    }
+
+    /// Returns true if this proof attests that the nullifier was unspent in the nullifier tree
+    /// with the specified root.
+    pub fn is_unspent_in(&self, root: Digest) -> bool {
+        self.opening
+            .verify_membership(&self.nullifier.inner(), &Smt::EMPTY_VALUE, &root)
+    }
+}
+
+// BATCH COMPUTATION
+// ================================================================================================
+
+/// Computes the nullifiers of the provided notes.
+///
+/// The nullifier of a note is returned at the same position as the note in the provided slice.
+/// When the `concurrent` feature is enabled, the nullifiers are computed in parallel.
+///
+/// Note that a [Note](super::Note) computes its nullifier when it is constructed, and thus the
+/// nullifiers of full notes are available via [Note::nullifier()](super::Note::nullifier)
+/// without any additional hashing.
+pub fn nullifiers(notes: &[NoteDetails]) -> Vec<Nullifier> {
+    #[cfg(feature = "concurrent")]
+    {
+        let thread_count = std::thread::available_parallelism().map_or(1, |v| v.get());
+        let chunk_size = notes.len().div_ceil(thread_count).max(1);
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = notes
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || chunk.iter().map(Nullifier::from).collect::<Vec<_>>())
+                })
+                .collect();
+
+            // joining the threads in the order of the chunks preserves the input ordering
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("nullifier thread panicked"))
+                .collect()
+        })
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    notes.iter().map(Nullifier::from).collect()
+}
+
 // CONVERSIONS INTO NULLIFIER
 // ================================================================================================
 
@@ -108,6 +212,14 @@ impl From<Digest> for Nullifier {
     }
 }
 
+impl TryFrom<[u8; 32]> for Nullifier {
+    type Error = HexParseError;
+
+    fn try_from(value: [u8; 32]) -> Result<Self, Self::Error> {
+        Ok(Self(Digest::try_from(value)?))
+    }
+}
+
 // CONVERSIONS FROM NULLIFIER
 // ================================================================================================
 
@@ -156,7 +268,16 @@ impl Deserializable for Nullifier {
 
 #[cfg(test)]
 mod tests {
-    use crate::notes::Nullifier;
+    use alloc::{string::ToString, vec::Vec};
+    use std::collections::HashSet;
+
+    use crate::{
+        notes::{
+            nullifiers, NoteAssets, NoteDetails, NoteInputs, NoteRecipient, NoteScript, Nullifier,
+        },
+        utils::serde::{Deserializable, Serializable},
+        Digest, Felt, ZERO,
+    };
 
     #[test]
     fn test_from_hex_and_back() {
@@ -165,4 +286,39 @@ mod tests {
 
         assert_eq!(nullifier_hex, nullifier.to_hex());
     }
+
+    #[test]
+    fn test_nullifier_serialization_and_display() {
+        let nullifier =
+            Nullifier::from(Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]));
+
+        let bytes = nullifier.to_bytes();
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(Nullifier::read_from_bytes(&bytes).unwrap(), nullifier);
+        assert_eq!(Nullifier::try_from(<[u8; 32]>::from(nullifier)).unwrap(), nullifier);
+
+        // the display form is the stable big-endian hex encoding of the nullifier
+        assert_eq!(nullifier.to_string(), nullifier.to_hex());
+        assert_eq!(Nullifier::from_hex(&nullifier.to_string()).unwrap(), nullifier);
+        assert_eq!(nullifier.as_digest(), &nullifier.inner());
+
+        let set: HashSet<Nullifier> = [nullifier, nullifier].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_batch_nullifiers() {
+        let notes: Vec<NoteDetails> = (0..5)
+            .map(|seed| {
+                let serial_num = [Felt::new(seed), ZERO, ZERO, ZERO];
+                let inputs = NoteInputs::new(vec![]).unwrap();
+                let recipient = NoteRecipient::new(serial_num, NoteScript::mock(), inputs);
+                NoteDetails::new(NoteAssets::new(vec![]).unwrap(), recipient)
+            })
+            .collect();
+
+        let expected: Vec<Nullifier> = notes.iter().map(NoteDetails::nullifier).collect();
+        assert_eq!(nullifiers(&notes), expected);
+        assert!(nullifiers(&[]).is_empty());
+    }
 }
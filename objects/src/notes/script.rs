@@ -1,4 +1,4 @@
-use alloc::{string::ToString, sync::Arc, vec::Vec};
+use alloc::{sync::Arc, vec::Vec};
 use core::fmt::Display;
 
 use assembly::{Assembler, Compile};
@@ -8,8 +8,9 @@ use vm_core::{
     Program,
 };
 
-use super::{Digest, Felt};
+use super::{Digest, Felt, NoteTarget};
 use crate::{
+    transaction::{collect_advice_map_keys, validate_mast_calls, DisallowedCall},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     NoteError,
 };
@@ -47,10 +48,31 @@ impl NoteScript {
     pub fn compile(source_code: impl Compile, assembler: Assembler) -> Result<Self, NoteError> {
         let program = assembler
             .assemble_program(source_code)
-            .map_err(|report| NoteError::NoteScriptAssemblyError(report.to_string()))?;
+            .map_err(|report| NoteError::NoteScriptAssemblyError(report.into()))?;
         Ok(Self::new(program))
     }
 
+    /// Returns a new [NoteScript] compiled from the provided source code using the specified
+    /// assembler, and validated against the provided targets.
+    ///
+    /// The compiled script may call only the procedures marked callable by at least one of the
+    /// targets (see [NoteScript::validate_targets()]). To call procedures by their paths, the
+    /// libraries of the account components defining them must be linked into the assembler; see
+    /// [NoteTarget] for the calling convention.
+    ///
+    /// # Errors
+    /// Returns an error if the compilation of the provided source code fails, or if the compiled
+    /// script fails validation against the provided targets.
+    pub fn compile_for_targets(
+        source_code: impl Compile,
+        assembler: Assembler,
+        targets: &[NoteTarget],
+    ) -> Result<Self, NoteError> {
+        let script = Self::compile(source_code, assembler)?;
+        script.validate_targets(targets)?;
+        Ok(script)
+    }
+
     /// Returns a new [NoteScript] deserialized from the provided bytes.
     ///
     /// # Errors
@@ -80,6 +102,61 @@ impl NoteScript {
     pub fn mast(&self) -> Arc<MastForest> {
         self.mast.clone()
     }
+
+    /// Returns the advice map keys which this note script is statically known to request.
+    ///
+    /// Only keys pushed onto the stack as constants right before being read from the advice map
+    /// are detected; keys computed at runtime are omitted.
+    pub fn required_advice_keys(&self) -> Vec<Digest> {
+        collect_advice_map_keys(&self.mast, self.entrypoint)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that this note script calls only the procedures with the specified MAST roots.
+    ///
+    /// This can be used to check that a note script can be executed against an account by
+    /// providing the MAST roots of the account's procedures. The check is performed statically in
+    /// the same way as in
+    /// [TransactionScript::validate_calls()](crate::transaction::TransactionScript::validate_calls).
+    ///
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script calls a procedure which is not in `allowed`; the error contains the MAST root
+    ///   of the first such procedure encountered.
+    /// - The script contains a dynamic call, which cannot be checked statically.
+    pub fn validate_calls(&self, allowed: &[Digest]) -> Result<(), NoteError> {
+        validate_mast_calls(&self.mast, self.entrypoint, allowed).map_err(|call| match call {
+            DisallowedCall::Static(callee) => NoteError::DisallowedProcedureCall(callee),
+            DisallowedCall::Dynamic => NoteError::DynamicProcedureCall,
+        })
+    }
+
+    /// Checks that this note script calls only the procedures marked callable by at least one of
+    /// the provided targets.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script calls a procedure of a target account which is not marked callable by any of
+    ///   the targets.
+    /// - The script calls a procedure which is not defined by any of the targets.
+    /// - The script contains a dynamic call, which cannot be checked statically.
+    pub fn validate_targets(&self, targets: &[NoteTarget]) -> Result<(), NoteError> {
+        let callable: Vec<Digest> = targets
+            .iter()
+            .flat_map(|target| target.callable_procedures().iter().copied())
+            .collect();
+        self.validate_calls(&callable).map_err(|err| match err {
+            NoteError::DisallowedProcedureCall(callee)
+                if targets.iter().any(|target| target.has_procedure(callee)) =>
+            {
+                NoteError::ProcedureNotCallable(callee)
+            },
+            err => err,
+        })
+    }
 }
 
 // CONVERSIONS INTO NOTE SCRIPT
@@ -0,0 +1,127 @@
+use alloc::vec::Vec;
+
+use super::{Digest, NoteError};
+use crate::accounts::AccountCode;
+
+// NOTE TARGET
+// ================================================================================================
+
+/// Describes the interface of an account against which a note script is intended to be executed.
+///
+/// A target specifies which procedures of the account code may be called from the note script.
+/// By default, no procedure is callable, and thus every procedure a note needs to invoke must be
+/// marked callable explicitly via [NoteTarget::with_callable_procedure()]. This allows note
+/// scripts to call only the parts of an account interface they are expected to use, e.g., to
+/// require a co-signature by calling the authentication procedure of the consuming account.
+///
+/// # Calling convention
+/// A note script invokes an account procedure via the `call` instruction, and so the procedure is
+/// executed in the context of the consuming account. The procedure can be referenced either by
+/// its MAST root (e.g., `call.0x...`), which does not require the account component library to
+/// be linked, or by its path if the library of the component defining the procedure has been
+/// added to the assembler used to compile the note script. Arguments are passed via the top 16
+/// elements of the operand stack, and any additional data, e.g., a signature verified by an
+/// authentication procedure, must be provided via the advice provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteTarget {
+    code_commitment: Digest,
+    procedures: Vec<Digest>,
+    callable: Vec<Digest>,
+}
+
+impl NoteTarget {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteTarget] for an account with the provided code, with no procedure of the
+    /// account marked as callable.
+    pub fn new(account_code: &AccountCode) -> Self {
+        Self {
+            code_commitment: account_code.commitment(),
+            procedures: account_code.procedure_roots().collect(),
+            callable: Vec::new(),
+        }
+    }
+
+    /// Returns a new [NoteTarget] for an account with the provided code, with all procedures of
+    /// the account marked as callable.
+    pub fn with_all_procedures_callable(account_code: &AccountCode) -> Self {
+        let procedures: Vec<Digest> = account_code.procedure_roots().collect();
+        Self {
+            code_commitment: account_code.commitment(),
+            callable: procedures.clone(),
+            procedures,
+        }
+    }
+
+    /// Marks the procedure with the specified MAST root as callable from the note script.
+    ///
+    /// # Errors
+    /// Returns an error if the procedure is not defined in the code of the target account.
+    pub fn with_callable_procedure(mut self, mast_root: Digest) -> Result<Self, NoteError> {
+        if !self.procedures.contains(&mast_root) {
+            return Err(NoteError::UnknownTargetProcedure(mast_root));
+        }
+        if !self.callable.contains(&mast_root) {
+            self.callable.push(mast_root);
+        }
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to the code of the target account.
+    pub fn code_commitment(&self) -> Digest {
+        self.code_commitment
+    }
+
+    /// Returns the MAST roots of the procedures which are callable from the note script.
+    pub fn callable_procedures(&self) -> &[Digest] {
+        &self.callable
+    }
+
+    /// Returns true if the procedure with the specified MAST root is defined in the code of the
+    /// target account.
+    pub fn has_procedure(&self, mast_root: Digest) -> bool {
+        self.procedures.contains(&mast_root)
+    }
+
+    /// Returns true if the procedure with the specified MAST root is callable from the note
+    /// script.
+    pub fn is_callable(&self, mast_root: Digest) -> bool {
+        self.callable.contains(&mast_root)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteError, NoteTarget};
+    use crate::{accounts::AccountCode, Digest};
+
+    #[test]
+    fn note_target_callable_procedures() {
+        let code = AccountCode::mock();
+        let mut roots = code.procedure_roots();
+        let first = roots.next().unwrap();
+        let second = roots.next().unwrap();
+
+        let target = NoteTarget::new(&code).with_callable_procedure(first).unwrap();
+        assert!(target.is_callable(first));
+        assert!(!target.is_callable(second));
+        assert!(target.has_procedure(second));
+        assert_eq!(target.code_commitment(), code.commitment());
+
+        let unknown = Digest::default();
+        assert_eq!(
+            NoteTarget::new(&code).with_callable_procedure(unknown),
+            Err(NoteError::UnknownTargetProcedure(unknown))
+        );
+
+        let target = NoteTarget::with_all_procedures_callable(&code);
+        assert_eq!(target.callable_procedures().len(), code.num_procedures());
+    }
+}
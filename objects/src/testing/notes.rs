@@ -7,7 +7,7 @@ use assembly::Assembler;
 use rand::Rng;
 
 use crate::{
-    accounts::AccountId,
+    accounts::{AccountCode, AccountId},
     assets::Asset,
     notes::{
         Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
@@ -32,6 +32,7 @@ pub struct NoteBuilder {
     tag: NoteTag,
     code: String,
     aux: Felt,
+    target_code: Option<AccountCode>,
 }
 
 impl NoteBuilder {
@@ -53,6 +54,7 @@ impl NoteBuilder {
             tag: 0.into(),
             code: DEFAULT_NOTE_CODE.to_string(),
             aux: ZERO,
+            target_code: None,
         }
     }
 
@@ -93,9 +95,21 @@ impl NoteBuilder {
         self
     }
 
+    /// Requires the note script to be executable against an account with the provided code.
+    ///
+    /// When set, [Self::build()] fails with [NoteError::DisallowedProcedureCall] if the note
+    /// script calls a procedure which is not a part of the provided account code.
+    pub fn targeting(mut self, account_code: &AccountCode) -> Self {
+        self.target_code = Some(account_code.clone());
+        self
+    }
+
     pub fn build(self, assembler: &Assembler) -> Result<Note, NoteError> {
         let code = assembler.clone().assemble_program(&self.code).unwrap();
         let note_script = NoteScript::new(code);
+        if let Some(target_code) = &self.target_code {
+            note_script.validate_calls(&target_code.procedure_roots().collect::<Vec<_>>())?;
+        }
         let vault = NoteAssets::new(self.assets)?;
         let metadata = NoteMetadata::new(
             self.sender,
@@ -46,16 +46,17 @@ impl ChainMmr {
 
         let mut block_map = BTreeMap::new();
         for block in blocks.into_iter() {
-            if block.block_num() as usize >= chain_length {
-                return Err(ChainMmrError::block_num_too_big(chain_length, block.block_num()));
+            let block_num = block.block_num().as_u32();
+            if block_num as usize >= chain_length {
+                return Err(ChainMmrError::block_num_too_big(chain_length, block_num));
             }
 
-            if block_map.insert(block.block_num(), block).is_some() {
-                return Err(ChainMmrError::duplicate_block(block.block_num()));
+            if block_map.insert(block_num, block).is_some() {
+                return Err(ChainMmrError::duplicate_block(block_num));
             }
 
-            if !mmr.is_tracked(block.block_num() as usize) {
-                return Err(ChainMmrError::untracked_block(block.block_num()));
+            if !mmr.is_tracked(block_num as usize) {
+                return Err(ChainMmrError::untracked_block(block_num));
             }
         }
 
@@ -99,7 +100,7 @@ impl ChainMmr {
     /// Panics if the `block_header.block_num` is not equal to the current chain length (i.e., the
     /// provided block header is not the next block in the chain).
     pub fn add_block(&mut self, block_header: BlockHeader, track: bool) {
-        assert_eq!(block_header.block_num(), self.chain_length() as u32);
+        assert_eq!(block_header.block_num().as_u32(), self.chain_length() as u32);
         self.mmr.add(block_header.hash(), track);
     }
 
@@ -110,7 +111,9 @@ impl ChainMmr {
     /// MMR.
     pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
         self.mmr.inner_nodes(
-            self.blocks.values().map(|block| (block.block_num() as usize, block.hash())),
+            self.blocks
+                .values()
+                .map(|block| (block.block_num().as_u32() as usize, block.hash())),
         )
     }
 }
@@ -134,7 +137,7 @@ impl Deserializable for ChainMmr {
         let mut blocks = BTreeMap::new();
         for _ in 0..block_count {
             let block = BlockHeader::read_from(source)?;
-            blocks.insert(block.block_num(), block);
+            blocks.insert(block.block_num().as_u32(), block);
         }
         Ok(Self { mmr, blocks })
     }
@@ -1,10 +1,10 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::cell::OnceCell;
 
 use super::{
-    Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, InputNote,
+    Account, AccountDelta, AccountHeader, AccountId, AdviceInputs, BlockHeader, Digest, InputNote,
     InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
-    TransactionOutputs, TransactionWitness,
+    TransactionOutputs, TransactionSummary, TransactionWitness,
 };
 use crate::accounts::AccountCode;
 
@@ -73,6 +73,12 @@ impl ExecutedTransaction {
         *self.id.get_or_init(|| self.into())
     }
 
+    /// Returns the identifier of this transaction which is known before the transaction is
+    /// executed (see [prepared_transaction_id()](super::prepared_transaction_id)).
+    pub fn prepared_id(&self) -> Digest {
+        self.tx_inputs.prepared_id()
+    }
+
     /// Returns the ID of the account against which this transaction was executed.
     pub fn account_id(&self) -> AccountId {
         self.initial_account().id()
@@ -145,6 +151,12 @@ impl ExecutedTransaction {
         };
         (self.account_delta, self.tx_outputs, tx_witness, self.tx_measurements)
     }
+
+    /// Returns a [TransactionSummary] of this transaction, discarding the data required to
+    /// re-execute or prove it.
+    pub fn into_summary(self) -> TransactionSummary {
+        TransactionSummary::from(&self)
+    }
 }
 
 impl From<ExecutedTransaction> for TransactionWitness {
@@ -165,7 +177,8 @@ impl From<ExecutedTransaction> for TransactionMeasurements {
 // ================================================================================================
 
 /// Stores the resulting number of cycles for each transaction execution stage obtained from the
-/// `TransactionProgress` struct.
+/// `TransactionProgress` struct, together with the storage slots of the native account accessed
+/// during execution.
 #[derive(Debug, Clone)]
 pub struct TransactionMeasurements {
     pub prologue: usize,
@@ -173,6 +186,7 @@ pub struct TransactionMeasurements {
     pub note_execution: Vec<(NoteId, usize)>,
     pub tx_script_processing: usize,
     pub epilogue: usize,
+    pub storage_accesses: StorageAccessSet,
 }
 
 impl TransactionMeasurements {
@@ -187,4 +201,67 @@ impl TransactionMeasurements {
         let total_cycles = self.total_cycles();
         total_cycles.next_power_of_two()
     }
+
+    /// Returns the storage slots of the native account which were read or written while executing
+    /// the transaction.
+    pub fn storage_accesses(&self) -> &StorageAccessSet {
+        &self.storage_accesses
+    }
+}
+
+// STORAGE ACCESS SET
+// ================================================================================================
+
+/// The indexes of the storage slots of an account which were read and written by a transaction.
+///
+/// A slot is read when its value, or an item of the map stored in it, is retrieved from the
+/// account storage, and written when its value, or an item of its map, is updated. Setting the
+/// value of a slot does not mark it as read, while updating an item of a map also reads the root
+/// of the map from the slot.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StorageAccessSet {
+    reads: BTreeSet<u8>,
+    writes: BTreeSet<u8>,
+}
+
+impl StorageAccessSet {
+    /// Returns the indexes of the storage slots which were read.
+    pub fn reads(&self) -> &BTreeSet<u8> {
+        &self.reads
+    }
+
+    /// Returns the indexes of the storage slots which were written.
+    pub fn writes(&self) -> &BTreeSet<u8> {
+        &self.writes
+    }
+
+    /// Returns true if no storage slot was accessed.
+    pub fn is_empty(&self) -> bool {
+        self.reads.is_empty() && self.writes.is_empty()
+    }
+
+    /// Returns true if the transactions whose accesses are described by `self` and `other`
+    /// conflict, i.e., if either of them writes a slot which the other one reads or writes.
+    ///
+    /// Transactions against the same account which do not conflict can be executed in any order
+    /// against the same initial account state.
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        let writes_accessed_by = |writes: &BTreeSet<u8>, accesses: &Self| {
+            writes
+                .iter()
+                .any(|slot| accesses.reads.contains(slot) || accesses.writes.contains(slot))
+        };
+
+        writes_accessed_by(&self.writes, other) || writes_accessed_by(&other.writes, self)
+    }
+
+    /// Records a read of the storage slot with the specified index.
+    pub fn record_read(&mut self, slot_index: u8) {
+        self.reads.insert(slot_index);
+    }
+
+    /// Records a write of the storage slot with the specified index.
+    pub fn record_write(&mut self, slot_index: u8) {
+        self.writes.insert(slot_index);
+    }
 }
@@ -1,12 +1,13 @@
 use alloc::{collections::BTreeSet, vec::Vec};
 use core::fmt::Debug;
 
-use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
+use super::{prepared_transaction_id, BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
 use crate::{
     accounts::{Account, AccountId},
+    assets::AssetVault,
     notes::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    TransactionInputError, MAX_INPUT_NOTES_PER_TX,
+    AssetVaultError, TransactionInputError, MAX_INPUT_NOTES_PER_TX,
 };
 
 // TRANSACTION INPUTS
@@ -27,10 +28,18 @@ impl TransactionInputs {
     // --------------------------------------------------------------------------------------------
     /// Returns new [TransactionInputs] instantiated with the specified parameters.
     ///
+    /// The chain MMR must contain all blocks preceding the reference block, and thus is empty for
+    /// transactions executed against the genesis block. Input notes created in the reference block
+    /// are authenticated against the reference block header directly.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
+    /// - The length of the chain MMR is not equal to the number of the reference block, or its root
+    ///   does not match the chain root of the reference block.
+    /// - An authenticated input note was created in a block which is not tracked by the chain MMR,
+    ///   or its inclusion proof is invalid.
     pub fn new(
         account: Account,
         account_seed: Option<Word>,
@@ -42,10 +51,10 @@ impl TransactionInputs {
         validate_account_seed(&account, account_seed)?;
 
         // check the block_chain and block_header are consistent
-        let block_num = block_header.block_num();
-        if block_chain.chain_length() != block_header.block_num() as usize {
+        let block_num = block_header.block_num().as_u32();
+        if block_chain.chain_length() != block_num as usize {
             return Err(TransactionInputError::InconsistentChainLength {
-                expected: block_header.block_num(),
+                expected: block_num,
                 actual: block_chain.chain_length() as u32,
             });
         }
@@ -112,6 +121,40 @@ impl TransactionInputs {
         &self.input_notes
     }
 
+    /// Returns the identifier of the transaction executed with these inputs (see
+    /// [prepared_transaction_id()]).
+    ///
+    /// The identifier is the same as the one of the resulting executed and proven transactions.
+    pub fn prepared_id(&self) -> Digest {
+        prepared_transaction_id(
+            self.account.id(),
+            self.account.init_hash(),
+            self.input_notes.commitment(),
+        )
+    }
+
+    /// Returns a vault containing all assets carried by the notes to be consumed in the
+    /// transaction.
+    ///
+    /// Fungible assets issued by the same faucet are summed up, while non-fungible assets are
+    /// collected as is.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The total amount of a fungible asset across all input notes is greater than or equal to
+    ///   2^63.
+    /// - The same non-fungible asset is carried by more than one input note.
+    pub fn consumed_assets(&self) -> Result<AssetVault, AssetVaultError> {
+        let mut vault = AssetVault::default();
+        for note in self.input_notes.iter() {
+            for asset in note.note().assets().iter() {
+                vault.add_asset(*asset)?;
+            }
+        }
+
+        Ok(vault)
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -10,17 +10,20 @@ mod executed_tx;
 mod inputs;
 mod outputs;
 mod proven_tx;
+mod summary;
 mod transaction_id;
 mod tx_args;
 mod tx_witness;
 
 pub use chain_mmr::ChainMmr;
-pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
+pub use executed_tx::{ExecutedTransaction, StorageAccessSet, TransactionMeasurements};
 pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
 };
-pub use transaction_id::TransactionId;
+pub use summary::TransactionSummary;
+pub use transaction_id::{prepared_transaction_id, TransactionId};
+pub(crate) use tx_args::{collect_advice_map_keys, validate_mast_calls, DisallowedCall};
 pub use tx_args::{TransactionArgs, TransactionScript};
-pub use tx_witness::TransactionWitness;
+pub use tx_witness::{TransactionWitness, WitnessDelta};
@@ -92,6 +92,14 @@ impl OutputNotes {
     pub fn iter(&self) -> impl Iterator<Item = &OutputNote> {
         self.notes.iter()
     }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Converts self into a vector of output notes.
+    pub fn into_vec(self) -> Vec<OutputNote> {
+        self.notes
+    }
 }
 
 // SERIALIZATION
@@ -2,12 +2,14 @@ use alloc::{string::ToString, vec::Vec};
 
 use miden_verifier::ExecutionProof;
 
-use super::{InputNote, ToInputNoteCommitments};
+use super::{prepared_transaction_id, InputNote, ToInputNoteCommitments};
 use crate::{
     accounts::delta::AccountUpdateDetails,
-    notes::NoteHeader,
+    crypto::merkle::Smt,
+    notes::{NoteHeader, NullifierProof},
     transaction::{
         AccountId, Digest, InputNotes, Nullifier, OutputNote, OutputNotes, TransactionId,
+        TransactionSummary,
     },
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     ProvenTransactionError, ACCOUNT_UPDATE_MAX_SIZE,
@@ -49,6 +51,16 @@ impl ProvenTransaction {
         self.id
     }
 
+    /// Returns the identifier of this transaction which is known before the transaction is
+    /// executed (see [prepared_transaction_id()]).
+    pub fn prepared_id(&self) -> Digest {
+        prepared_transaction_id(
+            self.account_id(),
+            self.account_update.init_state_hash(),
+            self.input_notes.commitment(),
+        )
+    }
+
     /// Returns ID of the account against which this transaction was executed.
     pub fn account_id(&self) -> AccountId {
         self.account_update.account_id()
@@ -89,6 +101,21 @@ impl ProvenTransaction {
         self.expiration_block_num
     }
 
+    /// Returns a [TransactionSummary] of this transaction, discarding the note details and the
+    /// proof.
+    pub fn into_summary(self) -> TransactionSummary {
+        TransactionSummary::from(&self)
+    }
+
+    /// Returns true if this transaction transitions its account from the state with the specified
+    /// initial hash to the state with the specified final hash.
+    ///
+    /// For transactions executed against new accounts, the initial hash is [ZERO; 4].
+    pub fn matches_transition(&self, initial: Digest, final_: Digest) -> bool {
+        self.account_update.init_state_hash() == initial
+            && self.account_update.final_state_hash() == final_
+    }
+
     /// Returns an iterator over the nullifiers of all input notes in this transaction.
     ///
     /// This includes both authenticated and unauthenticated notes.
@@ -96,6 +123,40 @@ impl ProvenTransaction {
         self.input_notes.iter().map(InputNoteCommitment::nullifier)
     }
 
+    /// Checks that all notes consumed by this transaction were unspent in the nullifier tree with
+    /// the specified root.
+    ///
+    /// A proof must be provided for the nullifier of every input note, and proofs for other
+    /// nullifiers are ignored. This does not verify the proof of the transaction itself.
+    ///
+    /// # Errors
+    /// Returns an error naming the first nullifier of an input note for which:
+    /// - No proof is provided.
+    /// - The provided proof does not open the nullifier against the specified root.
+    /// - The provided proof shows that the nullifier is already spent.
+    pub fn verify_with_nullifier_root(
+        &self,
+        root: Digest,
+        proofs: Vec<NullifierProof>,
+    ) -> Result<(), ProvenTransactionError> {
+        for nullifier in self.get_nullifiers() {
+            let proof = proofs
+                .iter()
+                .find(|proof| proof.nullifier() == nullifier)
+                .ok_or(ProvenTransactionError::MissingNullifierProof(nullifier))?;
+
+            let value = match proof.value() {
+                Some(value) if proof.root() == root => value,
+                _ => return Err(ProvenTransactionError::InvalidNullifierProof(nullifier)),
+            };
+            if value != Smt::EMPTY_VALUE {
+                return Err(ProvenTransactionError::NullifierAlreadySpent(nullifier));
+            }
+        }
+
+        Ok(())
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -436,6 +497,12 @@ pub struct InputNoteCommitment {
 }
 
 impl InputNoteCommitment {
+    /// Returns a new [InputNoteCommitment] with the provided nullifier and note header.
+    #[cfg(feature = "json")]
+    pub(crate) fn new(nullifier: Nullifier, header: Option<NoteHeader>) -> Self {
+        Self { nullifier, header }
+    }
+
     /// Returns the nullifier of the input note committed to by this commitment.
     pub fn nullifier(&self) -> Nullifier {
         self.nullifier
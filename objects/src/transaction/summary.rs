@@ -0,0 +1,144 @@
+use super::{AccountId, Digest, ExecutedTransaction, ProvenTransaction, TransactionId};
+use crate::utils::serde::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+// TRANSACTION SUMMARY
+// ================================================================================================
+
+/// A compact record of a transaction, intended for long-term storage of transactions which do
+/// not need to be re-executed or re-proven.
+///
+/// A summary contains only the public commitments of a transaction: the ID of the account the
+/// transaction was executed against, the hashes of the initial and final account states, and the
+/// commitments to the consumed and created notes. These are exactly the components from which the
+/// [TransactionId] is computed, and thus the ID of the summarized transaction is recomputed when
+/// a summary is created or deserialized.
+///
+/// A summary can be obtained from both an executed and a proven transaction. A
+/// [TransactionWitness](super::TransactionWitness) cannot be summarized since it does not commit
+/// to the outputs of the transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionSummary {
+    id: TransactionId,
+    account_id: AccountId,
+    init_account_hash: Digest,
+    final_account_hash: Digest,
+    input_notes_commitment: Digest,
+    output_notes_commitment: Digest,
+}
+
+impl TransactionSummary {
+    /// Returns a new [TransactionSummary] instantiated from the provided transaction components.
+    pub fn new(
+        account_id: AccountId,
+        init_account_hash: Digest,
+        final_account_hash: Digest,
+        input_notes_commitment: Digest,
+        output_notes_commitment: Digest,
+    ) -> Self {
+        let id = TransactionId::new(
+            init_account_hash,
+            final_account_hash,
+            input_notes_commitment,
+            output_notes_commitment,
+        );
+
+        Self {
+            id,
+            account_id,
+            init_account_hash,
+            final_account_hash,
+            input_notes_commitment,
+            output_notes_commitment,
+        }
+    }
+
+    /// Returns the ID of the summarized transaction.
+    pub fn id(&self) -> TransactionId {
+        self.id
+    }
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state before the transaction was executed.
+    pub fn init_account_hash(&self) -> Digest {
+        self.init_account_hash
+    }
+
+    /// Returns the hash of the account state after the transaction was executed.
+    pub fn final_account_hash(&self) -> Digest {
+        self.final_account_hash
+    }
+
+    /// Returns the commitment to the notes consumed by the transaction.
+    pub fn input_notes_commitment(&self) -> Digest {
+        self.input_notes_commitment
+    }
+
+    /// Returns the commitment to the notes created by the transaction.
+    pub fn output_notes_commitment(&self) -> Digest {
+        self.output_notes_commitment
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<&ExecutedTransaction> for TransactionSummary {
+    fn from(tx: &ExecutedTransaction) -> Self {
+        Self::new(
+            tx.account_id(),
+            tx.initial_account().init_hash(),
+            tx.final_account().hash(),
+            tx.input_notes().commitment(),
+            tx.output_notes().commitment(),
+        )
+    }
+}
+
+impl From<&ProvenTransaction> for TransactionSummary {
+    fn from(tx: &ProvenTransaction) -> Self {
+        Self::new(
+            tx.account_id(),
+            tx.account_update().init_state_hash(),
+            tx.account_update().final_state_hash(),
+            tx.input_notes().commitment(),
+            tx.output_notes().commitment(),
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for TransactionSummary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        self.init_account_hash.write_into(target);
+        self.final_account_hash.write_into(target);
+        self.input_notes_commitment.write_into(target);
+        self.output_notes_commitment.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionSummary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let init_account_hash = Digest::read_from(source)?;
+        let final_account_hash = Digest::read_from(source)?;
+        let input_notes_commitment = Digest::read_from(source)?;
+        let output_notes_commitment = Digest::read_from(source)?;
+
+        Ok(Self::new(
+            account_id,
+            init_account_hash,
+            final_account_hash,
+            input_notes_commitment,
+            output_notes_commitment,
+        ))
+    }
+}
@@ -1,7 +1,9 @@
 use alloc::string::String;
 use core::fmt::{Debug, Display};
 
-use super::{Digest, ExecutedTransaction, Felt, Hasher, ProvenTransaction, Word, WORD_SIZE, ZERO};
+use super::{
+    AccountId, Digest, ExecutedTransaction, Felt, Hasher, ProvenTransaction, Word, WORD_SIZE, ZERO,
+};
 use crate::utils::serde::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
 };
@@ -58,6 +60,36 @@ impl TransactionId {
     }
 }
 
+// PREPARED TRANSACTION ID
+// ================================================================================================
+
+/// Returns the identifier of a transaction which can be computed before the transaction is
+/// executed.
+///
+/// The identifier is computed as:
+///
+/// hash(account_id, 0, 0, 0, init_account_hash, input_notes_hash)
+///
+/// Unlike [TransactionId], this identifier does not depend on the outputs of the transaction, and
+/// thus it is identical for the inputs of a transaction (see
+/// [TransactionInputs::prepared_id()](super::TransactionInputs::prepared_id)), as well as for the
+/// executed and proven forms of the same transaction. The transaction script is not committed to
+/// since it is not revealed by a [ProvenTransaction]. Thus, transactions executed with different
+/// scripts against the same account state and consuming the same notes share this identifier;
+/// since at most one of them can be included in the chain, this makes the identifier suitable
+/// for detecting conflicting transactions (e.g., in a mempool).
+pub fn prepared_transaction_id(
+    account_id: AccountId,
+    init_account_hash: Digest,
+    input_notes_hash: Digest,
+) -> Digest {
+    let mut elements = [ZERO; 3 * WORD_SIZE];
+    elements[0] = account_id.into();
+    elements[4..8].copy_from_slice(init_account_hash.as_elements());
+    elements[8..].copy_from_slice(input_notes_hash.as_elements());
+    Hasher::hash_elements(&elements)
+}
+
 impl Debug for TransactionId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.to_hex())
@@ -1,18 +1,22 @@
-use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+    vec::Vec,
+};
 use core::ops::Deref;
 
 use assembly::{Assembler, Compile};
 use miden_crypto::merkle::InnerNodeInfo;
 use vm_core::{
-    mast::{MastForest, MastNodeId},
+    mast::{MastForest, MastNode, MastNodeId, OperationOrDecorator},
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
-    Program,
+    AdviceInjector, Decorator, Operation, Program, ONE, WORD_SIZE, ZERO,
 };
 use vm_processor::{AdviceInputs, AdviceMap, DeserializationError};
 
 use super::{Digest, Felt, Word};
 use crate::{
-    notes::{NoteDetails, NoteId},
+    notes::{NoteConsumptionProof, NoteDetails, NoteId},
     TransactionScriptError,
 };
 
@@ -26,11 +30,14 @@ use crate::{
 /// - Note arguments: data put onto the stack right before a note script is executed. These are
 ///   different from note inputs, as the user executing the transaction can specify arbitrary note
 ///   args.
+/// - Note consumption proofs: data required by the scripts of specific input notes to consume them,
+///   which is put into the advice map right before the transaction is executed.
 /// - Advice inputs: Provides data needed by the runtime, like the details of public output notes.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
     note_args: BTreeMap<NoteId, Word>,
+    note_consumption_proofs: BTreeMap<NoteId, NoteConsumptionProof>,
     advice_inputs: AdviceInputs,
 }
 
@@ -58,6 +65,7 @@ impl TransactionArgs {
         Self {
             tx_script,
             note_args: note_args.unwrap_or_default(),
+            note_consumption_proofs: BTreeMap::new(),
             advice_inputs,
         }
     }
@@ -92,11 +100,35 @@ impl TransactionArgs {
         self.note_args.get(&note_id)
     }
 
+    /// Returns a reference to the consumption proof provided for the specified note.
+    pub fn get_note_consumption_proof(&self, note_id: NoteId) -> Option<&NoteConsumptionProof> {
+        self.note_consumption_proofs.get(&note_id)
+    }
+
+    /// Returns an iterator over the provided note consumption proofs and the IDs of the notes
+    /// they are provided for.
+    pub fn note_consumption_proofs(
+        &self,
+    ) -> impl Iterator<Item = (&NoteId, &NoteConsumptionProof)> {
+        self.note_consumption_proofs.iter()
+    }
+
     /// Returns a reference to the args [AdviceInputs].
     pub fn advice_inputs(&self) -> &AdviceInputs {
         &self.advice_inputs
     }
 
+    /// Returns true if the advice inputs of these arguments are empty, i.e., if no advice stack
+    /// values, advice map entries or Merkle store nodes are provided.
+    ///
+    /// Arguments which do not require any advice (e.g., arguments without a transaction script,
+    /// or with a script without inputs) hold the canonical empty [AdviceInputs]. Since the Merkle
+    /// store of empty advice inputs still contains the roots of empty subtrees, empty advice
+    /// inputs are not serialized as part of the arguments, but encoded as a single byte.
+    pub fn has_empty_advice_inputs(&self) -> bool {
+        self.advice_inputs == AdviceInputs::default()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -141,6 +173,22 @@ impl TransactionArgs {
         }
     }
 
+    /// Sets the transaction script, replacing the script which was previously provided, if any.
+    ///
+    /// This also adds all mappings from the inputs of the new transaction script to the advice
+    /// inputs' map.
+    pub fn set_tx_script(&mut self, tx_script: TransactionScript) {
+        self.advice_inputs
+            .extend_map(tx_script.inputs().iter().map(|(hash, input)| (*hash, input.clone())));
+        self.tx_script = Some(tx_script);
+    }
+
+    /// Sets the consumption proof for the note with the specified ID, replacing the proof which
+    /// was previously provided for this note, if any.
+    pub fn add_note_consumption_proof(&mut self, note_id: NoteId, proof: NoteConsumptionProof) {
+        self.note_consumption_proofs.insert(note_id, proof);
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.extend_map(iter)
@@ -156,7 +204,12 @@ impl Serializable for TransactionArgs {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.tx_script.write_into(target);
         self.note_args.write_into(target);
-        self.advice_inputs.write_into(target);
+        self.note_consumption_proofs.write_into(target);
+        // empty advice inputs still contain the empty subtree roots of their Merkle store, and are
+        // thus omitted
+        (!self.has_empty_advice_inputs())
+            .then_some(&self.advice_inputs)
+            .write_into(target);
     }
 }
 
@@ -164,9 +217,15 @@ impl Deserializable for TransactionArgs {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let tx_script = Option::<TransactionScript>::read_from(source)?;
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
-        let advice_inputs = AdviceInputs::read_from(source)?;
+        let note_consumption_proofs = BTreeMap::<NoteId, NoteConsumptionProof>::read_from(source)?;
+        let advice_inputs = Option::<AdviceInputs>::read_from(source)?.unwrap_or_default();
 
-        Ok(Self { tx_script, note_args, advice_inputs })
+        Ok(Self {
+            tx_script,
+            note_args,
+            note_consumption_proofs,
+            advice_inputs,
+        })
     }
 }
 
@@ -214,7 +273,7 @@ impl TransactionScript {
     ) -> Result<Self, TransactionScriptError> {
         let program = assembler
             .assemble_program(source_code)
-            .map_err(|report| TransactionScriptError::AssemblyError(report.to_string()))?;
+            .map_err(|report| TransactionScriptError::AssemblyError(report.into()))?;
         Ok(Self::new(program, inputs))
     }
 
@@ -248,6 +307,175 @@ impl TransactionScript {
     pub fn inputs(&self) -> &BTreeMap<Digest, Vec<Felt>> {
         &self.inputs
     }
+
+    /// Returns the advice map keys which this transaction script is statically known to request.
+    ///
+    /// Only keys pushed onto the stack as constants right before being read from the advice map
+    /// are detected; keys computed at runtime are omitted. This can be used to check that the
+    /// advice inputs of a transaction contain the data required by the script before executing
+    /// it.
+    pub fn required_advice_keys(&self) -> Vec<Digest> {
+        collect_advice_map_keys(&self.mast, self.entrypoint)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that this transaction script calls only the procedures with the specified MAST roots.
+    ///
+    /// The check is performed statically by walking the MAST of the script reachable from its
+    /// entrypoint in execution order. Calls to allowed procedures are not inspected further, and
+    /// syscalls are not checked since they can target only the transaction kernel.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script calls a procedure which is not in `allowed`; the error contains the MAST root
+    ///   of the first such procedure encountered.
+    /// - The script contains a dynamic call, which cannot be checked statically.
+    pub fn validate_calls(&self, allowed: &[Digest]) -> Result<(), TransactionScriptError> {
+        validate_mast_calls(&self.mast, self.entrypoint, allowed).map_err(|call| match call {
+            DisallowedCall::Static(callee) => {
+                TransactionScriptError::DisallowedProcedureCall(callee)
+            },
+            DisallowedCall::Dynamic => TransactionScriptError::DynamicProcedureCall,
+        })
+    }
+}
+
+// CALL VALIDATION
+// ================================================================================================
+
+/// A procedure call rejected by [validate_mast_calls()].
+pub(crate) enum DisallowedCall {
+    /// A call to the procedure with the specified MAST root.
+    Static(Digest),
+    /// A dynamic call, whose target cannot be determined statically.
+    Dynamic,
+}
+
+/// Checks that the program with the specified entrypoint in the provided MAST forest calls only
+/// the procedures with the specified MAST roots.
+///
+/// The MAST reachable from the entrypoint is walked in execution order, and the first disallowed
+/// call encountered is returned. Calls to allowed procedures are not inspected further, and
+/// syscalls are not checked since they can target only the transaction kernel.
+pub(crate) fn validate_mast_calls(
+    mast: &MastForest,
+    entrypoint: MastNodeId,
+    allowed: &[Digest],
+) -> Result<(), DisallowedCall> {
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![entrypoint];
+    while let Some(node_id) = stack.pop() {
+        if !visited.insert(node_id) {
+            continue;
+        }
+
+        match &mast[node_id] {
+            MastNode::Join(node) => {
+                stack.push(node.second());
+                stack.push(node.first());
+            },
+            MastNode::Split(node) => {
+                stack.push(node.on_false());
+                stack.push(node.on_true());
+            },
+            MastNode::Loop(node) => stack.push(node.body()),
+            MastNode::Call(node) if !node.is_syscall() => {
+                let callee = mast[node.callee()].digest();
+                if !allowed.contains(&callee) {
+                    return Err(DisallowedCall::Static(callee));
+                }
+            },
+            MastNode::Dyn(_) => return Err(DisallowedCall::Dynamic),
+            MastNode::Block(_) | MastNode::Call(_) | MastNode::External(_) => (),
+        }
+    }
+
+    Ok(())
+}
+
+// ADVICE KEY ANALYSIS
+// ================================================================================================
+
+/// Returns the advice map keys which are statically known to be requested by the program with
+/// the specified entrypoint in the provided MAST forest.
+///
+/// A key is statically known if the `adv.push_mapval` (or similar) instruction reading it is
+/// preceded, within the same basic block, by instructions pushing the key onto the stack as
+/// constants. Keys computed at runtime are omitted, and so the returned list is a best-effort
+/// subset of the keys the program may request. Keys are returned in the order in which they are
+/// encountered, without duplicates.
+pub(crate) fn collect_advice_map_keys(mast: &MastForest, entrypoint: MastNodeId) -> Vec<Digest> {
+    let mut keys = Vec::new();
+    let mut visited = BTreeSet::new();
+    let mut stack = vec![entrypoint];
+    while let Some(node_id) = stack.pop() {
+        if !visited.insert(node_id) {
+            continue;
+        }
+
+        match &mast[node_id] {
+            MastNode::Join(node) => {
+                stack.push(node.second());
+                stack.push(node.first());
+            },
+            MastNode::Split(node) => {
+                stack.push(node.on_false());
+                stack.push(node.on_true());
+            },
+            MastNode::Loop(node) => stack.push(node.body()),
+            MastNode::Call(node) => stack.push(node.callee()),
+            MastNode::Block(block) => {
+                // constants known to be at the top of the stack, with the top element last
+                let mut constants: Vec<Felt> = Vec::new();
+                for item in block.iter() {
+                    match item {
+                        OperationOrDecorator::Operation(Operation::Push(value)) => {
+                            constants.push(*value)
+                        },
+                        OperationOrDecorator::Operation(Operation::Pad) => constants.push(ZERO),
+                        OperationOrDecorator::Operation(Operation::Incr) => {
+                            match constants.last_mut() {
+                                Some(value) => *value += ONE,
+                                None => constants.clear(),
+                            }
+                        },
+                        OperationOrDecorator::Operation(Operation::Noop) => (),
+                        OperationOrDecorator::Operation(_) => constants.clear(),
+                        OperationOrDecorator::Decorator(decorator_id) => {
+                            let Some(Decorator::Advice(AdviceInjector::MapValueToStack {
+                                key_offset,
+                                ..
+                            })) = mast.get_decorator_by_id(*decorator_id)
+                            else {
+                                continue;
+                            };
+
+                            // the key is the word at `key_offset` from the top of the stack, with
+                            // its first element being the deepest one
+                            let Some(end) = constants.len().checked_sub(*key_offset) else {
+                                continue;
+                            };
+                            let Some(start) = end.checked_sub(WORD_SIZE) else {
+                                continue;
+                            };
+                            let key = Digest::from(
+                                Word::try_from(&constants[start..end])
+                                    .expect("slice has 4 elements"),
+                            );
+                            if !keys.contains(&key) {
+                                keys.push(key);
+                            }
+                        },
+                    }
+                }
+            },
+            MastNode::Dyn(_) | MastNode::External(_) => (),
+        }
+    }
+
+    keys
 }
 
 // SERIALIZATION
@@ -273,10 +501,33 @@ impl Deserializable for TransactionScript {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{sync::Arc, vec};
+
+    use assembly::{Assembler, DefaultSourceManager};
     use vm_core::utils::{Deserializable, Serializable};
     use vm_processor::AdviceMap;
 
-    use crate::transaction::TransactionArgs;
+    use crate::{
+        notes::{NoteConsumptionProof, NoteId},
+        transaction::{TransactionArgs, TransactionScript},
+        CompilationError, Digest, Felt, TransactionScriptError,
+    };
+
+    #[test]
+    fn test_tx_args_empty_advice_inputs() {
+        let args = TransactionArgs::default();
+        assert!(args.has_empty_advice_inputs());
+        assert_eq!(args.to_bytes().len(), 4);
+        assert_eq!(TransactionArgs::read_from_bytes(&args.to_bytes()).unwrap(), args);
+
+        let code = "begin push.1 drop end";
+        let script = TransactionScript::compile(code, [], Assembler::default()).unwrap();
+        assert!(TransactionArgs::with_tx_script(script).has_empty_advice_inputs());
+
+        let script_inputs = [([Felt::new(1); 4], vec![Felt::new(2)])];
+        let script = TransactionScript::compile(code, script_inputs, Assembler::default()).unwrap();
+        assert!(!TransactionArgs::with_tx_script(script).has_empty_advice_inputs());
+    }
 
     #[test]
     fn test_tx_args_serialization() {
@@ -285,5 +536,128 @@ mod tests {
         let decoded = TransactionArgs::read_from_bytes(&bytes).unwrap();
 
         assert_eq!(args, decoded);
+
+        let mut args = TransactionArgs::new(None, None, AdviceMap::default());
+        args.add_note_consumption_proof(
+            NoteId::from(Digest::default()),
+            NoteConsumptionProof::new(vec![Felt::new(1), Felt::new(2)]).unwrap(),
+        );
+        let decoded = TransactionArgs::read_from_bytes(&args.to_bytes()).unwrap();
+
+        assert_eq!(args, decoded);
+    }
+
+    #[test]
+    fn test_tx_script_validate_calls() {
+        let source = "
+            proc.foo
+                push.1 drop
+            end
+
+            proc.bar
+                push.2 drop
+            end
+
+            begin
+                push.1
+                if.true
+                    call.foo
+                else
+                    call.bar
+                end
+            end
+        ";
+        let script = TransactionScript::compile(source, [], Assembler::default()).unwrap();
+
+        // the first call encountered in execution order is reported
+        let Err(TransactionScriptError::DisallowedProcedureCall(foo)) = script.validate_calls(&[])
+        else {
+            panic!("expected the call to foo to be rejected");
+        };
+        let Err(TransactionScriptError::DisallowedProcedureCall(bar)) =
+            script.validate_calls(&[foo])
+        else {
+            panic!("expected the call to bar to be rejected");
+        };
+        assert_ne!(foo, bar);
+        assert_eq!(script.validate_calls(&[foo, bar]), Ok(()));
+
+        // dynamic calls cannot be checked statically
+        let source = "begin dyncall end";
+        let script = TransactionScript::compile(source, [], Assembler::default()).unwrap();
+        assert_eq!(script.validate_calls(&[]), Err(TransactionScriptError::DynamicProcedureCall));
+    }
+
+    #[test]
+    fn test_tx_script_required_advice_keys() {
+        let source = "
+            proc.read_runtime_key
+                push.0 mem_loadw adv.push_mapval dropw
+            end
+
+            begin
+                push.1.2.3.4 adv.push_mapval dropw
+                push.5.6.7.8 push.0.0.0.0 adv.push_mapval.4 dropw dropw
+                push.1.2.3.4 adv.push_mapval dropw
+                call.read_runtime_key
+            end
+        ";
+        let script = TransactionScript::compile(source, [], Assembler::default()).unwrap();
+
+        let key = |elements: [u64; 4]| Digest::from(elements.map(Felt::new));
+        assert_eq!(script.required_advice_keys(), vec![key([1, 2, 3, 4]), key([5, 6, 7, 8])]);
+    }
+
+    #[test]
+    fn test_tx_script_compilation_errors() {
+        let compile = |source: &str, assembler: Assembler| match TransactionScript::compile(
+            source,
+            [],
+            assembler,
+        )
+        .unwrap_err()
+        {
+            TransactionScriptError::AssemblyError(err) => err,
+            err => panic!("unexpected error: {err}"),
+        };
+
+        let CompilationError::ParseError { location: Some(location), .. } =
+            compile("begin push.1 foo end", Assembler::default())
+        else {
+            panic!("expected a parse error")
+        };
+        assert_eq!((location.line, location.column), (1, 14));
+
+        let CompilationError::UnresolvedImport { name, location: Some(location) } =
+            compile("begin\n    call.bar\nend", Assembler::default())
+        else {
+            panic!("expected an unresolved import")
+        };
+        assert_eq!(name, "bar");
+        assert_eq!((location.line, location.column), (2, 10));
+
+        let CompilationError::UnresolvedImport { name, .. } =
+            compile("use.std::missing begin exec.missing::baz end", Assembler::default())
+        else {
+            panic!("expected an unresolved import")
+        };
+        assert_eq!(name, "std::missing");
+
+        let source = "proc.foo push.1 drop end proc.foo push.2 drop end begin exec.foo end";
+        let CompilationError::DuplicateDefinition { name, location: Some(location) } =
+            compile(source, Assembler::default())
+        else {
+            panic!("expected a duplicate definition")
+        };
+        assert_eq!(name, "foo");
+        assert_eq!((location.line, location.column), (1, 26));
+
+        let kernel = Assembler::default().assemble_kernel("export.foo push.1 drop end").unwrap();
+        let assembler = Assembler::with_kernel(Arc::new(DefaultSourceManager::default()), kernel);
+        let err = compile("begin syscall.bar end", assembler);
+        assert!(
+            matches!(&err, CompilationError::KernelProcedureMismatch { name, .. } if name.ends_with("bar")),
+            "unexpected error: {err}"
+        );
     }
 }
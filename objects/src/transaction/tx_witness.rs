@@ -1,10 +1,11 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
-use vm_core::utils::{ByteReader, Deserializable, Serializable};
-use vm_processor::DeserializationError;
+use miden_crypto::merkle::{InnerNodeInfo, MerkleStore};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use vm_processor::{AdviceMap, DeserializationError};
 
 use super::{AdviceInputs, TransactionArgs, TransactionInputs};
-use crate::accounts::AccountCode;
+use crate::{accounts::AccountCode, block::BlockNumber, notes::Note, Digest, Felt};
 
 // TRANSACTION WITNESS
 // ================================================================================================
@@ -35,11 +36,223 @@ pub struct TransactionWitness {
     pub account_codes: Vec<AccountCode>,
 }
 
+impl TransactionWitness {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of the reference block of the transaction.
+    ///
+    /// The block number is read from the block header in the transaction inputs, and thus it is
+    /// always consistent with the block hash the transaction is executed against. Since the block
+    /// header is serialized as part of the witness, the serialized format is not affected.
+    pub fn block_ref(&self) -> BlockNumber {
+        self.tx_inputs.block_header().block_num()
+    }
+
+    // CONSUMED NOTES
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over the notes consumed by the transaction.
+    ///
+    /// The transaction inputs of the witness carry the full details of all input notes, and thus
+    /// the notes are always available without being stored in the witness a second time. The
+    /// notes are returned in the order in which they are consumed by the transaction.
+    pub fn consumed_notes(&self) -> impl Iterator<Item = &Note> {
+        self.tx_inputs.input_notes().iter().map(|input_note| input_note.note())
+    }
+
+    // SIZE
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of bytes the advice witness of this transaction witness occupies when
+    /// the witness is serialized.
+    ///
+    /// The size is exact: the advice witness is written into a writer which only counts the
+    /// bytes, and thus no buffer for the serialized data is allocated.
+    pub fn advice_proof_size_bytes(&self) -> usize {
+        let mut counter = ByteCounter::default();
+        self.advice_witness.write_into(&mut counter);
+        counter.0
+    }
+
+    // DELTA ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [WitnessDelta] describing how this witness differs from the provided base
+    /// witness.
+    ///
+    /// Applying the returned delta to `base` via [Self::apply_delta()] reproduces this witness
+    /// exactly.
+    pub fn delta_encode(&self, base: &TransactionWitness) -> WitnessDelta {
+        let (stack, map, store) = advice_inputs_into_parts(&self.advice_witness);
+        let (base_stack, base_map, base_store) = advice_inputs_into_parts(&base.advice_witness);
+
+        let base_map: BTreeMap<Digest, Vec<Felt>> = base_map.into_iter().collect();
+        let map: BTreeMap<Digest, Vec<Felt>> = map.into_iter().collect();
+        let advice_map_updates = map
+            .iter()
+            .filter(|(key, values)| base_map.get(*key) != Some(*values))
+            .map(|(key, values)| (*key, values.clone()))
+            .collect();
+        let removed_advice_map_keys =
+            base_map.keys().filter(|key| !map.contains_key(*key)).copied().collect();
+
+        let base_nodes: BTreeMap<Digest, (Digest, Digest)> = base_store
+            .inner_nodes()
+            .map(|node| (node.value, (node.left, node.right)))
+            .collect();
+        let nodes: BTreeMap<Digest, (Digest, Digest)> =
+            store.inner_nodes().map(|node| (node.value, (node.left, node.right))).collect();
+        let merkle_store_updates = nodes
+            .iter()
+            .filter(|(value, children)| base_nodes.get(*value) != Some(*children))
+            .map(|(value, (left, right))| (*value, *left, *right))
+            .collect();
+        let removed_merkle_store_nodes =
+            base_nodes.keys().filter(|value| !nodes.contains_key(*value)).copied().collect();
+
+        WitnessDelta {
+            tx_inputs: (self.tx_inputs != base.tx_inputs).then(|| self.tx_inputs.clone()),
+            tx_args: (self.tx_args != base.tx_args).then(|| self.tx_args.clone()),
+            account_codes: (self.account_codes != base.account_codes)
+                .then(|| self.account_codes.clone()),
+            advice_stack: (stack != base_stack).then_some(stack),
+            advice_map_updates,
+            removed_advice_map_keys,
+            merkle_store_updates,
+            removed_merkle_store_nodes,
+        }
+    }
+
+    /// Returns the witness obtained by applying the provided [WitnessDelta] to this witness.
+    ///
+    /// If the delta was produced by [Self::delta_encode()] against this witness, the returned
+    /// witness is identical to the one the delta was computed for.
+    pub fn apply_delta(&self, delta: &WitnessDelta) -> TransactionWitness {
+        let (base_stack, base_map, base_store) = advice_inputs_into_parts(&self.advice_witness);
+
+        let stack = delta.advice_stack.clone().unwrap_or(base_stack);
+
+        let mut map: BTreeMap<Digest, Vec<Felt>> = base_map.into_iter().collect();
+        for key in delta.removed_advice_map_keys.iter() {
+            map.remove(key);
+        }
+        map.extend(delta.advice_map_updates.iter().cloned());
+
+        let mut nodes: BTreeMap<Digest, (Digest, Digest)> = base_store
+            .inner_nodes()
+            .map(|node| (node.value, (node.left, node.right)))
+            .collect();
+        for value in delta.removed_merkle_store_nodes.iter() {
+            nodes.remove(value);
+        }
+        nodes.extend(
+            delta
+                .merkle_store_updates
+                .iter()
+                .map(|(value, left, right)| (*value, (*left, *right))),
+        );
+        let store: MerkleStore = nodes
+            .into_iter()
+            .map(|(value, (left, right))| InnerNodeInfo { value, left, right })
+            .collect();
+
+        TransactionWitness {
+            tx_inputs: delta.tx_inputs.clone().unwrap_or_else(|| self.tx_inputs.clone()),
+            tx_args: delta.tx_args.clone().unwrap_or_else(|| self.tx_args.clone()),
+            advice_witness: AdviceInputs::default()
+                .with_stack(stack)
+                .with_map(map)
+                .with_merkle_store(store),
+            account_codes: delta
+                .account_codes
+                .clone()
+                .unwrap_or_else(|| self.account_codes.clone()),
+        }
+    }
+}
+
+// WITNESS DELTA
+// ================================================================================================
+
+/// Describes the difference between two [TransactionWitness]es.
+///
+/// A witness delta is produced by [TransactionWitness::delta_encode()] and can be applied to the
+/// base witness via [TransactionWitness::apply_delta()]. Transaction inputs, transaction arguments
+/// (including the transaction script) and account codes are recorded only if they differ from the
+/// base witness. For the advice witness, only the advice stack (if it differs), the advice map
+/// entries and Merkle store nodes which were added, changed or removed are recorded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WitnessDelta {
+    tx_inputs: Option<TransactionInputs>,
+    tx_args: Option<TransactionArgs>,
+    account_codes: Option<Vec<AccountCode>>,
+    advice_stack: Option<Vec<Felt>>,
+    advice_map_updates: Vec<(Digest, Vec<Felt>)>,
+    removed_advice_map_keys: Vec<Digest>,
+    merkle_store_updates: Vec<(Digest, Digest, Digest)>,
+    removed_merkle_store_nodes: Vec<Digest>,
+}
+
+impl WitnessDelta {
+    /// Returns true if this delta does not change the base witness.
+    pub fn is_empty(&self) -> bool {
+        self.tx_inputs.is_none()
+            && self.tx_args.is_none()
+            && self.account_codes.is_none()
+            && self.advice_stack.is_none()
+            && self.advice_map_updates.is_empty()
+            && self.removed_advice_map_keys.is_empty()
+            && self.merkle_store_updates.is_empty()
+            && self.removed_merkle_store_nodes.is_empty()
+    }
+
+    /// Returns the advice map entries which were added or changed relative to the base witness.
+    pub fn advice_map_updates(&self) -> &[(Digest, Vec<Felt>)] {
+        &self.advice_map_updates
+    }
+
+    /// Returns the keys of the advice map entries which were removed relative to the base witness.
+    pub fn removed_advice_map_keys(&self) -> &[Digest] {
+        &self.removed_advice_map_keys
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// A [ByteWriter] which discards the written bytes and only counts them.
+#[derive(Default)]
+struct ByteCounter(usize);
+
+impl ByteWriter for ByteCounter {
+    fn write_u8(&mut self, _value: u8) {
+        self.0 += 1;
+    }
+
+    fn write_bytes(&mut self, values: &[u8]) {
+        self.0 += values.len();
+    }
+}
+
+/// Splits the provided advice inputs into the advice stack, advice map and Merkle store.
+///
+/// [AdviceInputs] do not expose their advice map, but are serialized as the concatenation of these
+/// three components, and thus can be decomposed by deserializing the components one by one.
+fn advice_inputs_into_parts(advice_inputs: &AdviceInputs) -> (Vec<Felt>, AdviceMap, MerkleStore) {
+    let bytes = advice_inputs.to_bytes();
+    let mut source = vm_core::utils::SliceReader::new(&bytes);
+    let stack = Vec::<Felt>::read_from(&mut source).expect("advice stack is serialized first");
+    let map = AdviceMap::read_from(&mut source).expect("advice map is serialized second");
+    let store = MerkleStore::read_from(&mut source).expect("merkle store is serialized last");
+    (stack, map, store)
+}
+
 // SERIALIZATION
 // ================================================================================================
 
 impl Serializable for TransactionWitness {
-    fn write_into<W: miden_crypto::utils::ByteWriter>(&self, target: &mut W) {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.tx_inputs.write_into(target);
         self.tx_args.write_into(target);
         self.advice_witness.write_into(target);
@@ -61,3 +274,31 @@ impl Deserializable for TransactionWitness {
         })
     }
 }
+
+impl Serializable for WitnessDelta {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_inputs.write_into(target);
+        self.tx_args.write_into(target);
+        self.account_codes.write_into(target);
+        self.advice_stack.write_into(target);
+        self.advice_map_updates.write_into(target);
+        self.removed_advice_map_keys.write_into(target);
+        self.merkle_store_updates.write_into(target);
+        self.removed_merkle_store_nodes.write_into(target);
+    }
+}
+
+impl Deserializable for WitnessDelta {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            tx_inputs: Option::<TransactionInputs>::read_from(source)?,
+            tx_args: Option::<TransactionArgs>::read_from(source)?,
+            account_codes: Option::<Vec<AccountCode>>::read_from(source)?,
+            advice_stack: Option::<Vec<Felt>>::read_from(source)?,
+            advice_map_updates: Vec::<(Digest, Vec<Felt>)>::read_from(source)?,
+            removed_advice_map_keys: Vec::<Digest>::read_from(source)?,
+            merkle_store_updates: Vec::<(Digest, Digest, Digest)>::read_from(source)?,
+            removed_merkle_store_nodes: Vec::<Digest>::read_from(source)?,
+        })
+    }
+}
@@ -0,0 +1,466 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_crypto::utils::{Deserializable, DeserializationError, Serializable};
+use serde_json::{json, Value};
+
+use crate::{
+    accounts::{Account, AccountCode, AccountId, AccountStorage, StorageMap, StorageSlot},
+    assets::{Asset, AssetVault, FungibleAsset},
+    notes::{
+        Note, NoteAssets, NoteExecutionHint, NoteHeader, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteScript, NoteTag, NoteType, Nullifier,
+    },
+    transaction::{InputNoteCommitment, OutputNote, ProvenTransaction, ProvenTransactionBuilder},
+    vm::ExecutionProof,
+    Digest, Felt, Word,
+};
+
+// JSON SERIALIZABLE
+// ================================================================================================
+
+/// Serialization of objects into JSON, intended for debugging and human inspection.
+///
+/// Identifiers, hashes, words, amounts and other scalar values are encoded as readable JSON
+/// values: account IDs, digests and words are encoded as hex strings, while field elements and
+/// integers are encoded as numbers. Components which have no meaningful readable representation
+/// (e.g., compiled code, note scripts and execution proofs) are encoded as hex strings of their
+/// native encoding. Thus, the JSON encoding carries exactly the same information as the native
+/// encoding of an object.
+pub trait JsonSerializable: Sized {
+    /// Returns the JSON value representing this object.
+    fn to_json_value(&self) -> Value;
+
+    /// Returns an object decoded from the provided JSON value.
+    ///
+    /// # Errors
+    /// Returns an error if the value does not represent a valid object.
+    fn from_json_value(value: &Value) -> Result<Self, DeserializationError>;
+
+    /// Returns the JSON encoding of this object.
+    fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    /// Returns an object decoded from the provided JSON string.
+    ///
+    /// # Errors
+    /// Returns an error if the string is not valid JSON or does not represent a valid object.
+    fn from_json(json: &str) -> Result<Self, DeserializationError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|err| DeserializationError::InvalidValue(format!("invalid JSON: {err}")))?;
+        Self::from_json_value(&value)
+    }
+}
+
+// ACCOUNT
+// ================================================================================================
+
+impl JsonSerializable for Account {
+    fn to_json_value(&self) -> Value {
+        let storage: Vec<Value> = self
+            .storage()
+            .slots()
+            .iter()
+            .map(|slot| match slot {
+                StorageSlot::Value(value) => json!({ "value": word_to_hex(*value) }),
+                StorageSlot::Map(map) => {
+                    let entries: Vec<Value> = map
+                        .entries()
+                        .map(|(key, value)| json!([key.to_hex(), word_to_hex(*value)]))
+                        .collect();
+                    json!({ "map": entries })
+                },
+            })
+            .collect();
+
+        json!({
+            "id": self.id().to_hex(),
+            "nonce": self.nonce().as_int(),
+            "vault": self.vault().assets().map(asset_to_json).collect::<Vec<_>>(),
+            "storage": storage,
+            "code": bytes_to_hex(&self.code().to_bytes()),
+        })
+    }
+
+    fn from_json_value(value: &Value) -> Result<Self, DeserializationError> {
+        let id = account_id_from_json(field(value, "id")?)?;
+        let nonce = felt_from_json(field(value, "nonce")?)?;
+
+        let assets = array(field(value, "vault")?)?
+            .iter()
+            .map(asset_from_json)
+            .collect::<Result<Vec<_>, _>>()?;
+        let vault = AssetVault::new(&assets).map_err(invalid_value)?;
+
+        let slots = array(field(value, "storage")?)?
+            .iter()
+            .map(|slot| {
+                if let Some(value) = slot.get("value") {
+                    return Ok(StorageSlot::Value(word_from_json(value)?));
+                }
+                let entries = array(field(slot, "map")?)?
+                    .iter()
+                    .map(|entry| match array(entry)?.as_slice() {
+                        [key, value] => Ok((digest_from_json(key)?, word_from_json(value)?)),
+                        _ => Err(invalid_value("storage map entry must be a [key, value] pair")),
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(StorageSlot::Map(StorageMap::with_entries(entries).map_err(invalid_value)?))
+            })
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let storage = AccountStorage::new(slots).map_err(invalid_value)?;
+
+        let code: AccountCode = native_from_json(field(value, "code")?)?;
+
+        Ok(Account::from_parts(id, vault, storage, code, nonce))
+    }
+}
+
+// NOTE
+// ================================================================================================
+
+impl JsonSerializable for Note {
+    fn to_json_value(&self) -> Value {
+        let metadata = self.metadata();
+        let note_type = match metadata.note_type() {
+            NoteType::Private => "private",
+            NoteType::Encrypted => "encrypted",
+            NoteType::Public => "public",
+        };
+        let inputs: Vec<u64> = self.inputs().values().iter().map(Felt::as_int).collect();
+
+        json!({
+            "id": self.id().to_hex(),
+            "assets": self.assets().iter().copied().map(asset_to_json).collect::<Vec<_>>(),
+            "metadata": {
+                "sender": metadata.sender().to_hex(),
+                "note_type": note_type,
+                "tag": u32::from(metadata.tag()),
+                "execution_hint": u64::from(metadata.execution_hint()),
+                "aux": metadata.aux().as_int(),
+            },
+            "recipient": {
+                "serial_num": word_to_hex(self.serial_num()),
+                "script": bytes_to_hex(&self.script().to_bytes()),
+                "inputs": inputs,
+            },
+        })
+    }
+
+    fn from_json_value(value: &Value) -> Result<Self, DeserializationError> {
+        let assets = array(field(value, "assets")?)?
+            .iter()
+            .map(asset_from_json)
+            .collect::<Result<Vec<_>, _>>()?;
+        let assets = NoteAssets::new(assets).map_err(invalid_value)?;
+
+        let metadata = field(value, "metadata")?;
+        let note_type = match string(field(metadata, "note_type")?)? {
+            "private" => NoteType::Private,
+            "encrypted" => NoteType::Encrypted,
+            "public" => NoteType::Public,
+            other => return Err(invalid_value(format!("unknown note type {other}"))),
+        };
+        let tag = u32::try_from(integer(field(metadata, "tag")?)?)
+            .map(NoteTag::from)
+            .map_err(invalid_value)?;
+        let execution_hint =
+            NoteExecutionHint::try_from(integer(field(metadata, "execution_hint")?)?)
+                .map_err(invalid_value)?;
+        let metadata = NoteMetadata::new(
+            account_id_from_json(field(metadata, "sender")?)?,
+            note_type,
+            tag,
+            execution_hint,
+            felt_from_json(field(metadata, "aux")?)?,
+        )
+        .map_err(invalid_value)?;
+
+        let recipient = field(value, "recipient")?;
+        let script: NoteScript = native_from_json(field(recipient, "script")?)?;
+        let inputs = array(field(recipient, "inputs")?)?
+            .iter()
+            .map(felt_from_json)
+            .collect::<Result<Vec<_>, _>>()?;
+        let recipient = NoteRecipient::new(
+            word_from_json(field(recipient, "serial_num")?)?,
+            script,
+            NoteInputs::new(inputs).map_err(invalid_value)?,
+        );
+
+        let note = Note::new(assets, metadata, recipient);
+        check_id(value, &note.id().to_hex())?;
+        Ok(note)
+    }
+}
+
+// PROVEN TRANSACTION
+// ================================================================================================
+
+impl JsonSerializable for ProvenTransaction {
+    fn to_json_value(&self) -> Value {
+        let account_update = self.account_update();
+        let input_notes: Vec<Value> = self
+            .input_notes()
+            .iter()
+            .map(|note| {
+                json!({
+                    "nullifier": note.nullifier().to_hex(),
+                    "header": note.header().map(|header| bytes_to_hex(&header.to_bytes())),
+                })
+            })
+            .collect();
+        let output_notes: Vec<Value> = self
+            .output_notes()
+            .iter()
+            .map(|note| json!({ "id": note.id().to_hex(), "note": bytes_to_hex(&note.to_bytes()) }))
+            .collect();
+
+        json!({
+            "id": self.id().to_hex(),
+            "account_update": {
+                "account_id": account_update.account_id().to_hex(),
+                "init_state_hash": account_update.init_state_hash().to_hex(),
+                "final_state_hash": account_update.final_state_hash().to_hex(),
+                "details": bytes_to_hex(&account_update.details().to_bytes()),
+            },
+            "input_notes": input_notes,
+            "output_notes": output_notes,
+            "block_ref": self.block_ref().to_hex(),
+            "expiration_block_num": self.expiration_block_num(),
+            "proof": bytes_to_hex(&Serializable::to_bytes(self.proof())),
+        })
+    }
+
+    fn from_json_value(value: &Value) -> Result<Self, DeserializationError> {
+        let account_update = field(value, "account_update")?;
+        let expiration_block_num = u32::try_from(integer(field(value, "expiration_block_num")?)?)
+            .map_err(invalid_value)?;
+        let proof: ExecutionProof = native_from_json(field(value, "proof")?)?;
+
+        let input_notes = array(field(value, "input_notes")?)?
+            .iter()
+            .map(|note| {
+                let nullifier = Nullifier::from(digest_from_json(field(note, "nullifier")?)?);
+                let header = match field(note, "header")? {
+                    Value::Null => None,
+                    header => Some(native_from_json::<NoteHeader>(header)?),
+                };
+                Ok(InputNoteCommitment::new(nullifier, header))
+            })
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let output_notes = array(field(value, "output_notes")?)?
+            .iter()
+            .map(|note| {
+                let output_note: OutputNote = native_from_json(field(note, "note")?)?;
+                check_id(note, &output_note.id().to_hex())?;
+                Ok(output_note)
+            })
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+
+        let transaction = ProvenTransactionBuilder::new(
+            account_id_from_json(field(account_update, "account_id")?)?,
+            digest_from_json(field(account_update, "init_state_hash")?)?,
+            digest_from_json(field(account_update, "final_state_hash")?)?,
+            digest_from_json(field(value, "block_ref")?)?,
+            expiration_block_num,
+            proof,
+        )
+        .account_update_details(native_from_json(field(account_update, "details")?)?)
+        .add_input_notes(input_notes)
+        .add_output_notes(output_notes)
+        .build()
+        .map_err(invalid_value)?;
+
+        check_id(value, &transaction.id().to_hex())?;
+        Ok(transaction)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn invalid_value(err: impl ToString) -> DeserializationError {
+    DeserializationError::InvalidValue(err.to_string())
+}
+
+/// Returns an error if the `id` field of the provided JSON object differs from the expected ID,
+/// i.e., if the object was modified without updating its ID.
+fn check_id(value: &Value, expected: &str) -> Result<(), DeserializationError> {
+    let id = string(field(value, "id")?)?;
+    if id != expected {
+        return Err(invalid_value(format!("ID {id} does not match the content (ID {expected})")));
+    }
+    Ok(())
+}
+
+fn field<'a>(value: &'a Value, name: &str) -> Result<&'a Value, DeserializationError> {
+    value
+        .as_object()
+        .and_then(|object| object.get(name))
+        .ok_or_else(|| invalid_value(format!("missing field {name}")))
+}
+
+fn array(value: &Value) -> Result<&Vec<Value>, DeserializationError> {
+    value
+        .as_array()
+        .ok_or_else(|| invalid_value(format!("expected an array, found {value}")))
+}
+
+fn string(value: &Value) -> Result<&str, DeserializationError> {
+    value
+        .as_str()
+        .ok_or_else(|| invalid_value(format!("expected a string, found {value}")))
+}
+
+fn integer(value: &Value) -> Result<u64, DeserializationError> {
+    value
+        .as_u64()
+        .ok_or_else(|| invalid_value(format!("expected an integer, found {value}")))
+}
+
+fn felt_from_json(value: &Value) -> Result<Felt, DeserializationError> {
+    Felt::try_from(integer(value)?).map_err(invalid_value)
+}
+
+fn account_id_from_json(value: &Value) -> Result<AccountId, DeserializationError> {
+    AccountId::from_hex(string(value)?).map_err(invalid_value)
+}
+
+fn digest_from_json(value: &Value) -> Result<Digest, DeserializationError> {
+    Digest::try_from(string(value)?).map_err(invalid_value)
+}
+
+fn word_to_hex(word: Word) -> String {
+    Digest::from(word).to_hex()
+}
+
+fn word_from_json(value: &Value) -> Result<Word, DeserializationError> {
+    digest_from_json(value).map(Word::from)
+}
+
+fn asset_to_json(asset: Asset) -> Value {
+    match asset {
+        Asset::Fungible(asset) => json!({
+            "faucet_id": asset.faucet_id().to_hex(),
+            "amount": asset.amount(),
+        }),
+        Asset::NonFungible(asset) => json!({
+            "faucet_id": asset.faucet_id().to_hex(),
+            "word": word_to_hex(asset.into()),
+        }),
+    }
+}
+
+fn asset_from_json(value: &Value) -> Result<Asset, DeserializationError> {
+    let faucet_id = account_id_from_json(field(value, "faucet_id")?)?;
+    let asset = match value.get("amount") {
+        Some(amount) => FungibleAsset::new(faucet_id, integer(amount)?)
+            .map(Asset::Fungible)
+            .map_err(invalid_value)?,
+        None => Asset::try_from(word_from_json(field(value, "word")?)?).map_err(invalid_value)?,
+    };
+    if asset.faucet_id() != faucet_id {
+        return Err(invalid_value(format!("asset is not issued by faucet {faucet_id}")));
+    }
+    Ok(asset)
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + 2 * bytes.len());
+    hex.push_str("0x");
+    for byte in bytes {
+        hex.push_str(&format!("{byte:02x}"));
+    }
+    hex
+}
+
+/// Decodes an object from a hex string of its native encoding.
+fn native_from_json<T: Deserializable>(value: &Value) -> Result<T, DeserializationError> {
+    let hex = string(value)?;
+    let digits = hex
+        .strip_prefix("0x")
+        .ok_or_else(|| invalid_value(format!("hex string {hex} must start with 0x")))?;
+    if digits.len() % 2 != 0 {
+        return Err(invalid_value(format!("hex string {hex} has an odd number of digits")));
+    }
+    let bytes = (0..digits.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digits[i..i + 2], 16))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(invalid_value)?;
+    T::read_from_bytes(&bytes)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assembly::Assembler;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::JsonSerializable;
+    use crate::{
+        accounts::{
+            account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+            Account, AccountId, AccountStorage,
+        },
+        assets::{Asset, FungibleAsset},
+        notes::Note,
+        testing::{
+            notes::NoteBuilder,
+            storage::{build_account, build_assets},
+        },
+        utils::format::{Format, FormatSerializable},
+        Felt, ONE,
+    };
+
+    /// Asserts that the provided object is decoded into itself from both the native and the JSON
+    /// format.
+    fn assert_round_trip<T: FormatSerializable + PartialEq + core::fmt::Debug>(object: &T) {
+        for format in [Format::Native, Format::Json] {
+            let bytes = object.to_bytes_in(format);
+            assert_eq!(T::read_from_bytes_in(&bytes, format).unwrap(), *object);
+        }
+    }
+
+    #[test]
+    fn account_json_round_trip() {
+        let (fungible_asset, non_fungible_asset) = build_assets();
+        let account = build_account(
+            vec![fungible_asset, non_fungible_asset],
+            ONE,
+            AccountStorage::mock_storage_slots(),
+        );
+        assert_round_trip(&account);
+
+        let json = account.to_json_value();
+        assert_eq!(json["id"], account.id().to_hex());
+        assert_eq!(json["nonce"], 1);
+        assert_eq!(Account::from_json(&account.to_json()).unwrap(), account);
+    }
+
+    #[test]
+    fn note_json_round_trip() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let note = NoteBuilder::new(sender, StdRng::seed_from_u64(3))
+            .note_inputs([Felt::new(1), Felt::new(2)])
+            .unwrap()
+            .add_assets([Asset::Fungible(FungibleAsset::new(faucet_id, 10).unwrap())])
+            .build(&Assembler::default())
+            .unwrap();
+        assert_round_trip(&note);
+        assert_eq!(note.to_json_value()["recipient"]["inputs"], serde_json::json!([1, 2]));
+
+        // the ID must match the content
+        let mut json = note.to_json_value();
+        json["recipient"]["inputs"] = serde_json::json!([1, 3]);
+        assert!(Note::from_json_value(&json).is_err());
+    }
+}
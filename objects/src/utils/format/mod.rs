@@ -0,0 +1,80 @@
+use alloc::vec::Vec;
+
+use miden_crypto::utils::{Deserializable, DeserializationError, Serializable};
+
+use crate::{accounts::Account, notes::Note, transaction::ProvenTransaction};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "json")]
+pub use json::JsonSerializable;
+
+// FORMAT
+// ================================================================================================
+
+/// The wire formats in which core objects can be encoded.
+///
+/// Both formats represent the same logical content, and so an object encoded in one format and
+/// decoded from it is identical to an object encoded and decoded in the other format.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The compact binary encoding defined by the [Serializable] and [Deserializable]
+    /// implementations of an object. This is the format to be used in production.
+    #[default]
+    Native,
+    /// A JSON encoding intended for debugging and human inspection (see [JsonSerializable]).
+    #[cfg(feature = "json")]
+    Json,
+}
+
+// FORMAT SERIALIZABLE
+// ================================================================================================
+
+/// Serialization of objects into any of the supported [Format]s.
+pub trait FormatSerializable: Sized {
+    /// Returns the encoding of this object in the specified format.
+    fn to_bytes_in(&self, format: Format) -> Vec<u8>;
+
+    /// Returns an object decoded from its encoding in the specified format.
+    ///
+    /// # Errors
+    /// Returns an error if the provided bytes are not a valid encoding of an object in the
+    /// specified format.
+    fn read_from_bytes_in(bytes: &[u8], format: Format) -> Result<Self, DeserializationError>;
+}
+
+macro_rules! impl_format_serializable {
+    ($($ty:ty),*) => {
+        $(
+            impl FormatSerializable for $ty {
+                fn to_bytes_in(&self, format: Format) -> Vec<u8> {
+                    match format {
+                        Format::Native => self.to_bytes(),
+                        #[cfg(feature = "json")]
+                        Format::Json => self.to_json().into_bytes(),
+                    }
+                }
+
+                fn read_from_bytes_in(
+                    bytes: &[u8],
+                    format: Format,
+                ) -> Result<Self, DeserializationError> {
+                    match format {
+                        Format::Native => Self::read_from_bytes(bytes),
+                        #[cfg(feature = "json")]
+                        Format::Json => {
+                            let json = core::str::from_utf8(bytes).map_err(|err| {
+                                DeserializationError::InvalidValue(alloc::format!(
+                                    "JSON is not valid UTF-8: {err}"
+                                ))
+                            })?;
+                            Self::from_json(json)
+                        },
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_format_serializable!(Account, Note, ProvenTransaction);
@@ -0,0 +1,134 @@
+use alloc::string::ToString;
+
+use miden_crypto::utils::DeserializationError;
+
+use crate::{Digest, Felt, Word, WORD_SIZE};
+
+// WORD BYTES
+// ================================================================================================
+
+/// Explicit byte encodings of [Word]s and [Digest]s.
+///
+/// Both encodings produce 32 bytes: the elements are encoded in order, each as the 8-byte
+/// representation of its canonical `u64` value. In the little-endian encoding these 8 bytes are
+/// little-endian, and in the big-endian encoding they are big-endian.
+///
+/// The little-endian encoding is the one used by the default serialization of words and digests
+/// (i.e., via [Serializable](super::serde::Serializable) and `Digest::as_bytes()`).
+pub trait WordBytes: Sized {
+    /// Returns the little-endian encoding of this value.
+    fn to_le_bytes(&self) -> [u8; 32];
+
+    /// Returns the big-endian encoding of this value.
+    fn to_be_bytes(&self) -> [u8; 32];
+
+    /// Returns a value decoded from its little-endian encoding.
+    ///
+    /// # Errors
+    /// Returns an error if any of the encoded elements is not a canonical field element.
+    fn from_le_bytes(bytes: [u8; 32]) -> Result<Self, DeserializationError>;
+
+    /// Returns a value decoded from its big-endian encoding.
+    ///
+    /// # Errors
+    /// Returns an error if any of the encoded elements is not a canonical field element.
+    fn from_be_bytes(bytes: [u8; 32]) -> Result<Self, DeserializationError>;
+}
+
+impl WordBytes for Word {
+    fn to_le_bytes(&self) -> [u8; 32] {
+        encode(self, u64::to_le_bytes)
+    }
+
+    fn to_be_bytes(&self) -> [u8; 32] {
+        encode(self, u64::to_be_bytes)
+    }
+
+    fn from_le_bytes(bytes: [u8; 32]) -> Result<Self, DeserializationError> {
+        decode(bytes, u64::from_le_bytes)
+    }
+
+    fn from_be_bytes(bytes: [u8; 32]) -> Result<Self, DeserializationError> {
+        decode(bytes, u64::from_be_bytes)
+    }
+}
+
+impl WordBytes for Digest {
+    fn to_le_bytes(&self) -> [u8; 32] {
+        Word::from(self).to_le_bytes()
+    }
+
+    fn to_be_bytes(&self) -> [u8; 32] {
+        Word::from(self).to_be_bytes()
+    }
+
+    fn from_le_bytes(bytes: [u8; 32]) -> Result<Self, DeserializationError> {
+        Word::from_le_bytes(bytes).map(Digest::from)
+    }
+
+    fn from_be_bytes(bytes: [u8; 32]) -> Result<Self, DeserializationError> {
+        Word::from_be_bytes(bytes).map(Digest::from)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn encode(word: &Word, encode_element: fn(u64) -> [u8; 8]) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    for (chunk, element) in bytes.chunks_exact_mut(8).zip(word) {
+        chunk.copy_from_slice(&encode_element(element.as_int()));
+    }
+    bytes
+}
+
+fn decode(
+    bytes: [u8; 32],
+    decode_element: fn([u8; 8]) -> u64,
+) -> Result<Word, DeserializationError> {
+    let mut word = [Felt::default(); WORD_SIZE];
+    for (element, chunk) in word.iter_mut().zip(bytes.chunks_exact(8)) {
+        let value = decode_element(chunk.try_into().expect("chunk has 8 bytes"));
+        *element = Felt::try_from(value)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+    }
+    Ok(word)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_crypto::utils::Serializable;
+
+    use super::WordBytes;
+    use crate::{Digest, Felt, Word};
+
+    #[test]
+    fn word_bytes_round_trip() {
+        let word: Word = [Felt::new(1), Felt::new(u32::MAX as u64), Felt::new(3), -Felt::new(1)];
+        let digest = Digest::from(word);
+
+        assert_eq!(Word::from_le_bytes(word.to_le_bytes()).unwrap(), word);
+        assert_eq!(Word::from_be_bytes(word.to_be_bytes()).unwrap(), word);
+        assert_eq!(Digest::from_le_bytes(digest.to_le_bytes()).unwrap(), digest);
+        assert_eq!(Digest::from_be_bytes(digest.to_be_bytes()).unwrap(), digest);
+
+        // the little-endian encoding matches the default serialization
+        assert_eq!(word.to_le_bytes().to_vec(), word.to_bytes());
+        assert_eq!(digest.to_le_bytes(), digest.as_bytes());
+
+        // the big-endian encoding reverses the bytes of each element
+        assert_eq!(&word.to_be_bytes()[..8], &1_u64.to_be_bytes());
+        assert_ne!(word.to_be_bytes(), word.to_le_bytes());
+    }
+
+    #[test]
+    fn word_bytes_rejects_non_canonical_elements() {
+        let mut bytes = [0; 32];
+        bytes[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(Word::from_le_bytes(bytes).is_err());
+        assert!(Word::from_be_bytes(bytes).is_err());
+    }
+}